@@ -0,0 +1,298 @@
+//! Works out how much of the cartridge each file in `gfx/` takes up, and stops the build if a
+//! sprite sheet stores the same frame twice. `include_aseprite!` keeps every frame it's given
+//! even when it's the same as another, so a frame wanted by two tags should be shared between
+//! them, either by the tags overlapping or by giving it a tag of its own. Frames that are copies
+//! of each other flipped are caught too, since the sprite can just be flipped instead.
+//!
+//! The only repeats allowed are inside a single tag, where an animation needs the same frame
+//! more than once to play in order. They're counted in the report so they stay visible.
+//!
+//! It also checks every tag `src/assets.rs` asks for is in the sheet it's looked up in, since
+//! a missing one only shows up as a panic with no name when the statics are evaluated. Tags the
+//! code picks frames out of by number have to have the number of frames in `FRAMES`.
+//!
+//! The sizes are written out to `assets.rs` for `rom` to check against the budget.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use asefile::AsepriteFile;
+use image::{imageops, RgbaImage};
+
+/// Backgrounds and sprites are both made of 8x8 tiles
+const TILE: u32 = 8;
+
+/// Where the sprite sheets are loaded and their tags looked up
+const SPRITE_ASSETS: &str = "src/assets.rs";
+
+/// Put in front of the fields of `Asset` only the debug log reads
+const DEBUG: &str = "#[cfg(feature = \"debug-tools\")]";
+
+/// The tags something counts the frames of, and how many it expects. Any other tag just has to
+/// have at least one.
+const FRAMES: [(&str, u32); 16] = [
+    // The paddles only ever show their first frame, the flash is a tag of its own
+    ("Paddle End", 1),
+    ("Paddle Mid", 1),
+    ("Dash End", 1),
+    ("Dash Mid", 1),
+    ("Tower End", 1),
+    ("Tower Mid", 1),
+    ("Flash End", 1),
+    ("Flash Mid", 1),
+    // Picked by the digit drawn
+    ("Digits", 10),
+    // Each of the 28 glyphs in `marquee` in each of its 3 colours
+    ("Letters", 84),
+    // The crowd steps between two frames
+    ("Spectator", 2),
+    ("Cheer", 2),
+    // The trail's frames are shared out over its length, the coloured ones are drawn the same
+    ("Trail", 3),
+    ("Trail Left", 3),
+    ("Trail Right", 3),
+    // One frame for each quarter of a full energy meter and one for empty
+    ("Meter", 5),
+];
+
+/// Each tag in a sprite sheet and which of its frames it covers
+type Tags = Vec<(String, RangeInclusive<u32>)>;
+
+/// A file from `gfx/` and how many bytes of it end up on the cartridge
+struct Asset {
+    path: String,
+    bytes: usize,
+    /// Frames or tiles the size doesn't have to include, repeated within a tag for a sheet and
+    /// shared with another tile for a background
+    repeats: usize,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=gfx");
+    println!("cargo:rerun-if-changed={SPRITE_ASSETS}");
+
+    let mut paths: Vec<PathBuf> = fs::read_dir("gfx")
+        .expect("can't list gfx/")
+        .map(|entry| entry.expect("can't list gfx/").path())
+        .collect();
+    paths.sort();
+
+    let mut assets = Vec::new();
+    let mut sheets = HashMap::new();
+    let mut problems = Vec::new();
+    for path in &paths {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("aseprite") => {
+                let (asset, tags) = sheet(path, &mut problems);
+                sheets.insert(asset.path.clone(), tags);
+                assets.push(asset);
+            }
+            Some("png") => assets.push(background(path)),
+            _ => {}
+        }
+    }
+    check_tags(&sheets, &mut problems);
+
+    if !problems.is_empty() {
+        panic!(
+            "the sprite sheets aren't what the game expects:\n  {}",
+            problems.join("\n  ")
+        );
+    }
+
+    let mut table = String::new();
+    writeln!(table, "pub static ASSETS: [Asset; {}] = [", assets.len()).unwrap();
+    for asset in &assets {
+        writeln!(
+            table,
+            "    Asset {{ {DEBUG} path: {:?}, bytes: {}, {DEBUG} repeats: {} }},",
+            asset.path, asset.bytes, asset.repeats
+        )
+        .unwrap();
+    }
+    writeln!(table, "];").unwrap();
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+    fs::write(out.join("assets.rs"), table).expect("can't write assets.rs");
+}
+
+fn display(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// The image and each way it could be flipped, for comparing with other images
+fn flips(image: &RgbaImage) -> [RgbaImage; 4] {
+    let horizontal = imageops::flip_horizontal(image);
+    let both = imageops::flip_vertical(&horizontal);
+    [
+        image.clone(),
+        horizontal,
+        imageops::flip_vertical(image),
+        both,
+    ]
+}
+
+/// All the frames of a sprite sheet. Any two that are the same, or the same flipped, have to be
+/// in the same tag, anything else goes in `problems`.
+fn sheet(path: &Path, problems: &mut Vec<String>) -> (Asset, Tags) {
+    let name = display(path);
+    let file = AsepriteFile::read_file(path).unwrap_or_else(|err| panic!("{name}: {err}"));
+    let tags: Tags = (0..file.num_tags())
+        .map(|index| {
+            let tag = file.tag(index);
+            (tag.name().to_owned(), tag.from_frame()..=tag.to_frame())
+        })
+        .collect();
+    let tag_of = |frame: u32| {
+        tags.iter()
+            .filter(|(_, frames)| frames.contains(&frame))
+            .map(|(name, _)| format!("{name:?}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let share_a_tag = |a: u32, b: u32| {
+        tags.iter()
+            .any(|(_, frames)| frames.contains(&a) && frames.contains(&b))
+    };
+
+    let mut seen: HashMap<Vec<u8>, u32> = HashMap::new();
+    let mut repeats = 0;
+    let mut colours = Vec::new();
+    for frame in 0..file.num_frames() {
+        let image = file.frame(frame).image();
+        for pixel in image.pixels().filter(|pixel| pixel[3] != 0) {
+            if !colours.contains(pixel) {
+                colours.push(*pixel);
+            }
+        }
+
+        let earlier = flips(&image)
+            .iter()
+            .find_map(|flipped| seen.get(flipped.as_raw()).copied());
+        match earlier {
+            Some(earlier) if share_a_tag(earlier, frame) => repeats += 1,
+            Some(earlier) => problems.push(format!(
+                "{name}: frame {frame} ({}) is frame {earlier} ({}) again",
+                tag_of(frame),
+                tag_of(earlier)
+            )),
+            None => {}
+        }
+        seen.entry(image.into_raw()).or_insert(frame);
+    }
+
+    // A sheet with a transparent colour and up to 15 others fits in a 16 colour palette
+    let bits_per_pixel = if colours.len() < 16 { 4 } else { 8 };
+    let frame_bytes = file.width() * file.height() * bits_per_pixel / 8;
+    let asset = Asset {
+        path: name,
+        bytes: file.num_frames() as usize * frame_bytes,
+        repeats,
+    };
+    (asset, tags)
+}
+
+/// The text between `start` and the next `end` after it
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    Some(rest.split_once(end)?.0)
+}
+
+/// Goes through `SPRITE_ASSETS` for each `include_aseprite!` and `tags().get`, making sure the
+/// tag is in the sheet with the name in front of it
+fn check_tags(sheets: &HashMap<String, Tags>, problems: &mut Vec<String>) {
+    let source = fs::read_to_string(SPRITE_ASSETS).expect("can't read the sprite assets");
+
+    // Which static each sheet is loaded into, like `static CLASSIC: &Graphics = ...`
+    let mut statics = HashMap::new();
+    for line in source.lines() {
+        if let (Some(name), Some(path)) = (
+            between(line, "static ", ":"),
+            between(line, "include_aseprite!(\"", "\""),
+        ) {
+            statics.insert(name.trim(), path);
+        }
+    }
+
+    let mut used = Vec::new();
+    for (number, line) in source.lines().enumerate() {
+        let Some((before, after)) = line.split_once(".tags().get(\"") else {
+            continue;
+        };
+        let Some((tag, _)) = after.split_once('"') else {
+            continue;
+        };
+        let at = format!("{SPRITE_ASSETS}:{}", number + 1);
+        // The last word before it, from `    ball: CLASSIC.tags().get("Bal"),`
+        let sheet = before.rsplit([' ', '(']).next().unwrap_or(before);
+
+        let Some(path) = statics.get(sheet) else {
+            problems.push(format!(
+                "{at}: {sheet} isn't a sheet loaded with include_aseprite!"
+            ));
+            continue;
+        };
+        let Some(tags) = sheets.get(*path) else {
+            problems.push(format!("{at}: there's no sprite sheet at {path}"));
+            continue;
+        };
+        let Some((_, frames)) = tags.iter().find(|(name, _)| name == tag) else {
+            let names: Vec<&str> = tags.iter().map(|(name, _)| name.as_str()).collect();
+            problems.push(format!(
+                "{at}: {path} has no tag called {tag:?}, it has {}",
+                names.join(", ")
+            ));
+            continue;
+        };
+
+        let count = frames.end() - frames.start() + 1;
+        if let Some((_, expected)) = FRAMES.iter().find(|(name, _)| name == &tag) {
+            if count != *expected {
+                problems.push(format!(
+                    "{at}: {tag:?} in {path} has {count} frames but {expected} are expected"
+                ));
+            }
+        }
+        used.push(tag);
+    }
+
+    for (tag, _) in FRAMES {
+        if !used.contains(&tag) {
+            problems.push(format!(
+                "FRAMES in build.rs expects {tag:?}, but it isn't used"
+            ));
+        }
+    }
+}
+
+/// A background, deduplicated by its include so each tile is only stored once however it's
+/// flipped, plus the two bytes per tile saying which one goes where
+fn background(path: &Path) -> Asset {
+    let name = display(path);
+    let image = image::open(path)
+        .unwrap_or_else(|err| panic!("{name}: {err}"))
+        .to_rgba8();
+
+    let mut tiles: Vec<Vec<u8>> = Vec::new();
+    let mut count = 0;
+    for y in (0..image.height()).step_by(TILE as usize) {
+        for x in (0..image.width()).step_by(TILE as usize) {
+            let tile = imageops::crop_imm(&image, x, y, TILE, TILE).to_image();
+            count += 1;
+            let flipped = flips(&tile);
+            if !flipped.iter().any(|tile| tiles.contains(tile.as_raw())) {
+                tiles.push(tile.into_raw());
+            }
+        }
+    }
+
+    Asset {
+        path: name,
+        bytes: tiles.len() * (TILE * TILE / 2) as usize + count * 2,
+        repeats: count - tiles.len(),
+    }
+}
@@ -0,0 +1,125 @@
+//! How the computer drives its paddle. It waits until the ball is clearly away from the middle
+//! of the paddle before chasing it, then eases in and out of moving rather than snapping
+//! between full speed up and down every frame.
+
+use agb::fixnum::Vector2D;
+
+use crate::entity::Entity;
+use crate::paddle::{Paddle, Side};
+use crate::random;
+use crate::strings::Text;
+use crate::tuning::{Fixed, Frames, AI_SERVES, AI_SERVE_DELAY, AI_SETTLE_DISTANCE};
+use crate::tweaks;
+
+/// How good the computer is
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn name(self) -> Text {
+        match self {
+            Difficulty::Easy => Text::Easy,
+            Difficulty::Normal => Text::Normal,
+            Difficulty::Hard => Text::Hard,
+        }
+    }
+}
+
+pub struct Ai {
+    difficulty: Difficulty,
+    /// How hard the paddle is being pushed, from -1 for full speed up to 1 for full speed down
+    push: Fixed,
+    /// Whether the ball was far enough off to be worth moving for
+    chasing: bool,
+    /// Frames left before serving, `None` until the computer has been given the ball
+    frames_until_serve: Option<Frames>,
+    /// Frames left before it notices the ball coming back towards it
+    reaction_frames_left: Frames,
+}
+
+impl Ai {
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self {
+            difficulty,
+            push: 0.into(),
+            chasing: false,
+            frames_until_serve: None,
+            reaction_frames_left: Frames::ZERO,
+        }
+    }
+
+    /// Waits a little while as if it's thinking, then picks one of its serves at random. Harder
+    /// computers are more likely to pick the nasty ones.
+    pub fn serve(&mut self) -> Option<Vector2D<Fixed>> {
+        let (shortest, longest) = AI_SERVE_DELAY;
+        let frames_left = self.frames_until_serve.get_or_insert_with(|| {
+            shortest + Frames(random::gen().rem_euclid((longest - shortest).0 as i32) as u32)
+        });
+
+        if *frames_left > Frames::ZERO {
+            frames_left.tick_down();
+            return None;
+        }
+        self.frames_until_serve = None;
+
+        let difficulty = self.difficulty as usize;
+        let total: u32 = AI_SERVES
+            .iter()
+            .map(|serve| serve.weights[difficulty])
+            .sum();
+        let mut pick = random::gen().rem_euclid(total as i32) as u32;
+        for serve in &AI_SERVES {
+            if pick < serve.weights[difficulty] {
+                return Some(serve.velocity);
+            }
+            pick -= serve.weights[difficulty];
+        }
+
+        // Only reachable if every weight was 0, which the tuning never does
+        Some(AI_SERVES[0].velocity)
+    }
+
+    /// Works out how to push the paddle this frame to get it towards the ball
+    #[link_section = ".iwram.ai"]
+    #[instruction_set(arm::a32)]
+    pub fn input(&mut self, paddle: &Paddle, ball: &Entity) -> Fixed {
+        let ball_centre = ball.position.y + ball.size().y / 2;
+        let error = ball_centre - paddle.centre_y();
+        let tweaks = tweaks::get();
+
+        let coming = match paddle.side() {
+            Side::Left => ball.velocity.x < 0.into(),
+            Side::Right => ball.velocity.x > 0.into(),
+        };
+        if !coming {
+            self.reaction_frames_left = tweaks.ai_reaction_frames;
+        } else if self.reaction_frames_left > Frames::ZERO {
+            self.reaction_frames_left.tick_down();
+            return self.push;
+        }
+
+        if error.abs() > tweaks.ai_dead_zone.into() {
+            self.chasing = true;
+        } else if error.abs() < AI_SETTLE_DISTANCE.into() {
+            self.chasing = false;
+        }
+
+        let target: Fixed = if !self.chasing {
+            0.into()
+        } else if error > 0.into() {
+            1.into()
+        } else {
+            (-1).into()
+        };
+
+        self.push += (target - self.push).clamp(-tweaks.ai_acceleration, tweaks.ai_acceleration);
+        self.push
+    }
+}
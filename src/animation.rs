@@ -0,0 +1,106 @@
+//! Helpers for animating things along a fixed timeline, used for cutscenes like the splash
+//! screen where everything moves the same way every time. Tracks say where things are, cues say
+//! what happens at a given frame, so a cutscene is written down as data instead of counting
+//! frames by hand.
+
+use agb::fixnum::Vector2D;
+
+use crate::events::GameEvent;
+use crate::strings::Text;
+
+/// Where something should be a number of frames into an animation
+#[derive(Clone, Copy)]
+pub struct Keyframe {
+    pub frame: u32,
+    pub position: Vector2D<i32>,
+}
+
+impl Keyframe {
+    pub const fn new(frame: u32, x: i32, y: i32) -> Self {
+        Self {
+            frame,
+            position: Vector2D::new(x, y),
+        }
+    }
+}
+
+/// A path made of keyframes, which must be in order of frame. Between two keyframes the
+/// position moves in a straight line, before the first and after the last it stays put.
+pub struct Track {
+    keyframes: &'static [Keyframe],
+}
+
+impl Track {
+    pub const fn new(keyframes: &'static [Keyframe]) -> Self {
+        Self { keyframes }
+    }
+
+    /// Where the track is at `frame`
+    pub fn position_at(&self, frame: u32) -> Vector2D<i32> {
+        let next = self.keyframes.iter().position(|key| key.frame > frame);
+
+        let (from, to) = match next {
+            Some(0) => return self.keyframes[0].position,
+            Some(next) => (self.keyframes[next - 1], self.keyframes[next]),
+            None => return self.keyframes[self.keyframes.len() - 1].position,
+        };
+
+        let elapsed = (frame - from.frame) as i32;
+        let length = (to.frame - from.frame) as i32;
+        from.position + (to.position - from.position) * elapsed / length
+    }
+
+    /// The frame the track reaches its last keyframe
+    pub fn end(&self) -> u32 {
+        self.keyframes.last().map_or(0, |key| key.frame)
+    }
+}
+
+/// Something that happens at a moment in a cutscene. What each one does is up to the scene
+/// playing it.
+#[derive(Clone, Copy)]
+pub enum Cue {
+    ShowSprite,
+    HideSprite,
+    /// Puts up some text
+    Prompt(Text),
+    /// Plays the sound an event makes
+    Sound(GameEvent),
+}
+
+/// A cue and the frame it happens on
+#[derive(Clone, Copy)]
+pub struct Cued {
+    pub frame: u32,
+    pub cue: Cue,
+}
+
+impl Cued {
+    pub const fn new(frame: u32, cue: Cue) -> Self {
+        Self { frame, cue }
+    }
+}
+
+/// Counts the frames since an animation started
+#[derive(Default)]
+pub struct Timeline {
+    frame: u32,
+}
+
+impl Timeline {
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    pub fn advance(&mut self) {
+        self.frame += 1;
+    }
+
+    /// The cues that happen on the current frame
+    pub fn cues<'c>(&self, cues: &'c [Cued]) -> impl Iterator<Item = Cue> + 'c {
+        let frame = self.frame;
+        cues.iter()
+            .filter(move |cued| cued.frame == frame)
+            .map(|cued| cued.cue)
+    }
+}
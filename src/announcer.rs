@@ -0,0 +1,95 @@
+//! The voice calling out the big moments of a match. Only one clip is said at a time on its own
+//! channel. A more important one cuts off whatever is being said, anything else waits its turn.
+
+use agb::fixnum::Num;
+use agb::sound::mixer::{ChannelId, Mixer, SoundChannel};
+
+use crate::events::GameEvent;
+use crate::sounds::{Sample, VOICE_ACE, VOICE_GAME, VOICE_MATCH_POINT};
+
+/// Things the announcer says, each more important than the ones before it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Clip {
+    Ace,
+    MatchPoint,
+    Game,
+}
+
+impl Clip {
+    fn for_event(event: GameEvent) -> Option<Clip> {
+        match event {
+            GameEvent::Goal { ace: true, .. } => Some(Clip::Ace),
+            GameEvent::MatchPoint { .. } => Some(Clip::MatchPoint),
+            GameEvent::MatchWon { .. } => Some(Clip::Game),
+            _ => None,
+        }
+    }
+
+    fn sample(self) -> Sample {
+        match self {
+            Clip::Ace => VOICE_ACE,
+            Clip::MatchPoint => VOICE_MATCH_POINT,
+            Clip::Game => VOICE_GAME,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Announcer {
+    /// The clip being said and the channel it's on
+    saying: Option<(Clip, ChannelId)>,
+    /// The most important clip waiting for the one being said to finish
+    next: Option<Clip>,
+}
+
+impl Announcer {
+    /// Says the clip for an event if it has one, straight away or once the current one is done
+    pub fn handle_event(&mut self, event: GameEvent, mixer: &mut Mixer) {
+        let Some(clip) = Clip::for_event(event) else {
+            return;
+        };
+
+        match self.current(mixer) {
+            Some(saying) if clip > saying => {
+                self.stop(mixer);
+                self.say(clip, mixer);
+            }
+            Some(_) => self.next = self.next.max(Some(clip)),
+            None => self.say(clip, mixer),
+        }
+    }
+
+    /// The clip still being said, if there is one
+    fn current(&self, mixer: &mut Mixer) -> Option<Clip> {
+        let (clip, id) = self.saying.as_ref()?;
+        mixer.channel(id).map(|_| *clip)
+    }
+
+    fn say(&mut self, clip: Clip, mixer: &mut Mixer) {
+        // High priority so the voice is never dropped for lack of a free channel
+        let sample = clip.sample();
+        let mut channel = SoundChannel::new_high_priority(sample.data);
+        channel.playback(Num::from_raw(sample.speed().to_raw() as u32));
+        self.saying = mixer.play_sound(channel).map(|id| (clip, id));
+    }
+
+    /// Moves on to the waiting clip once the one being said has finished
+    pub fn frame(&mut self, mixer: &mut Mixer) {
+        if self.current(mixer).is_none() {
+            self.saying = None;
+            if let Some(clip) = self.next.take() {
+                self.say(clip, mixer);
+            }
+        }
+    }
+
+    /// Cuts off the clip being said and forgets the one waiting
+    pub fn stop(&mut self, mixer: &mut Mixer) {
+        self.next = None;
+        if let Some((_, id)) = self.saying.take() {
+            if let Some(channel) = mixer.channel(&id) {
+                channel.stop();
+            }
+        }
+    }
+}
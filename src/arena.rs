@@ -0,0 +1,188 @@
+//! A bump allocator for the things that only last as long as a match, so a long session of
+//! matches can't leave the heap in EWRAM in pieces. Anything made with `MatchArena` as its
+//! allocator takes the next bytes of a fixed block, and the whole block is given back at once
+//! when the match scene has gone. It's given back after every other scene too, so the sprites
+//! the menus share with a match, like a paddle, come from it wherever they're made.
+//!
+//! Only the most recent allocation can grow or be freed in place, anything else freed during the
+//! match stays used until the end. If the block runs out, allocations go on the heap as usual.
+
+use core::alloc::{AllocError, Allocator, Layout};
+use core::cell::{Cell, UnsafeCell};
+use core::ptr::{self, NonNull};
+
+use agb::external::critical_section::{self, Mutex};
+use alloc::alloc::Global;
+use alloc::vec::Vec;
+
+use crate::tuning::MATCH_ARENA_BYTES;
+
+#[repr(align(8))]
+struct Block(UnsafeCell<[u8; MATCH_ARENA_BYTES]>);
+
+// Safety: the bytes are only reached through the allocations handed out, each of which is only
+// handed out once
+unsafe impl Sync for Block {}
+
+/// All zeroes, so it goes in `.bss` and doesn't take up room on the cartridge
+static BLOCK: Block = Block(UnsafeCell::new([0; MATCH_ARENA_BYTES]));
+
+#[derive(Clone, Copy)]
+struct State {
+    /// How far into the block has been handed out
+    used: usize,
+    /// Where the most recent allocation starts, the only one that can grow or shrink in place
+    last: Option<usize>,
+    /// The furthest into the block that's been used this match
+    peak: usize,
+    /// How many allocations haven't been freed yet
+    live: usize,
+    /// Whether the block has run out this match
+    spilled: bool,
+}
+
+impl State {
+    const EMPTY: State = State {
+        used: 0,
+        last: None,
+        peak: 0,
+        live: 0,
+        spilled: false,
+    };
+
+    fn take_up_to(&mut self, used: usize) {
+        self.used = used;
+        self.peak = self.peak.max(used);
+    }
+}
+
+static STATE: Mutex<Cell<State>> = Mutex::new(Cell::new(State::EMPTY));
+
+fn start() -> usize {
+    BLOCK.0.get() as usize
+}
+
+/// How far into the block `ptr` is, if it's in the block at all
+fn offset(ptr: NonNull<u8>) -> Option<usize> {
+    let offset = (ptr.as_ptr() as usize).checked_sub(start())?;
+    (offset < MATCH_ARENA_BYTES).then_some(offset)
+}
+
+/// The allocator for anything that belongs to a match
+#[derive(Clone, Copy, Default)]
+pub struct MatchArena;
+
+unsafe impl Allocator for MatchArena {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let taken = critical_section::with(|cs| {
+            let cell = STATE.borrow(cs);
+            let mut state = cell.get();
+            let offset = (start() + state.used).next_multiple_of(layout.align()) - start();
+            if offset + layout.size() > MATCH_ARENA_BYTES {
+                if !state.spilled {
+                    state.spilled = true;
+                    cell.set(state);
+                    #[cfg(feature = "debug-tools")]
+                    agb::println!("match arena is full, the rest of the match uses the heap");
+                }
+                return None;
+            }
+
+            state.take_up_to(offset + layout.size());
+            state.last = Some(offset);
+            state.live += 1;
+            cell.set(state);
+            Some(offset)
+        });
+
+        match taken {
+            Some(offset) => {
+                // Safety: the block starts at a real address, so nothing past it is null
+                let ptr = unsafe { NonNull::new_unchecked((start() + offset) as *mut u8) };
+                Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+            }
+            None => Global.allocate(layout),
+        }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        let Some(offset) = offset(ptr) else {
+            return Global.deallocate(ptr, layout);
+        };
+
+        critical_section::with(|cs| {
+            let cell = STATE.borrow(cs);
+            let mut state = cell.get();
+            state.live -= 1;
+            if state.last == Some(offset) {
+                state.used = offset;
+                state.last = None;
+            }
+            cell.set(state);
+        });
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // The most recent allocation can just take more of the block after it
+        let grown = offset(ptr).is_some_and(|offset| {
+            critical_section::with(|cs| {
+                let cell = STATE.borrow(cs);
+                let mut state = cell.get();
+                let fits = state.last == Some(offset)
+                    && ptr.as_ptr() as usize % new_layout.align() == 0
+                    && offset + new_layout.size() <= MATCH_ARENA_BYTES;
+                if fits {
+                    state.take_up_to(offset + new_layout.size());
+                    cell.set(state);
+                }
+                fits
+            })
+        });
+        if grown {
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.cast().as_ptr(), old_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+}
+
+/// Collects `items` into a `Vec` in the arena, `collect` only makes them on the heap
+pub fn collect<T>(items: impl IntoIterator<Item = T>) -> Vec<T, MatchArena> {
+    let mut collected = Vec::new_in(MatchArena);
+    collected.extend(items);
+    collected
+}
+
+/// Gives the whole block back, once the match and everything allocated for it has gone. If
+/// something from the match is somehow still around the block is left as it is, rather than
+/// handing out its bytes again.
+pub fn reset() {
+    let state = critical_section::with(|cs| STATE.borrow(cs).get());
+    if state.peak == 0 && !state.spilled {
+        return;
+    }
+
+    debug_assert_eq!(
+        state.live, 0,
+        "the match arena was reset while still in use"
+    );
+    if state.live > 0 {
+        return;
+    }
+
+    #[cfg(feature = "debug-tools")]
+    agb::println!(
+        "match used {} of {} bytes of its arena",
+        state.peak,
+        MATCH_ARENA_BYTES
+    );
+    critical_section::with(|cs| STATE.borrow(cs).set(State::EMPTY));
+}
@@ -0,0 +1,132 @@
+//! A vector that keeps its items inline with room for a fixed number of them, for the lists that
+//! get built up every frame. Nothing is allocated, so a frame takes the same time however the
+//! heap is doing.
+//!
+//! Running out of room is a bug, debug builds panic straight away so the capacity can be raised.
+//! Release builds drop whatever didn't fit rather than crash in the middle of a match.
+
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+
+pub struct ArrayVec<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    /// How many of `items` are set, always the ones at the start
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            items: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn push(&mut self, item: T) {
+        debug_assert!(!self.is_full(), "no room for more than {} items", N);
+        if self.is_full() {
+            return;
+        }
+        self.items[self.len].write(item);
+        self.len += 1;
+    }
+
+    /// Drops everything after the first `len` items
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.len -= 1;
+            // Safety: the item was set and `len` no longer covers it, so it's never read again
+            unsafe { self.items[self.len].assume_init_drop() };
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the items `keep` returns true for, in the order they were in
+    pub fn retain_mut(&mut self, mut keep: impl FnMut(&mut T) -> bool) {
+        let mut kept = 0;
+        for index in 0..self.len {
+            if keep(&mut self[index]) {
+                self.swap(kept, index);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    /// Takes every item out, leaving it empty. Any the iterator isn't run through to are
+    /// forgotten without being dropped.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        let len = core::mem::take(&mut self.len);
+        // Safety: the first `len` items are set, and with `len` at 0 each is only read once
+        (0..len).map(|index| unsafe { self.items[index].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // Safety: the first `len` items are set
+        unsafe { core::slice::from_raw_parts(self.items.as_ptr().cast(), self.len) }
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // Safety: the first `len` items are set
+        unsafe { core::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast(), self.len) }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut array = Self::new();
+        array.extend(items);
+        array
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,141 @@
+//! Every sprite the game draws, loaded from the aseprite files in one place. Anything new that
+//! needs a sprite adds its tag to `SpriteAssets` rather than looking it up itself, `build.rs`
+//! reads this file to check every tag asked for is in its sheet.
+//!
+//! Each aseprite file is its own set of graphics, so a new sheet can be added without making
+//! the others any bigger. They only sit in the cartridge until they're needed, a sprite is put
+//! into video memory when something on screen first uses it and freed again once nothing does.
+//! So each scene only ever takes up room for the sprites it is showing.
+
+use agb::display::object::{Graphics, Tag};
+use agb::include_aseprite;
+
+/// The ball and the first paddle, from the original tutorial
+static CLASSIC: &Graphics = include_aseprite!("gfx/sprites.aseprite");
+/// The rest of the characters' paddles
+static PADDLES: &Graphics = include_aseprite!("gfx/paddles.aseprite");
+/// Everything that appears during a rally, like popups, power ups and confetti
+static EFFECTS: &Graphics = include_aseprite!("gfx/effects.aseprite");
+static HUD: &Graphics = include_aseprite!("gfx/hud.aseprite");
+/// 8x8 digits from 0 to 9, drawn by `Digits`
+static DIGITS: &Graphics = include_aseprite!("gfx/digits.aseprite");
+/// Capital letters in three colours, drawn by `Marquee`
+static LETTERS: &Graphics = include_aseprite!("gfx/letters.aseprite");
+/// The people watching along the top of the court
+static CROWD: &Graphics = include_aseprite!("gfx/crowd.aseprite");
+
+pub struct SpriteAssets {
+    /// The ends and middles of each character's paddle
+    pub paddle_end: &'static Tag,
+    pub paddle_mid: &'static Tag,
+    pub dash_end: &'static Tag,
+    pub dash_mid: &'static Tag,
+    pub tower_end: &'static Tag,
+    pub tower_mid: &'static Tag,
+    pub ball: &'static Tag,
+    /// The bright flash any paddle shows when it hits, the same whichever character it is
+    pub flash_end: &'static Tag,
+    pub flash_mid: &'static Tag,
+    /// The score popups
+    pub plus_one: &'static Tag,
+    pub plus_two: &'static Tag,
+    pub power_up_shield: &'static Tag,
+    pub power_up_split: &'static Tag,
+    pub power_up_curse: &'static Tag,
+    pub wall: &'static Tag,
+    pub shield: &'static Tag,
+    /// Spins over a paddle while its controls are cursed
+    pub curse: &'static Tag,
+    pub aim_dot: &'static Tag,
+    /// What falls on the courts that have weather
+    pub raindrop: &'static Tag,
+    pub snowflake: &'static Tag,
+    /// The dots left behind the ball, shrinking with each frame. There's one for nobody having
+    /// hit it yet and one in each side's colour.
+    pub trail: &'static Tag,
+    pub trail_left: &'static Tag,
+    pub trail_right: &'static Tag,
+    pub hint: &'static Tag,
+    /// The swirl of each portal on the court
+    pub portal: &'static Tag,
+    /// A bumper sitting still, then lit up and fading after the ball hits it
+    pub bumper: &'static Tag,
+    /// The pieces a broken shield flies apart into
+    pub shard: &'static Tag,
+    pub confetti: [&'static Tag; 3],
+    /// The special energy meter in the corner
+    pub meter: &'static Tag,
+    /// A frame for each digit from 0 to 9
+    pub digits: &'static Tag,
+    /// Every capital letter, an exclamation mark and Ü, in each colour one after another
+    pub letters: &'static Tag,
+    /// Someone in the crowd swaying, then jumping with their arms up
+    pub spectator: &'static Tag,
+    pub cheer: &'static Tag,
+}
+
+/// What the ball looks like, picked from the extras once it's unlocked. Each is another of the
+/// 16x16 sprites so it fits the ball's collision box.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BallSkin {
+    #[default]
+    Classic,
+    /// The spinning curse swirl
+    Swirl,
+    /// A piece of a broken shield
+    Shard,
+}
+
+impl BallSkin {
+    pub const ALL: [BallSkin; 3] = [BallSkin::Classic, BallSkin::Swirl, BallSkin::Shard];
+}
+
+impl SpriteAssets {
+    pub fn ball_skin(&self, skin: BallSkin) -> &'static Tag {
+        match skin {
+            BallSkin::Classic => self.ball,
+            BallSkin::Swirl => self.curse,
+            BallSkin::Shard => self.shard,
+        }
+    }
+}
+
+pub static SPRITES: SpriteAssets = SpriteAssets {
+    paddle_end: CLASSIC.tags().get("Paddle End"),
+    paddle_mid: CLASSIC.tags().get("Paddle Mid"),
+    dash_end: PADDLES.tags().get("Dash End"),
+    dash_mid: PADDLES.tags().get("Dash Mid"),
+    tower_end: PADDLES.tags().get("Tower End"),
+    tower_mid: PADDLES.tags().get("Tower Mid"),
+    ball: CLASSIC.tags().get("Ball"),
+    flash_end: CLASSIC.tags().get("Flash End"),
+    flash_mid: CLASSIC.tags().get("Flash Mid"),
+    plus_one: EFFECTS.tags().get("Plus One"),
+    plus_two: EFFECTS.tags().get("Plus Two"),
+    power_up_shield: EFFECTS.tags().get("Power Up Shield"),
+    power_up_split: EFFECTS.tags().get("Power Up Split"),
+    power_up_curse: EFFECTS.tags().get("Power Up Curse"),
+    wall: EFFECTS.tags().get("Wall"),
+    shield: EFFECTS.tags().get("Shield"),
+    curse: EFFECTS.tags().get("Curse"),
+    aim_dot: EFFECTS.tags().get("Aim Dot"),
+    raindrop: EFFECTS.tags().get("Raindrop"),
+    snowflake: EFFECTS.tags().get("Snowflake"),
+    trail: EFFECTS.tags().get("Trail"),
+    trail_left: EFFECTS.tags().get("Trail Left"),
+    trail_right: EFFECTS.tags().get("Trail Right"),
+    hint: EFFECTS.tags().get("Hint"),
+    portal: EFFECTS.tags().get("Portal"),
+    bumper: EFFECTS.tags().get("Bumper"),
+    shard: EFFECTS.tags().get("Shard"),
+    confetti: [
+        EFFECTS.tags().get("Confetti Red"),
+        EFFECTS.tags().get("Confetti Yellow"),
+        EFFECTS.tags().get("Confetti Blue"),
+    ],
+    meter: HUD.tags().get("Meter"),
+    digits: DIGITS.tags().get("Digits"),
+    letters: LETTERS.tags().get("Letters"),
+    spectator: CROWD.tags().get("Spectator"),
+    cheer: CROWD.tags().get("Cheer"),
+};
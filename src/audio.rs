@@ -0,0 +1,170 @@
+//! Plays the sound effects for what happens in the game. The sounds themselves are in `sounds`.
+
+use agb::fixnum::Num;
+use agb::sound::mixer::{ChannelId, Frequency, Mixer, MixerController, SoundChannel};
+use alloc::vec::Vec;
+
+use crate::announcer::Announcer;
+use crate::events::{GameEvent, Impact};
+use crate::music::{Jukebox, Music};
+use crate::sounds::{
+    Sample, ACE, BUMPER, EDGE_SHOT, GOAL, PADDLE_HITS, POWER_UP, RALLY, SHIELD_BREAK, SPECIAL,
+    VICTORY, WALL_BOUNCES,
+};
+use crate::tuning::{
+    Fixed, BOUNCE_PITCH_MAX, BOUNCE_PITCH_STEP, BOUNCE_PITCH_WOBBLE, IMPACT_HARDNESS_SPEEDS,
+    IMPACT_LOUDEST_SPEED, IMPACT_QUIETEST_VOLUME, SOUND_PANNING_WIDTH,
+};
+use crate::view;
+
+pub struct Audio<'a> {
+    mixer: Mixer<'a>,
+    /// Every sound that might still be going, so they can all be cut off
+    playing: Vec<ChannelId>,
+    /// Returns since the last goal, bounces go up in pitch the longer a rally goes on
+    returns: u32,
+    /// How many bounces have been played, for stepping through `BOUNCE_PITCH_WOBBLE`
+    bounces: usize,
+    announcer: Announcer,
+    jukebox: Jukebox,
+}
+
+impl<'a> Audio<'a> {
+    pub fn new(mixer_controller: &'a mut MixerController) -> Self {
+        let mut mixer = mixer_controller.mixer(Frequency::Hz18157);
+        mixer.enable();
+
+        Self {
+            mixer,
+            playing: Vec::new(),
+            returns: 0,
+            bounces: 0,
+            announcer: Announcer::default(),
+            jukebox: Jukebox::default(),
+        }
+    }
+
+    /// Plays the sound effect for an event, if it has one
+    pub fn handle_event(&mut self, event: GameEvent) {
+        match event {
+            GameEvent::PaddleHit { .. } | GameEvent::EdgeShot { .. } => self.returns += 1,
+            GameEvent::Goal { .. } => self.returns = 0,
+            GameEvent::MatchWon { .. } => {
+                self.returns = 0;
+                self.play_music(Some(Music::Victory));
+            }
+            _ => {}
+        }
+        self.announcer.handle_event(event, &mut self.mixer);
+
+        let sound: Sample = match event {
+            GameEvent::PaddleHit { impact, .. } => by_hardness(&PADDLE_HITS, impact),
+            GameEvent::EdgeShot { .. } => EDGE_SHOT,
+            GameEvent::WallBounce { impact } => by_hardness(&WALL_BOUNCES, impact),
+            GameEvent::Goal { ace: true, .. } => ACE,
+            GameEvent::Goal { .. } => GOAL,
+            GameEvent::SpecialUsed { .. } => SPECIAL,
+            GameEvent::PowerUpCollected { .. } => POWER_UP,
+            GameEvent::ShieldBroken { .. } => SHIELD_BREAK,
+            GameEvent::BumperHit => BUMPER,
+            GameEvent::MatchWon { .. } => VICTORY,
+            GameEvent::RallyMilestone { .. } => RALLY,
+            // Only the announcer has anything to say about this
+            GameEvent::MatchPoint { .. } => return,
+        };
+
+        // Harder hits are louder
+        let volume: Fixed = match event {
+            GameEvent::PaddleHit { impact, .. }
+            | GameEvent::EdgeShot { impact, .. }
+            | GameEvent::WallBounce { impact } => {
+                (impact.speed / IMPACT_LOUDEST_SPEED.0).clamp(IMPACT_QUIETEST_VOLUME, 1.into())
+            }
+            _ => 1.into(),
+        };
+
+        // Played from whichever side of the screen it happened on
+        let x = match event {
+            GameEvent::PaddleHit { impact, .. }
+            | GameEvent::EdgeShot { impact, .. }
+            | GameEvent::WallBounce { impact } => Some(impact.point.x),
+            GameEvent::Goal { position, .. } | GameEvent::ShieldBroken { position } => {
+                Some(position.x)
+            }
+            _ => None,
+        };
+        let panning = x.map_or(0.into(), panning);
+
+        // Bounces go up as the rally goes on
+        let pitch = match event {
+            GameEvent::PaddleHit { .. } | GameEvent::WallBounce { .. } => self.bounce_pitch(),
+            _ => 1.into(),
+        };
+
+        let mut channel = SoundChannel::new(sound.data);
+        channel
+            .playback(Num::from_raw((pitch * sound.speed()).to_raw() as u32))
+            .volume(Num::from_raw(volume.to_raw() as i16))
+            .panning(Num::from_raw(panning.to_raw() as i16));
+        let mixer = &mut self.mixer;
+        self.playing.retain(|id| mixer.channel(id).is_some());
+        if let Some(id) = mixer.play_sound(channel) {
+            self.playing.push(id);
+        }
+    }
+
+    /// Crossfades over to `music`, or fades out to silence for `None`
+    pub fn play_music(&mut self, music: Option<Music>) {
+        self.jukebox.play(music, &mut self.mixer);
+    }
+
+    /// How loud the music is, from the options
+    pub fn set_music_volume(&mut self, volume: Fixed) {
+        self.jukebox.set_volume(volume);
+    }
+
+    /// How fast to play the next bounce, a little higher for each return in the rally and a
+    /// little off from the last one so a long rally doesn't tick like a metronome
+    fn bounce_pitch(&mut self) -> Fixed {
+        let ramp = (BOUNCE_PITCH_STEP * self.returns as i32).min(BOUNCE_PITCH_MAX);
+        let wobble = BOUNCE_PITCH_WOBBLE[self.bounces % BOUNCE_PITCH_WOBBLE.len()];
+        self.bounces += 1;
+        Fixed::new(1) + ramp + wobble
+    }
+
+    /// Cuts off every sound that's playing and starts the rally pitch again
+    pub fn stop(&mut self) {
+        self.returns = 0;
+        self.announcer.stop(&mut self.mixer);
+        for id in self.playing.drain(..) {
+            if let Some(channel) = self.mixer.channel(&id) {
+                channel.stop();
+            }
+        }
+    }
+
+    /// Does the mixing for this frame. It's only called from the main loop as it waits for each
+    /// vblank, so scenes never have to think about it however they pause or slow down.
+    pub fn frame(&mut self) {
+        self.announcer.frame(&mut self.mixer);
+        self.jukebox.frame(&mut self.mixer);
+        self.mixer.frame();
+    }
+}
+
+/// How far left or right to play a sound from `x` across the court, going by where that is on
+/// screen so it follows the court being mirrored
+fn panning(x: i32) -> Fixed {
+    let half_width = agb::display::WIDTH / 2;
+    let from_middle = Fixed::new(view::x(x, 0) - half_width) / half_width;
+    from_middle.clamp(Fixed::new(-1), Fixed::new(1)) * SOUND_PANNING_WIDTH
+}
+
+/// The soft, normal or hard one of `sounds`, going by how fast `impact` was
+fn by_hardness(sounds: &[Sample; 3], impact: Impact) -> Sample {
+    let hardness = IMPACT_HARDNESS_SPEEDS
+        .iter()
+        .filter(|&&speed| impact.speed >= speed.0)
+        .count();
+    sounds[hardness]
+}
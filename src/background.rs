@@ -0,0 +1,227 @@
+//! The tiled backgrounds drawn behind the sprites. Each scene picks the backdrop it sits on and
+//! clears it when it leaves, so the tiles are free again for whatever comes next.
+
+use agb::display::tile_data::TileData;
+use agb::display::tiled::{
+    MapLoan, RegularBackgroundSize, RegularMap, TileFormat, TileSetting, Tiled0, TiledMap,
+    VRamManager,
+};
+use agb::display::Priority;
+use agb::include_background_gfx;
+
+use crate::colour::{self, rgb15};
+use crate::court::Court;
+use crate::text;
+use crate::tuning::{fixed, Fixed};
+use crate::view;
+use crate::weather::WeatherKind;
+
+// The full screen ones are mostly the same few tiles over and over, so each tile is only kept
+// once however many times it's used
+include_background_gfx!(
+    gfx,
+    "181425",
+    court => deduplicate "gfx/court.png",
+    net => deduplicate "gfx/net.png",
+    crt => deduplicate "gfx/crt.png",
+    boundary => "gfx/boundary.png",
+    title => deduplicate "gfx/title.png",
+    panel => deduplicate "gfx/panel.png"
+);
+
+/// The palettes the two halves of the lopsided court are drawn with, left then right. They're
+/// copies of the court's own colours, so nothing else uses them.
+const HALF_PALETTES: [u8; 2] = [14, 15];
+/// What each half of the lopsided court is tinted towards and how far, warm for the half that
+/// speeds the ball up and cold for the one that slows it down
+const HALF_TINTS: [(u16, Fixed); 2] = [
+    (rgb15(0xd77643), fixed(1, 4)),
+    (rgb15(0x3978a8), fixed(1, 4)),
+];
+
+/// The full screen pictures a scene can be drawn on top of
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backdrop {
+    Title,
+    /// A plain panel for the menus to sit on
+    Panel,
+    Court,
+}
+
+/// What the court looks like, picked from the extras once it's unlocked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CourtTheme {
+    #[default]
+    Classic,
+    /// The plain menu panel with just the walls drawn over it
+    Plain,
+    /// The classic court with weather falling over it
+    Rainy,
+    Snowy,
+}
+
+impl CourtTheme {
+    pub const ALL: [CourtTheme; 4] = [
+        CourtTheme::Classic,
+        CourtTheme::Plain,
+        CourtTheme::Rainy,
+        CourtTheme::Snowy,
+    ];
+
+    pub fn backdrop(self) -> Backdrop {
+        match self {
+            CourtTheme::Classic | CourtTheme::Rainy | CourtTheme::Snowy => Backdrop::Court,
+            CourtTheme::Plain => Backdrop::Panel,
+        }
+    }
+
+    pub fn weather(self) -> Option<WeatherKind> {
+        match self {
+            CourtTheme::Classic | CourtTheme::Plain => None,
+            CourtTheme::Rainy => Some(WeatherKind::Rain),
+            CourtTheme::Snowy => Some(WeatherKind::Snow),
+        }
+    }
+}
+
+/// Loads the palettes every background shares, must be done before any of them are shown
+pub fn set_palettes(vram: &mut VRamManager) {
+    vram.set_background_palettes(gfx::PALETTES);
+}
+
+/// The colour at `index` of the palette the court is drawn with, before anything has changed it
+pub fn court_colour(index: usize) -> u16 {
+    gfx::PALETTES[0].colour(index)
+}
+
+/// Creates `backdrop` on the lowest priority layer so everything is drawn over it
+pub fn backdrop<'a>(
+    tiled: &'a Tiled0,
+    vram: &mut VRamManager,
+    backdrop: Backdrop,
+) -> MapLoan<'a, RegularMap> {
+    let mut background = tiled.background(
+        Priority::P3,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+    background.fill_with(vram, backdrop_gfx(backdrop));
+    background.commit(vram);
+    background.set_visible(true);
+
+    background
+}
+
+fn backdrop_gfx(backdrop: Backdrop) -> &'static TileData {
+    match backdrop {
+        Backdrop::Title => &gfx::title,
+        Backdrop::Panel => &gfx::panel,
+        Backdrop::Court => &gfx::court,
+    }
+}
+
+/// Draws each half of `backdrop` with its own tinted palette for the lopsided court. The walls
+/// are drawn over it afterwards so they stay the same colour.
+pub fn tint_halves(
+    background: &mut MapLoan<RegularMap>,
+    vram: &mut VRamManager,
+    backdrop: Backdrop,
+    court: &Court,
+) {
+    for index in 0..text::LOCKED as usize {
+        set_court_colour(vram, index, court_colour(index));
+    }
+
+    let gfx = backdrop_gfx(backdrop);
+    for row in 0..(agb::display::HEIGHT / 8) as u16 {
+        for column in 0..(agb::display::WIDTH / 8) as u16 {
+            // The court's halves swap sides when it's mirrored
+            let x = view::x(column as i32 * 8, 8);
+            let half = if x < court.centre_x() { 0 } else { 1 };
+            let tile = gfx.tile_settings[row as usize * 30 + column as usize];
+            background.set_tile(
+                vram,
+                (column, row),
+                &gfx.tiles,
+                tile.palette(HALF_PALETTES[half]),
+            );
+        }
+    }
+    background.commit(vram);
+}
+
+/// Changes the colour at `index` of the court's palette, along with the tinted copies of it the
+/// lopsided court is drawn with
+pub fn set_court_colour(vram: &mut VRamManager, index: usize, colour: u16) {
+    vram.set_background_palette_colour(0, index, colour);
+    for (palette, (tint, amount)) in HALF_PALETTES.into_iter().zip(HALF_TINTS) {
+        vram.set_background_palette_colour(
+            palette as usize,
+            index,
+            colour::lerp(colour, tint, amount),
+        );
+    }
+}
+
+/// Fills in the rows of the court background that are behind the top and bottom walls
+pub fn draw_walls(background: &mut MapLoan<RegularMap>, vram: &mut VRamManager, court: &Court) {
+    let area = court.rect();
+    let top_rows = (area.position.y / 8) as u16;
+    let bottom_row = ((area.position.y + area.size.y) / 8) as u16;
+    let screen_rows = (agb::display::HEIGHT / 8) as u16;
+    let screen_columns = (agb::display::WIDTH / 8) as u16;
+    let tile = gfx::boundary.tile_settings[0];
+
+    for row in (0..top_rows).chain(bottom_row..screen_rows) {
+        for column in 0..screen_columns {
+            background.set_tile(vram, (column, row), &gfx::boundary.tiles, tile);
+        }
+    }
+    background.commit(vram);
+}
+
+/// Empties a whole row of tiles on `background`, it has to be committed afterwards
+pub fn clear_row(background: &mut MapLoan<RegularMap>, vram: &mut VRamManager, row: u16) {
+    for column in 0..32 {
+        background.set_tile(
+            vram,
+            (column, row),
+            &gfx::boundary.tiles,
+            TileSetting::BLANK,
+        );
+    }
+}
+
+/// Creates the net down the middle of the court. It sits above the sprites so that it can be
+/// blended over the ball as it passes through. With the crt overlay on it also has scanlines
+/// and darker corners across the whole screen, which get blended the same way as the net so
+/// they don't need the blend registers to themselves.
+pub fn net<'a>(
+    tiled: &'a Tiled0,
+    vram: &mut VRamManager,
+    crt_overlay: bool,
+) -> MapLoan<'a, RegularMap> {
+    let mut net = tiled.background(
+        Priority::P0,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+    net.fill_with(vram, if crt_overlay { &gfx::crt } else { &gfx::net });
+    net.commit(vram);
+    net.set_visible(true);
+
+    net
+}
+
+/// Creates an empty background in front of everything for text to be written on
+pub fn text<'a>(tiled: &'a Tiled0, vram: &mut VRamManager) -> MapLoan<'a, RegularMap> {
+    let mut text = tiled.background(
+        Priority::P0,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+    text.commit(vram);
+    text.set_visible(true);
+
+    text
+}
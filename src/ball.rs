@@ -0,0 +1,294 @@
+use agb::display::affine::AffineMatrix;
+use agb::display::object::{AffineMatrixInstance, AffineMode, OamManaged};
+use agb::fixnum::Vector2D;
+
+use crate::assets::{BallSkin, SPRITES};
+use crate::collision::Layer;
+use crate::court::Court;
+use crate::entity::Entity;
+use crate::events::{Events, GameEvent, Impact};
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::physics;
+use crate::tuning::{
+    Fixed, Frames, BALL_COLLISION_MASK, BALL_SPEED_RAMP_RETURNS, BALL_SPIN_SPEED, CURVE_SPIN,
+    DAMPEN_REPEATED_WALL_BOUNCES, HIGH_SPEED_THRESHOLD, MINIMUM_SPIN, PORTAL_COOLDOWN_FRAMES,
+    SMASH_SPIN_FRAMES, SPINNING_BALL_SCALE, SPIN_DECAY, SPLIT_FRAMES,
+    WALL_BOUNCES_BEFORE_DAMPENING, WALL_BOUNCE_DAMPENING,
+};
+use crate::tweaks;
+use crate::view;
+
+/// Whether a ball is the one the point is being played with or a copy from a split
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BallKind {
+    Real,
+    /// A copy that disappears after a while
+    Split {
+        frames_left: u32,
+    },
+}
+
+/// Ball struct that holds the sprite of the ball
+pub struct Ball<'a> {
+    pub entity: Entity<'a>,
+    pub kind: BallKind,
+    /// The side whose paddle last sent the ball back, nobody's straight after a serve
+    pub last_hit_by: Option<Side>,
+    /// How many times the ball has hit the top or bottom of the screen since a paddle last hit it
+    wall_bounces_since_paddle_hit: u32,
+    /// Frames left of the spin after a smash return
+    smash_frames_left: Frames,
+    /// How far round the spinning ball is, in full turns
+    rotation: Fixed,
+    /// Bends the ball's path sideways to the way it's going, wearing off a little each frame
+    spin: Fixed,
+    /// Frames left before the ball can go through a portal again
+    portal_frames_left: Frames,
+    /// What the ball looks like, kept so copies from a split look the same
+    skin: BallSkin,
+    /// How many times a paddle has sent it back since the serve, for the speed ramp
+    returns: u32,
+}
+
+/// Impl of ball to allow for methods to interact with the sprite
+impl<'a> Ball<'a> {
+    pub fn new(object: &'a OamManaged, skin: BallSkin) -> Self {
+        // Where it starts doesn't matter, it is held by the server before anything can happen
+        let mut ball = Entity::new(
+            object,
+            SPRITES.ball_skin(skin).sprite(0),
+            BALL_COLLISION_MASK.into(),
+            Layer::Ball,
+            RenderLayer::Ball,
+            (0, 0).into(),
+            (0, 0).into(),
+        );
+        ball.sprite.show();
+        let mut ball = Self {
+            entity: ball,
+            kind: BallKind::Real,
+            last_hit_by: None,
+            wall_bounces_since_paddle_hit: 0,
+            smash_frames_left: Frames::ZERO,
+            rotation: 0.into(),
+            spin: 0.into(),
+            portal_frames_left: Frames::ZERO,
+            skin,
+            returns: 0,
+        };
+        ball.serve();
+        ball
+    }
+
+    /// Gets the ball ready to be served. It stays still wherever it is held until it's launched.
+    pub fn serve(&mut self) {
+        self.entity.velocity = (0, 0).into();
+        self.wall_bounces_since_paddle_hit = 0;
+        self.smash_frames_left = Frames::ZERO;
+        self.spin = 0.into();
+        self.portal_frames_left = Frames::ZERO;
+        self.last_hit_by = None;
+        self.returns = 0;
+    }
+
+    /// Keeps the ball at `position` while it waits to be served
+    pub fn hold(&mut self, position: Vector2D<Fixed>) {
+        self.entity.position = position;
+    }
+
+    /// Moves the ball to `position` after going through a portal, keeping its velocity. It can't
+    /// go through another one until it has had time to get clear.
+    pub fn teleport(&mut self, position: Vector2D<Fixed>) {
+        self.entity.position = position;
+        self.portal_frames_left = PORTAL_COOLDOWN_FRAMES;
+    }
+
+    pub fn can_teleport(&self) -> bool {
+        self.portal_frames_left == Frames::ZERO
+    }
+
+    /// Sends a held ball off with `velocity`
+    pub fn launch(&mut self, velocity: Vector2D<Fixed>) {
+        self.entity.velocity = velocity;
+    }
+
+    /// Makes a copy of the ball heading off the other way vertically, so the two split apart
+    pub fn split(&self, object: &'a OamManaged) -> Self {
+        let mut split = Ball::new(object, self.skin);
+        split.kind = BallKind::Split {
+            frames_left: SPLIT_FRAMES.0,
+        };
+        split.last_hit_by = self.last_hit_by;
+        split.entity.position = self.entity.position;
+
+        let velocity = self.entity.velocity;
+        // A ball going straight across would just stay on top of its copy
+        let velocity_y = if velocity.y == 0.into() {
+            Fixed::new(1)
+        } else {
+            -velocity.y
+        };
+        split.entity.velocity = (velocity.x, velocity_y).into();
+        split
+    }
+
+    /// Counts down the life of a copy from a split, returns false once it should be removed
+    pub fn update_lifetime(&mut self) -> bool {
+        match &mut self.kind {
+            BallKind::Real => true,
+            BallKind::Split { frames_left } => {
+                *frames_left = frames_left.saturating_sub(1);
+                *frames_left > 0
+            }
+        }
+    }
+
+    /// Makes the ball spin and grow for a while after a smash return
+    pub fn smash(&mut self) {
+        self.smash_frames_left = SMASH_SPIN_FRAMES;
+    }
+
+    /// Spins the ball so it bends back the other way vertically, used by the curveball special
+    pub fn curve(&mut self) {
+        let velocity = self.entity.velocity;
+        let heading_up = velocity.y < 0.into();
+        let heading_right = velocity.x > 0.into();
+        self.spin = if heading_up == heading_right {
+            CURVE_SPIN
+        } else {
+            -CURVE_SPIN
+        };
+    }
+
+    /// How much the spin is pushing the ball sideways this frame
+    pub fn spin_acceleration(&self) -> Vector2D<Fixed> {
+        let velocity = self.entity.velocity;
+        Vector2D::new(-velocity.y, velocity.x) * self.spin
+    }
+
+    /// Whether the ball should be drawn spinning, either just after a smash or when it is going fast
+    fn is_spinning(&self) -> bool {
+        self.smash_frames_left > Frames::ZERO
+            || self.entity.velocity.magnitude() >= HIGH_SPEED_THRESHOLD.0
+    }
+
+    /// Moves the sprite to match the ball. A spinning ball uses an affine object so it can be
+    /// rotated and scaled, otherwise it goes back to being a regular object.
+    pub fn update_sprite(&mut self) {
+        if !self.is_spinning() {
+            self.rotation = 0.into();
+            self.entity.sprite.show();
+            self.entity.update_sprite_position();
+            return;
+        }
+
+        self.smash_frames_left.tick_down();
+        self.rotation += BALL_SPIN_SPEED;
+
+        // The scale is the inverse of how big the sprite appears, the gba maps from the screen
+        // back to the sprite
+        let scale = Fixed::new(1) / SPINNING_BALL_SCALE;
+        let matrix = AffineMatrix::from_rotation(self.rotation)
+            * AffineMatrix::from_scale((scale, scale).into());
+
+        // A double sized affine object is drawn in a box twice the size of the sprite with
+        // the sprite in the middle, so it has to move up and left by half a sprite to line up
+        let size: Vector2D<i32> = (
+            self.entity.collision_mask.x as i32,
+            self.entity.collision_mask.y as i32,
+        )
+            .into();
+        let position = view::position(self.entity.position.floor(), size);
+
+        self.entity
+            .sprite
+            .set_affine_matrix(AffineMatrixInstance::new(matrix.to_object_wrapping()))
+            .show_affine(AffineMode::AffineDouble)
+            .set_position(position - size / 2);
+    }
+
+    /// Keeps the ball within the bounds of the screen not allowing it to move pass the limit
+    pub fn checks_and_keeps_in_bounds(&mut self, court: &Court) {
+        let size = self.entity.size();
+        if self.spin != 0.into() {
+            self.entity.velocity += self.spin_acceleration();
+            self.spin *= SPIN_DECAY;
+            if self.spin.abs() < MINIMUM_SPIN {
+                self.spin = 0.into();
+            }
+        }
+        self.portal_frames_left.tick_down();
+
+        self.entity.velocity =
+            court.half_court_velocity(self.entity.position, self.entity.velocity);
+        let position = physics::integrate(self.entity.position, self.entity.velocity, Frames(1));
+        self.entity.position = physics::clamp_to_court(position, court, size);
+    }
+
+    /// Checks if the ball has reached the left or right edge of the court, returning the
+    /// side that scored
+    pub fn scoring_side(&self, court: &Court) -> Option<Side> {
+        if self.entity.position.x == court.left() {
+            Some(Side::Right)
+        } else if self.entity.position.x == court.right() - self.entity.size().x {
+            Some(Side::Left)
+        } else {
+            None
+        }
+    }
+
+    /// Bounces the ball if it hits the top or bottom wall of the court
+    pub fn bounce_if_hits_screen_bounds(&mut self, court: &Court, events: &mut Events) {
+        let lowest = court.bottom() - self.entity.size().y;
+        let at_top = self.entity.position.y == court.top();
+        if at_top || self.entity.position.y == lowest {
+            let size = self.entity.size();
+            let y = if at_top { 0 } else { size.y };
+            let impact = Impact {
+                point: self.entity.position.floor() + (size.x / 2, y).into(),
+                speed: self.entity.velocity.y.abs(),
+            };
+            self.entity.velocity = physics::reflect(self.entity.velocity, (0, 1).into());
+            self.wall_bounces_since_paddle_hit += 1;
+
+            // A ball bouncing between the top and bottom over and over without a paddle
+            // touching it loses some of its vertical speed each time
+            if DAMPEN_REPEATED_WALL_BOUNCES
+                && self.wall_bounces_since_paddle_hit > WALL_BOUNCES_BEFORE_DAMPENING
+            {
+                self.entity.velocity.y *= WALL_BOUNCE_DAMPENING;
+            }
+
+            events.push(GameEvent::WallBounce { impact });
+        }
+    }
+
+    /// Sends the ball back the way it came after it hits a wall in front of a goal
+    pub fn bounce_off_goal_wall(&mut self, events: &mut Events) {
+        let impact = Impact {
+            point: self.entity.position.floor() + self.entity.size() / 2,
+            speed: self.entity.velocity.x.abs(),
+        };
+        self.entity.velocity = physics::reflect(self.entity.velocity, (1, 0).into());
+        events.push(GameEvent::WallBounce { impact });
+    }
+
+    /// Sends the ball off with a new velocity after a paddle hits it
+    pub fn hit_by_paddle(&mut self, velocity: Vector2D<Fixed>) {
+        // Each return sends it a little faster than the bounce alone would, up to a limit
+        self.returns = (self.returns + 1).min(BALL_SPEED_RAMP_RETURNS);
+        let ramp = Fixed::new(1) + tweaks::get().ball_speed_ramp * self.returns as i32;
+        self.entity.velocity = velocity * ramp;
+        self.wall_bounces_since_paddle_hit = 0;
+    }
+
+    /// Stops the ball from ending up bouncing almost vertically forever by making sure
+    /// it is always moving towards one of the paddles at a minimum speed
+    pub fn keep_moving_horizontally(&mut self) {
+        self.entity.velocity = physics::with_minimum_horizontal_speed(
+            self.entity.velocity,
+            tweaks::get().minimum_horizontal_speed,
+        );
+    }
+}
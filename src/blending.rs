@@ -0,0 +1,83 @@
+//! The GBA only has one set of blend registers, so everything that wants blending asks for it
+//! here rather than writing to them directly. That way turning one effect on doesn't silently
+//! undo another one.
+
+use agb::display::blend::{Blend, BlendMode, Layer};
+use agb::display::tiled::BackgroundID;
+use agb::fixnum::Num;
+
+use crate::tuning::{NET_BENEATH_WEIGHT, NET_WEIGHT};
+
+/// A background that should be drawn see through over everything beneath it
+#[derive(Clone, Copy)]
+struct Translucent {
+    layer: BackgroundID,
+    beneath: BackgroundID,
+}
+
+/// A background faded towards white
+#[derive(Clone, Copy)]
+struct Flash {
+    layer: BackgroundID,
+    strength: Num<u8, 4>,
+}
+
+pub struct Blending<'gba> {
+    blend: Blend<'gba>,
+    translucent: Option<Translucent>,
+    flash: Option<Flash>,
+}
+
+impl<'gba> Blending<'gba> {
+    pub fn new(blend: Blend<'gba>) -> Self {
+        Self {
+            blend,
+            translucent: None,
+            flash: None,
+        }
+    }
+
+    /// Makes `layer` see through, blending it with the sprites and the `beneath` background
+    pub fn set_translucent(&mut self, layer: BackgroundID, beneath: BackgroundID) {
+        self.translucent = Some(Translucent { layer, beneath });
+    }
+
+    /// Stops blending the translucent background, for when it is taken off the screen
+    pub fn clear_translucent(&mut self) {
+        self.translucent = None;
+    }
+
+    /// Fades `layer` towards white by `strength`. A flash only lasts a few frames, so the
+    /// translucent background goes solid while it's on rather than needing both at once.
+    pub fn set_flash(&mut self, layer: BackgroundID, strength: Num<u8, 4>) {
+        self.flash = Some(Flash { layer, strength });
+    }
+
+    pub fn clear_flash(&mut self) {
+        self.flash = None;
+    }
+
+    /// Works out the blend registers from everything that has asked for blending and writes
+    /// them. Should be called just after vblank.
+    pub fn commit(&mut self) {
+        self.blend.reset();
+
+        if let Some(flash) = self.flash {
+            self.blend
+                .set_blend_mode(BlendMode::FadeToWhite)
+                .set_background_enable(Layer::Top, flash.layer, true)
+                .set_fade(flash.strength);
+        } else if let Some(translucent) = self.translucent {
+            self.blend
+                .set_blend_mode(BlendMode::Normal)
+                .set_background_enable(Layer::Top, translucent.layer, true)
+                .set_blend_weight(Layer::Top, NET_WEIGHT)
+                .set_background_enable(Layer::Bottom, translucent.beneath, true)
+                .set_object_enable(Layer::Bottom, true)
+                .set_backdrop_enable(Layer::Bottom, true)
+                .set_blend_weight(Layer::Bottom, NET_BENEATH_WEIGHT);
+        }
+
+        self.blend.commit();
+    }
+}
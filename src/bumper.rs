@@ -0,0 +1,119 @@
+//! Round bumpers in the middle of the court when the bumpers modifier is on, like on a pinball
+//! table. The ball bounces off them as circles rather than boxes and gets a kick away from the
+//! middle of the bumper, which lights up.
+
+use agb::display::object::{OamManaged, Object};
+use agb::fixnum::{Rect, Vector2D};
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::collision::{Collider, Layer};
+use crate::court::Court;
+use crate::events::{Events, GameEvent};
+use crate::layering::RenderLayer;
+use crate::physics;
+use crate::tuning::{
+    Fixed, Frames, BUMPER_FLASH_FRAMES, BUMPER_KICK, BUMPER_MAX_SPEED, BUMPER_OFFSETS,
+};
+use crate::view;
+
+/// How many pixels across a bumper is, the same as its sprite
+const SIZE: i32 = 16;
+
+struct Bumper<'a> {
+    centre: Vector2D<i32>,
+    sprite: Object<'a>,
+    /// Frames left of it lighting up after being hit
+    flash_frames_left: Frames,
+}
+
+impl Bumper<'_> {
+    fn top_left(&self) -> Vector2D<i32> {
+        self.centre - (SIZE / 2, SIZE / 2).into()
+    }
+}
+
+pub struct Bumpers<'a> {
+    object: &'a OamManaged<'a>,
+    bumpers: Vec<Bumper<'a>, MatchArena>,
+}
+
+impl<'a> Bumpers<'a> {
+    pub fn new(object: &'a OamManaged<'a>, court: &Court) -> Self {
+        let bumpers = arena::collect(BUMPER_OFFSETS.iter().map(|&(x, y)| {
+            let centre: Vector2D<i32> = (court.centre_x() + x, y).into();
+            let mut sprite = object.object_sprite(SPRITES.bumper.sprite(0));
+            let position = centre - (SIZE / 2, SIZE / 2).into();
+            RenderLayer::PowerUps
+                .apply(&mut sprite)
+                .set_position(view::sprite_position(position, SPRITES.bumper.sprite(0)))
+                .show();
+            Bumper {
+                centre,
+                sprite,
+                flash_frames_left: Frames::ZERO,
+            }
+        }));
+
+        Self { object, bumpers }
+    }
+
+    /// The square around each bumper for the broadphase, whether the ball really touches the
+    /// round part is up to `bounce`
+    pub fn colliders(&self) -> impl Iterator<Item = Collider> + '_ {
+        self.bumpers
+            .iter()
+            .enumerate()
+            .map(|(index, bumper)| Collider {
+                layer: Layer::Bumper,
+                index,
+                rect: Rect::new(bumper.top_left().into(), Vector2D::new(SIZE, SIZE).into()),
+            })
+    }
+
+    /// Bounces `ball` off bumper `index` if it's touching it and heading in, sending it away
+    /// from the middle a bit faster than it came
+    pub fn bounce(&mut self, index: usize, ball: &mut Ball, events: &mut Events) {
+        let bumper = &mut self.bumpers[index];
+        let centre: Vector2D<Fixed> = bumper.centre.into();
+        let radius = Fixed::new(SIZE / 2);
+        let Some(normal) = physics::circle_contact(centre, radius, ball.entity.rect()) else {
+            return;
+        };
+        let velocity = ball.entity.velocity;
+        if velocity.dot(normal) >= 0.into() {
+            return;
+        }
+
+        let velocity = physics::reflect_off(velocity, normal) + normal * BUMPER_KICK.0;
+        ball.entity.velocity = if velocity.magnitude() > BUMPER_MAX_SPEED.0 {
+            velocity.normalise() * BUMPER_MAX_SPEED.0
+        } else {
+            velocity
+        };
+        bumper.flash_frames_left = BUMPER_FLASH_FRAMES;
+        events.push(GameEvent::BumperHit);
+    }
+
+    pub fn update(&mut self) {
+        for bumper in &mut self.bumpers {
+            bumper.flash_frames_left.tick_down();
+        }
+    }
+
+    /// Lights up the bumpers that were just hit, fading back over the second half of the flash
+    pub fn update_sprites(&mut self) {
+        for bumper in &mut self.bumpers {
+            let frame = match bumper.flash_frames_left {
+                Frames::ZERO => 0,
+                left if left > BUMPER_FLASH_FRAMES / 2 => 1,
+                _ => 2,
+            };
+            bumper
+                .sprite
+                .set_sprite(self.object.sprite(SPRITES.bumper.sprite(frame)));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! What happens between the winning point and the results screen. The winner's paddle jumps
+//! about while the final score counts up and then who won is put up, the confetti and jingle
+//! come from the `MatchWon` event.
+
+use agb::display::tiled::{Tiled0, VRamManager};
+
+use crate::animation::{Cue, Cued, Timeline};
+use crate::paddle::{Paddle, Side};
+use crate::score::Score;
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+use crate::tuning::{
+    Fixed, CELEBRATION_FRAMES, POINTS_TO_WIN, SCORE_COUNT_FRAMES, WINNER_HOP_FRAMES,
+    WINNER_HOP_HEIGHT,
+};
+
+/// Once the score could have finished counting up, says who won. The player is always on the
+/// left.
+const LEFT_WON: &[Cued] = &[Cued::new(
+    POINTS_TO_WIN * SCORE_COUNT_FRAMES.0,
+    Cue::Prompt(Text::YouWin),
+)];
+const RIGHT_WON: &[Cued] = &[Cued::new(
+    POINTS_TO_WIN * SCORE_COUNT_FRAMES.0,
+    Cue::Prompt(Text::CpuWins),
+)];
+
+pub struct Celebration<'a> {
+    pub winner: Side,
+    score: Score,
+    /// The score as it has counted up so far
+    shown: Score,
+    label: Label<'a>,
+    prompt: Label<'a>,
+    /// Where the winning paddle was when the match ended, it jumps up from here
+    paddle_y: Fixed,
+    timeline: Timeline,
+}
+
+impl<'a> Celebration<'a> {
+    pub fn new(
+        winner: Side,
+        score: Score,
+        paddle: &Paddle,
+        tiled: &'a Tiled0,
+        vram: &mut VRamManager,
+    ) -> Self {
+        let shown = Score::default();
+        let mut label = Label::new(tiled, vram, (13, 2));
+        label.write(
+            vram,
+            text::HIGHLIGHT,
+            format_args!("{} - {}", shown.left, shown.right),
+        );
+
+        Self {
+            winner,
+            score,
+            shown,
+            label,
+            prompt: Label::new(tiled, vram, (11, 4)),
+            // Leave enough room above the paddle that it doesn't jump off the top of the screen
+            paddle_y: paddle.y().max(WINNER_HOP_HEIGHT.into()),
+            timeline: Timeline::default(),
+        }
+    }
+
+    /// Moves the celebration on a frame, returns true once it has finished
+    pub fn update(
+        &mut self,
+        winning_paddle: &mut Paddle,
+        vram: &mut VRamManager,
+        language: Language,
+    ) -> bool {
+        self.timeline.advance();
+        let cues = match self.winner {
+            Side::Left => LEFT_WON,
+            Side::Right => RIGHT_WON,
+        };
+        for cue in self.timeline.cues(cues) {
+            if let Cue::Prompt(prompt) = cue {
+                let prompt = prompt.get(language);
+                self.prompt
+                    .write(vram, text::HIGHLIGHT, format_args!("{prompt}"));
+            }
+        }
+
+        let frame = self.timeline.frame();
+
+        // Each jump is a parabola, up from the resting place and back down again
+        let t = (frame % WINNER_HOP_FRAMES.0) as i32;
+        let length = WINNER_HOP_FRAMES.0 as i32;
+        let hop = 4 * WINNER_HOP_HEIGHT * t * (length - t) / (length * length);
+        winning_paddle.set_y(self.paddle_y - hop);
+
+        let counted = frame / SCORE_COUNT_FRAMES.0;
+        let shown = Score {
+            left: self.score.left.min(counted),
+            right: self.score.right.min(counted),
+        };
+        if shown != self.shown {
+            self.shown = shown;
+            self.label.write(
+                vram,
+                text::HIGHLIGHT,
+                format_args!("{} - {}", shown.left, shown.right),
+            );
+        }
+
+        frame >= CELEBRATION_FRAMES.0
+    }
+
+    pub fn score(&self) -> Score {
+        self.score
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.label.clear(vram);
+        self.prompt.clear(vram);
+    }
+}
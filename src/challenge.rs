@@ -0,0 +1,61 @@
+//! A match with its rules picked for the player. With a clock in the cartridge it's the daily
+//! challenge, the same for everyone on the same day, and the best score each day is kept.
+//! Without one it's a random challenge that changes every time.
+
+use agb::rng::RandomNumberGenerator;
+
+use crate::ai::Difficulty;
+use crate::character::{Character, CHARACTERS};
+use crate::modifiers::Modifiers;
+use crate::rtc::{self, Date};
+use crate::tuning::{PixelsPerFrame, CHALLENGE_BALL_SPEEDS};
+
+#[derive(Clone, Copy)]
+pub struct Challenge {
+    /// The day it's the challenge for, none for a random one
+    pub date: Option<Date>,
+    /// Where the match's random numbers start from
+    pub seed: [u32; 4],
+    pub cpu: &'static Character,
+    pub difficulty: Difficulty,
+    pub modifiers: Modifiers,
+    /// The slowest the ball goes towards a paddle
+    pub ball_speed: PixelsPerFrame,
+}
+
+impl Challenge {
+    /// Today's challenge, or a random one if there's no clock
+    pub fn today() -> Self {
+        match rtc::today() {
+            Some(date) => {
+                // Spread the days out so the next day doesn't start from a similar seed
+                let day = (date.year as u32 * 12 + date.month as u32) * 31 + date.day as u32;
+                let mixed = day.wrapping_mul(0x9e37_79b9);
+                Self::from_seed(
+                    Some(date),
+                    core::array::from_fn(|i| mixed.rotate_left(i as u32 * 8) | 1),
+                )
+            }
+            None => Self::from_seed(None, core::array::from_fn(|_| agb::rng::gen() as u32 | 1)),
+        }
+    }
+
+    fn from_seed(date: Option<Date>, seed: [u32; 4]) -> Self {
+        let mut rng = RandomNumberGenerator::new_with_seed(seed);
+        let mut pick = |count: usize| rng.gen().rem_euclid(count as i32) as usize;
+
+        Self {
+            date,
+            seed,
+            cpu: &CHARACTERS[pick(CHARACTERS.len())],
+            difficulty: Difficulty::ALL[pick(Difficulty::ALL.len())],
+            modifiers: Modifiers {
+                fog: pick(2) == 1,
+                shrinking_court: pick(2) == 1,
+                // Left out so the challenges already played stay the same
+                ..Modifiers::default()
+            },
+            ball_speed: CHALLENGE_BALL_SPEEDS[pick(CHALLENGE_BALL_SPEEDS.len())],
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! The paddles players can pick from. Each one trades speed against size and has its own special.
+
+use agb::display::object::Tag;
+
+use crate::assets::SPRITES;
+use crate::strings::Text;
+use crate::tuning::{fixed, PixelsPerFrame};
+
+/// The special move a character can pull off
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ability {
+    /// The next return bends as it travels
+    Curveball,
+    /// A wall goes up in front of the goal for a while
+    Wall,
+    /// The paddle moves much faster for a while
+    SpeedBurst,
+}
+
+impl Ability {
+    pub fn description(self) -> Text {
+        match self {
+            Ability::Curveball => Text::CurveballReturn,
+            Ability::Wall => Text::TemporaryWall,
+            Ability::SpeedBurst => Text::SpeedBurst,
+        }
+    }
+}
+
+pub struct Character {
+    pub name: &'static str,
+    /// How far the paddle moves each frame it is pushed up or down
+    pub speed: PixelsPerFrame,
+    /// How many 16 pixel segments tall the paddle is, counting both ends
+    pub segments: usize,
+    pub ability: Ability,
+    /// Sprites for the ends and middle of the paddle, each character has its own colours
+    pub end: &'static Tag,
+    pub middle: &'static Tag,
+}
+
+pub static CHARACTERS: [Character; 3] = [
+    Character {
+        name: "rally",
+        speed: PixelsPerFrame(fixed(3, 2)),
+        segments: 3,
+        ability: Ability::Curveball,
+        end: SPRITES.paddle_end,
+        middle: SPRITES.paddle_mid,
+    },
+    Character {
+        name: "dash",
+        speed: PixelsPerFrame(fixed(2, 1)),
+        segments: 2,
+        ability: Ability::SpeedBurst,
+        end: SPRITES.dash_end,
+        middle: SPRITES.dash_mid,
+    },
+    Character {
+        name: "tower",
+        speed: PixelsPerFrame(fixed(1, 1)),
+        segments: 4,
+        ability: Ability::Wall,
+        end: SPRITES.tower_end,
+        middle: SPRITES.tower_mid,
+    },
+];
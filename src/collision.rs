@@ -0,0 +1,139 @@
+//! Finds which things on the court might be touching. Everything that can collide is put on a
+//! layer, and each layer has a mask of the layers it hits. The broadphase only hands back the
+//! pairs that are allowed to hit and overlap, what happens to each pair is up to the game.
+//!
+//! The screen is split into a grid and colliders are only checked against the others in the
+//! same cells, so lots of them don't mean checking every one against every other.
+
+use agb::fixnum::Rect;
+
+use crate::arrayvec::ArrayVec;
+use crate::physics;
+use crate::tuning::{Fixed, BROADPHASE_CELL_SIZE};
+
+const COLUMNS: i32 = (agb::display::WIDTH + BROADPHASE_CELL_SIZE - 1) / BROADPHASE_CELL_SIZE;
+const ROWS: i32 = (agb::display::HEIGHT + BROADPHASE_CELL_SIZE - 1) / BROADPHASE_CELL_SIZE;
+
+/// The most things on the court that can be collided with at once
+pub const MAX_COLLIDERS: usize = 16;
+/// The most pairs that can be touching in a frame
+pub const MAX_PAIRS: usize = 32;
+/// The most cells all the colliders can cover between them
+const MAX_CELL_ENTRIES: usize = 96;
+
+pub type Colliders = ArrayVec<Collider, MAX_COLLIDERS>;
+pub type Pairs = ArrayVec<(Collider, Collider), MAX_PAIRS>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layer {
+    Ball,
+    Paddle,
+    PowerUp,
+    Shield,
+    Portal,
+    Bumper,
+}
+
+impl Layer {
+    /// The layers this one collides with. Only balls move into things, so everything else just
+    /// needs to hit them and balls go through each other.
+    pub fn mask(self) -> Layers {
+        match self {
+            Layer::Ball => Layers::of(&[
+                Layer::Paddle,
+                Layer::PowerUp,
+                Layer::Shield,
+                Layer::Portal,
+                Layer::Bumper,
+            ]),
+            Layer::Paddle | Layer::PowerUp | Layer::Shield | Layer::Portal | Layer::Bumper => {
+                Layers::of(&[Layer::Ball])
+            }
+        }
+    }
+}
+
+/// A set of layers, one bit each
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Layers(u8);
+
+impl Layers {
+    const fn of(layers: &[Layer]) -> Self {
+        let mut bits = 0;
+        let mut index = 0;
+        while index < layers.len() {
+            bits |= 1 << layers[index] as u8;
+            index += 1;
+        }
+        Self(bits)
+    }
+
+    pub fn contains(self, layer: Layer) -> bool {
+        self.0 & (1 << layer as u8) != 0
+    }
+}
+
+/// Something that can be collided with this frame
+#[derive(Clone, Copy, Debug)]
+pub struct Collider {
+    pub layer: Layer,
+    /// Which of the things on its layer this is, like the index of the ball
+    pub index: usize,
+    pub rect: Rect<Fixed>,
+}
+
+/// The column and row of the cell a point is in. Anything off the screen counts as being in
+/// the cells along the edge.
+fn cell(x: Fixed, y: Fixed) -> (i32, i32) {
+    (
+        (x.floor() / BROADPHASE_CELL_SIZE).clamp(0, COLUMNS - 1),
+        (y.floor() / BROADPHASE_CELL_SIZE).clamp(0, ROWS - 1),
+    )
+}
+
+/// Every pair of colliders whose layers hit each other and whose rectangles overlap, each pair
+/// coming out once
+#[link_section = ".iwram.collision"]
+#[instruction_set(arm::a32)]
+pub fn broadphase(colliders: &[Collider]) -> Pairs {
+    // Which cell each collider is in, once for each cell it covers. Sorted, the colliders in
+    // each cell come out next to each other.
+    let mut entries: ArrayVec<(usize, usize), MAX_CELL_ENTRIES> = ArrayVec::new();
+    for (index, collider) in colliders.iter().enumerate() {
+        let rect = collider.rect;
+        let (left, top) = cell(rect.position.x, rect.position.y);
+        let (right, bottom) = cell(rect.position.x + rect.size.x, rect.position.y + rect.size.y);
+        for row in top..=bottom {
+            for column in left..=right {
+                entries.push(((row * COLUMNS + column) as usize, index));
+            }
+        }
+    }
+    entries.sort_unstable();
+
+    let mut pairs = Pairs::new();
+    for cell_entries in entries.chunk_by(|a, b| a.0 == b.0) {
+        let cell_index = cell_entries[0].0;
+        for (index, &(_, first)) in cell_entries.iter().enumerate() {
+            for &(_, second) in &cell_entries[index + 1..] {
+                let (first, second) = (colliders[first], colliders[second]);
+                if !first.layer.mask().contains(second.layer)
+                    || !physics::overlaps(first.rect, second.rect)
+                {
+                    continue;
+                }
+
+                // A pair that shares more than one cell is only kept in the cell where the
+                // overlap starts, so it doesn't come out more than once
+                let (column, row) = cell(
+                    first.rect.position.x.max(second.rect.position.x),
+                    first.rect.position.y.max(second.rect.position.y),
+                );
+                if (row * COLUMNS + column) as usize == cell_index {
+                    pairs.push((first, second));
+                }
+            }
+        }
+    }
+    pairs
+}
@@ -0,0 +1,23 @@
+//! Working with the 15 bit colours the gba's palettes are made of
+
+use crate::tuning::Fixed;
+
+/// Converts a 24 bit colour into the 15 bit colour the gba uses
+pub const fn rgb15(colour: u32) -> u16 {
+    let r = (colour >> 19) & 0x1f;
+    let g = (colour >> 11) & 0x1f;
+    let b = (colour >> 3) & 0x1f;
+    (r | (g << 5) | (b << 10)) as u16
+}
+
+/// The colour `amount` of the way from `from` to `to`, each channel blended on its own
+pub fn lerp(from: u16, to: u16, amount: Fixed) -> u16 {
+    let mut colour = 0;
+    for shift in [0, 5, 10] {
+        let from = Fixed::new(i32::from((from >> shift) & 0x1f));
+        let to = Fixed::new(i32::from((to >> shift) & 0x1f));
+        let channel = (from + (to - from) * amount).floor().clamp(0, 0x1f) as u16;
+        colour |= channel << shift;
+    }
+    colour
+}
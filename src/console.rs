@@ -0,0 +1,197 @@
+//! A debug screen over the match for changing the tuning numbers without a rebuild. Select and
+//! start opens it in debug builds, up and down pick a number, left and right change it and B
+//! goes back to the match with the new numbers.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{Tiled0, VRamManager};
+use agb::input::{Button, ButtonController};
+
+use crate::text::{self, Label};
+use crate::tuning::{fixed, Fixed, PixelsPerFrame};
+use crate::tweaks::{self, Tweaks};
+
+#[derive(Clone, Copy)]
+enum Setting {
+    MinimumSpeed,
+    SpeedRamp,
+    PaddleAcceleration,
+    AiAcceleration,
+    AiDeadZone,
+    AiReaction,
+    EndAngle,
+    MiddleAngle,
+    ReturnSpeed,
+}
+
+impl Setting {
+    const ALL: [Setting; 9] = [
+        Setting::MinimumSpeed,
+        Setting::SpeedRamp,
+        Setting::PaddleAcceleration,
+        Setting::AiAcceleration,
+        Setting::AiDeadZone,
+        Setting::AiReaction,
+        Setting::EndAngle,
+        Setting::MiddleAngle,
+        Setting::ReturnSpeed,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Setting::MinimumSpeed => "min ball speed",
+            Setting::SpeedRamp => "speed ramp",
+            Setting::PaddleAcceleration => "paddle accel",
+            Setting::AiAcceleration => "ai acceleration",
+            Setting::AiDeadZone => "ai dead zone",
+            Setting::AiReaction => "ai reaction",
+            Setting::EndAngle => "end bounce",
+            Setting::MiddleAngle => "middle bounce",
+            Setting::ReturnSpeed => "return speed",
+        }
+    }
+
+    fn value(self, tweaks: &Tweaks) -> Fixed {
+        let bounces = &tweaks.segment_bounces;
+        match self {
+            Setting::MinimumSpeed => tweaks.minimum_horizontal_speed.0,
+            Setting::SpeedRamp => tweaks.ball_speed_ramp,
+            Setting::PaddleAcceleration => tweaks.paddle_acceleration.0,
+            Setting::AiAcceleration => tweaks.ai_acceleration,
+            Setting::AiDeadZone => tweaks.ai_dead_zone.into(),
+            Setting::AiReaction => (tweaks.ai_reaction_frames.0 as i32).into(),
+            Setting::EndAngle => bounces[2].velocity.y,
+            Setting::MiddleAngle => bounces[1].velocity.y,
+            Setting::ReturnSpeed => bounces[1].velocity.x,
+        }
+    }
+
+    /// Moves the setting `steps` steps up, or down if it's negative
+    fn change(self, tweaks: &mut Tweaks, steps: i32) {
+        let step = fixed(steps, 16);
+        let bounces = &mut tweaks.segment_bounces;
+        match self {
+            Setting::MinimumSpeed => {
+                tweaks.minimum_horizontal_speed = (tweaks.minimum_horizontal_speed
+                    + PixelsPerFrame(step))
+                .max(PixelsPerFrame::ZERO);
+            }
+            Setting::AiAcceleration => {
+                tweaks.ai_acceleration = (tweaks.ai_acceleration + step / 2).max(fixed(1, 32));
+            }
+            // The ramp is small, a sixteenth more per return would be far too much
+            Setting::SpeedRamp => {
+                tweaks.ball_speed_ramp = (tweaks.ball_speed_ramp + step / 8).max(0.into());
+            }
+            Setting::PaddleAcceleration => {
+                tweaks.paddle_acceleration = (tweaks.paddle_acceleration
+                    + PixelsPerFrame(step * 4))
+                .max(PixelsPerFrame(fixed(1, 16)));
+            }
+            Setting::AiDeadZone => tweaks.ai_dead_zone = (tweaks.ai_dead_zone + steps).max(0),
+            Setting::AiReaction => {
+                let frames = &mut tweaks.ai_reaction_frames.0;
+                *frames = frames.saturating_add_signed(steps);
+            }
+            // Both ends change together so the paddle stays the same from top to bottom
+            Setting::EndAngle => {
+                bounces[0].velocity.y -= step;
+                bounces[2].velocity.y += step;
+            }
+            Setting::MiddleAngle => bounces[1].velocity.y += step,
+            Setting::ReturnSpeed => {
+                for bounce in bounces {
+                    bounce.velocity.x = (bounce.velocity.x + step).max(fixed(1, 2));
+                }
+            }
+        }
+    }
+}
+
+/// Every setting and its value, with an arrow next to the one being changed
+struct SettingList {
+    tweaks: Tweaks,
+    cursor: usize,
+}
+
+impl Display for SettingList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "tuning")?;
+        for (index, setting) in Setting::ALL.into_iter().enumerate() {
+            let arrow = if index == self.cursor { ">" } else { " " };
+            let value = setting.value(&self.tweaks);
+            writeln!(f, "{} {} {}", arrow, setting.name(), value)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct TuningConsole<'a> {
+    label: Label<'a>,
+    cursor: usize,
+}
+
+impl<'a> TuningConsole<'a> {
+    pub fn new(tiled: &'a Tiled0, vram: &mut VRamManager) -> Self {
+        let mut console = Self {
+            label: Label::new(tiled, vram, (6, 5)),
+            cursor: 0,
+        };
+        console.show(vram);
+        console
+    }
+
+    /// Select and start together, only in debug builds
+    pub fn wants_open(input: &ButtonController) -> bool {
+        cfg!(debug_assertions)
+            && input.is_pressed(Button::SELECT)
+            && input.is_just_pressed(Button::START)
+    }
+
+    fn show(&mut self, vram: &mut VRamManager) {
+        let list = SettingList {
+            tweaks: tweaks::get(),
+            cursor: self.cursor,
+        };
+        self.label
+            .write(vram, text::HIGHLIGHT, format_args!("{}", list));
+    }
+
+    /// Changes the settings from the d-pad, returns true once B closes the console
+    pub fn update(&mut self, input: &ButtonController, vram: &mut VRamManager) -> bool {
+        if input.is_just_pressed(Button::B) {
+            return true;
+        }
+
+        let rows = Setting::ALL.len();
+        let cursor = if input.is_just_pressed(Button::UP) {
+            (self.cursor + rows - 1) % rows
+        } else if input.is_just_pressed(Button::DOWN) {
+            (self.cursor + 1) % rows
+        } else {
+            self.cursor
+        };
+        let steps = if input.is_just_pressed(Button::LEFT) {
+            -1
+        } else if input.is_just_pressed(Button::RIGHT) {
+            1
+        } else {
+            0
+        };
+
+        if cursor != self.cursor || steps != 0 {
+            self.cursor = cursor;
+            // Changes go in straight away, the match picks them up as soon as it carries on
+            let mut tweaks = tweaks::get();
+            Setting::ALL[self.cursor].change(&mut tweaks, steps);
+            tweaks::set(tweaks);
+            self.show(vram);
+        }
+
+        false
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.label.clear(vram);
+    }
+}
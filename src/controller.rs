@@ -0,0 +1,167 @@
+//! Whatever is in charge of a paddle, either the player with the buttons or the computer.
+//! The game asks the controller for each side what it wants to do and doesn't need to care
+//! which one it is.
+//!
+//! The player's serves and specials go through a short buffer of button presses, so pressing a
+//! few frames too early still counts once the serve or special is ready. A quick tap on the
+//! d-pad nudges the paddle a little way, holding it moves the paddle at full speed.
+
+use agb::fixnum::Vector2D;
+use agb::input::{Button, ButtonController};
+
+use crate::ai::Ai;
+use crate::ball::Ball;
+use crate::paddle::Paddle;
+use crate::tuning::{
+    Fixed, Frames, INPUT_BUFFER_FRAMES, PLAYER_SERVES, TAP_NUDGE_FRAMES, TAP_NUDGE_SPEED,
+};
+
+/// The buttons pressed on each of the last few frames, newest first
+#[derive(Clone, Copy)]
+pub struct InputBuffer {
+    presses: [Button; INPUT_BUFFER_FRAMES.0 as usize],
+}
+
+impl Default for InputBuffer {
+    fn default() -> Self {
+        Self {
+            presses: [Button::empty(); INPUT_BUFFER_FRAMES.0 as usize],
+        }
+    }
+}
+
+impl InputBuffer {
+    /// Remembers this frame's presses, forgetting the oldest frame
+    pub fn record(&mut self, input: &ButtonController) {
+        self.presses
+            .copy_within(..INPUT_BUFFER_FRAMES.0 as usize - 1, 1);
+        self.presses[0] = Button::all()
+            .iter()
+            .filter(|&button| input.is_just_pressed(button))
+            .collect();
+    }
+
+    /// Whether `button` was pressed recently and hasn't been used yet
+    pub fn contains(&self, button: Button) -> bool {
+        self.presses.iter().any(|presses| presses.contains(button))
+    }
+
+    /// Uses up a recent press of `button`, returning whether there was one
+    pub fn take(&mut self, button: Button) -> bool {
+        let pressed = self.contains(button);
+        for presses in &mut self.presses {
+            presses.remove(button);
+        }
+        pressed
+    }
+}
+
+/// Tells a tap on up or down apart from holding it. Every press starts with a nudge at part
+/// speed that plays out even if it's let go straight away, and only once it has been held for
+/// longer than the nudge does it move at full speed.
+#[derive(Clone, Copy, Default)]
+pub struct Dpad {
+    /// Which way is held, -1 for up, 1 for down and 0 for neither
+    held: i32,
+    held_frames: Frames,
+    nudge: i32,
+    nudge_frames_left: Frames,
+}
+
+impl Dpad {
+    /// How hard to push the paddle this frame, the same as `Controller::vertical`
+    pub fn vertical(&mut self, input: &ButtonController) -> Fixed {
+        let direction = input.y_tri() as i32;
+        if direction != self.held {
+            self.held = direction;
+            self.held_frames = Frames::ZERO;
+            if direction != 0 {
+                self.nudge = direction;
+                self.nudge_frames_left = TAP_NUDGE_FRAMES;
+            }
+        }
+
+        if direction != 0 {
+            self.held_frames.tick();
+            if self.held_frames > TAP_NUDGE_FRAMES {
+                return Fixed::new(direction);
+            }
+        }
+
+        if self.nudge_frames_left > Frames::ZERO {
+            self.nudge_frames_left.tick_down();
+            return TAP_NUDGE_SPEED * self.nudge;
+        }
+
+        0.into()
+    }
+}
+
+pub enum Controller {
+    /// `aim` is which of the serves the player has picked
+    Player {
+        aim: usize,
+        buffer: InputBuffer,
+        dpad: Dpad,
+    },
+    Computer(Ai),
+}
+
+impl Controller {
+    /// Keeps track of the player's presses, must be called every frame of the match
+    pub fn record(&mut self, input: &ButtonController) {
+        if let Controller::Player { buffer, .. } = self {
+            buffer.record(input);
+        }
+    }
+
+    /// How hard to push the paddle this frame, from -1 for full speed up to 1 for full speed down
+    pub fn vertical(&mut self, input: &ButtonController, paddle: &Paddle, ball: &Ball) -> Fixed {
+        match self {
+            Controller::Player { dpad, .. } => dpad.vertical(input),
+            Controller::Computer(ai) => ai.input(paddle, &ball.entity),
+        }
+    }
+
+    /// Whether to spend a full energy meter on the special this frame. The computer uses it as
+    /// soon as it can while a ball is coming towards it.
+    pub fn wants_special(&self, balls: &[Ball]) -> bool {
+        match self {
+            Controller::Player { buffer, .. } => buffer.contains(Button::B),
+            Controller::Computer(_) => balls.iter().any(|ball| ball.entity.velocity.x > 0.into()),
+        }
+    }
+
+    /// Uses up the press that set the special off, so it doesn't go again as soon as it's
+    /// ready next time
+    pub fn used_special(&mut self) {
+        if let Controller::Player { buffer, .. } = self {
+            buffer.take(Button::B);
+        }
+    }
+
+    /// The serve the player is lining up, for showing where it will go
+    pub fn aim(&self) -> Option<Vector2D<Fixed>> {
+        match self {
+            Controller::Player { aim, .. } => Some(PLAYER_SERVES[*aim]),
+            Controller::Computer(_) => None,
+        }
+    }
+
+    /// Asked every frame while this side is holding the ball. Returns the velocity to serve
+    /// with, x being the speed towards the other side, once it's ready to go.
+    pub fn serve(&mut self, input: &ButtonController) -> Option<Vector2D<Fixed>> {
+        match self {
+            Controller::Player { aim, buffer, .. } => {
+                if input.is_just_pressed(Button::L) {
+                    *aim = aim.saturating_sub(1);
+                }
+                if input.is_just_pressed(Button::R) {
+                    *aim = (*aim + 1).min(PLAYER_SERVES.len() - 1);
+                }
+                buffer.take(Button::A).then_some(PLAYER_SERVES[*aim])
+            }
+            Controller::Computer(ai) => ai.serve(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Sits between a controller and its paddle. Whatever is driving the paddle, the player's d-pad
+//! or the computer, asks to move up or down and this decides what actually happens, so a curse
+//! can flip it around without either of them knowing.
+
+use agb::display::object::{OamManaged, Object};
+
+use crate::assets::SPRITES;
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::tuning::{Fixed, CURSE_FRAMES};
+use crate::view;
+
+/// How far the cursed paddle's sprites are pushed sideways over each wobble
+const WOBBLE: [i32; 8] = [0, 1, 2, 1, 0, -1, -2, -1];
+
+pub struct Controls<'a> {
+    object: &'a OamManaged<'a>,
+    /// Frames left with up and down swapped, 0 when the controls are normal
+    reversed_frames_left: u32,
+    /// Shown under the energy meter while the controls are reversed
+    indicator: Object<'a>,
+}
+
+impl<'a> Controls<'a> {
+    pub fn new(object: &'a OamManaged<'a>, side: Side) -> Self {
+        let x = match side {
+            Side::Left => 4,
+            Side::Right => agb::display::WIDTH - 20,
+        };
+        let mut indicator = object.object_sprite(SPRITES.curse.sprite(0));
+        RenderLayer::Hud
+            .apply(&mut indicator)
+            .set_position(view::sprite_position(
+                (x, 12).into(),
+                SPRITES.curse.sprite(0),
+            ))
+            .hide();
+
+        Self {
+            object,
+            reversed_frames_left: 0,
+            indicator,
+        }
+    }
+
+    /// Swaps up and down for a while, another curse while one is running starts it again
+    pub fn reverse(&mut self) {
+        self.reversed_frames_left = CURSE_FRAMES.0;
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed_frames_left > 0
+    }
+
+    /// Turns the direction the controller asked for into the one the paddle moves in
+    pub fn vertical(&self, y: Fixed) -> Fixed {
+        if self.is_reversed() {
+            -y
+        } else {
+            y
+        }
+    }
+
+    /// How far sideways to draw the paddle this frame, it wobbles while cursed
+    pub fn wobble(&self) -> i32 {
+        if self.is_reversed() {
+            WOBBLE[(self.reversed_frames_left as usize / 2) % WOBBLE.len()]
+        } else {
+            0
+        }
+    }
+
+    /// Lifts the curse straight away, used when the point is restarted
+    pub fn reset(&mut self) {
+        self.reversed_frames_left = 0;
+    }
+
+    pub fn update(&mut self) {
+        self.reversed_frames_left = self.reversed_frames_left.saturating_sub(1);
+    }
+
+    /// Flicks the indicator between its frames, blinking it for the last second of the curse
+    pub fn update_sprites(&mut self) {
+        let fading = self.reversed_frames_left < 60 && (self.reversed_frames_left / 4) % 2 == 0;
+        if !self.is_reversed() || fading {
+            self.indicator.hide();
+            return;
+        }
+
+        let frame = (self.reversed_frames_left as usize / 16) % SPRITES.curse.sprites().len();
+        self.indicator
+            .set_sprite(self.object.sprite(SPRITES.curse.sprite(frame)))
+            .show();
+    }
+}
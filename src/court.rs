@@ -0,0 +1,134 @@
+//! Where the edges of the court are. The walls can move in during a match and the playfield
+//! doesn't have to fill the screen, so everything that needs to stay on the court asks here
+//! instead of using the size of the screen.
+
+use agb::fixnum::{Rect, Vector2D};
+
+use crate::paddle::Side;
+use crate::tuning::{
+    Fixed, COURT_SHRINK_LIMIT, COURT_SHRINK_STEP, LOPSIDED_MAX_SPEED, POINTS_PER_COURT_SHRINK,
+};
+
+/// The stretch of a side of the court the ball scores through
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Goal {
+    /// The edge of the court the goal is on
+    pub x: i32,
+    pub top: i32,
+    pub bottom: i32,
+}
+
+impl Goal {
+    pub fn centre_y(&self) -> i32 {
+        (self.top + self.bottom) / 2
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Court {
+    /// The area of the screen the court takes up before the walls move in
+    bounds: Rect<i32>,
+    /// How far the top and bottom walls have moved in from the edges of the bounds
+    wall_inset: i32,
+    /// What the ball's speed is multiplied by each frame in the left and right halves
+    half_speeds: [Fixed; 2],
+}
+
+impl Default for Court {
+    /// A court filling the whole screen
+    fn default() -> Self {
+        let size: Vector2D<i32> = (agb::display::WIDTH, agb::display::HEIGHT).into();
+        Self::new(Rect::new((0, 0).into(), size))
+    }
+}
+
+impl Court {
+    /// A court covering `bounds` of the screen
+    pub fn new(bounds: Rect<i32>) -> Self {
+        Self {
+            bounds,
+            wall_inset: 0,
+            half_speeds: [1.into(); 2],
+        }
+    }
+
+    /// Makes the ball speed up or slow down in each half, left then right
+    pub fn set_half_speeds(&mut self, half_speeds: [Fixed; 2]) {
+        self.half_speeds = half_speeds;
+    }
+
+    /// The velocity something at `position` has after a frame in whichever half it's in. It's
+    /// never sped up past `LOPSIDED_MAX_SPEED` across the court.
+    pub fn half_court_velocity(
+        &self,
+        position: Vector2D<Fixed>,
+        velocity: Vector2D<Fixed>,
+    ) -> Vector2D<Fixed> {
+        let half = if position.x.floor() < self.centre_x() {
+            0
+        } else {
+            1
+        };
+        let speed = self.half_speeds[half];
+        if speed > 1.into() && velocity.x.abs() >= LOPSIDED_MAX_SPEED.0 {
+            return velocity;
+        }
+        velocity * speed
+    }
+
+    /// The area that can be played in right now, inside the walls
+    pub fn rect(&self) -> Rect<i32> {
+        Rect::new(
+            self.bounds.position + (0, self.wall_inset).into(),
+            self.bounds.size - (0, 2 * self.wall_inset).into(),
+        )
+    }
+
+    pub fn left(&self) -> Fixed {
+        self.bounds.position.x.into()
+    }
+
+    pub fn right(&self) -> Fixed {
+        (self.bounds.position.x + self.bounds.size.x).into()
+    }
+
+    /// The highest anything can go
+    pub fn top(&self) -> Fixed {
+        self.rect().position.y.into()
+    }
+
+    /// The lowest anything can go
+    pub fn bottom(&self) -> Fixed {
+        let rect = self.rect();
+        (rect.position.y + rect.size.y).into()
+    }
+
+    /// Where the net is
+    pub fn centre_x(&self) -> i32 {
+        self.bounds.position.x + self.bounds.size.x / 2
+    }
+
+    /// The goal `side` is defending, it runs the whole way between the walls
+    pub fn goal(&self, side: Side) -> Goal {
+        let rect = self.rect();
+        let x = match side {
+            Side::Left => rect.position.x,
+            Side::Right => rect.position.x + rect.size.x,
+        };
+        Goal {
+            x,
+            top: rect.position.y,
+            bottom: rect.position.y + rect.size.y,
+        }
+    }
+
+    /// Moves the walls in to match how many points have been played, returning true if
+    /// they moved
+    pub fn shrink_for_points(&mut self, points_played: u32) -> bool {
+        let steps = (points_played / POINTS_PER_COURT_SHRINK) as i32;
+        let inset = (steps * COURT_SHRINK_STEP).min(COURT_SHRINK_LIMIT);
+        let moved = inset != self.wall_inset;
+        self.wall_inset = inset;
+        moved
+    }
+}
@@ -0,0 +1,73 @@
+//! Who made the game, scrolling up the screen. Select on the title screen gets here, and it goes
+//! back there once everything has scrolled past or on B or start.
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::scene::{Frame, Next, Resources};
+use crate::scroller::{Line, Scroller};
+use crate::text::{HIGHLIGHT, TEXT};
+use crate::tuning::CREDITS_SCROLL_SPEED;
+
+const fn heading(text: &'static str) -> Line {
+    Line {
+        text,
+        colour: HIGHLIGHT,
+    }
+}
+
+const fn line(text: &'static str) -> Line {
+    Line { text, colour: TEXT }
+}
+
+static CREDITS: [Line; 15] = [
+    heading("agb pong"),
+    line(""),
+    heading("made by"),
+    line("fatfingers23"),
+    line(""),
+    heading("started from"),
+    line("the agb pong tutorial"),
+    line(""),
+    heading("built with"),
+    line("agb, the rust gba library"),
+    line(""),
+    heading("font"),
+    line("ark pixel"),
+    line(""),
+    line("thanks for playing!"),
+];
+
+pub struct Credits<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    scroller: Scroller<'a>,
+}
+
+impl<'a> Credits<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            scroller: Scroller::new(resources.tiled, frame.vram, &CREDITS, CREDITS_SCROLL_SPEED),
+        }
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        let skipped =
+            frame.input.is_just_pressed(Button::B) || frame.input.is_just_pressed(Button::START);
+        if self.scroller.update(frame.vram) || skipped {
+            return Some(Next::Title);
+        }
+
+        None
+    }
+
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        self.scroller.after_vblank(vram);
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.scroller.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,87 @@
+//! The people watching from the stands along the top of the court. They sway out of step with
+//! each other while the match goes on and jump up cheering for goals and long rallies. They're
+//! only decoration, so they come from a sprite pool and there are only as many as it has room
+//! for.
+
+use agb::display::object::OamManaged;
+use agb::fixnum::Vector2D;
+
+use crate::assets::SPRITES;
+use crate::events::GameEvent;
+use crate::layering::RenderLayer;
+use crate::pool::SpritePool;
+use crate::tuning::{Frames, CROWD_CHEER_FRAMES, CROWD_SIZE, CROWD_SWAY_FRAMES};
+
+/// How far each stand is in from the side of the screen, clear of the meters
+const STAND_INSET: i32 = 44;
+/// How far apart the seats in a stand are
+const SEAT_SPACING: i32 = 10;
+/// How far down the seats are, leaving room to jump
+const SEAT_Y: i32 = 3;
+/// How high a cheer jumps through each step of it
+const JUMP: [i32; 6] = [0, -2, -3, -3, -2, 0];
+
+pub struct Crowd<'a> {
+    pool: SpritePool<'a>,
+    /// Counts up the whole match to drive the swaying
+    frame: Frames,
+    /// Frames left of cheering
+    cheer_frames_left: Frames,
+}
+
+impl<'a> Crowd<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            pool: SpritePool::new(
+                object,
+                SPRITES.spectator.sprite(0),
+                CROWD_SIZE,
+                RenderLayer::Decor,
+            ),
+            frame: Frames::ZERO,
+            cheer_frames_left: Frames::ZERO,
+        }
+    }
+
+    pub fn handle_event(&mut self, event: GameEvent) {
+        if let GameEvent::Goal { .. }
+        | GameEvent::RallyMilestone { .. }
+        | GameEvent::MatchWon { .. } = event
+        {
+            self.cheer_frames_left = CROWD_CHEER_FRAMES;
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.frame.tick();
+        self.cheer_frames_left.tick_down();
+    }
+
+    pub fn update_sprites(&mut self) {
+        let step = (self.frame / CROWD_SWAY_FRAMES) as usize;
+        let cheering = self.cheer_frames_left > Frames::ZERO;
+        self.pool.place_sprites((0..CROWD_SIZE).map(|seat| {
+            // Everyone is a step behind the person next to them so they don't move as one
+            let step = step + seat;
+            let (tag, y) = if cheering {
+                (
+                    SPRITES.cheer,
+                    JUMP[(self.frame.0 as usize / 4 + seat) % JUMP.len()],
+                )
+            } else {
+                (SPRITES.spectator, 0)
+            };
+            (seat_position(seat) + (0, y).into(), tag.sprite(step % 2))
+        }));
+    }
+}
+
+/// Where a seat is, every other one going in the right hand stand
+fn seat_position(seat: usize) -> Vector2D<i32> {
+    let along = (seat / 2) as i32 * SEAT_SPACING;
+    let x = match seat % 2 {
+        0 => STAND_INSET + along,
+        _ => agb::display::WIDTH - STAND_INSET - 8 - along,
+    };
+    (x, SEAT_Y).into()
+}
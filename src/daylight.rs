@@ -0,0 +1,83 @@
+//! The court going from day to dusk to night over a long match. Its colours are blended a bit
+//! further towards the tint for the time of day as the match goes on, only a couple of them each
+//! frame so it never takes long. The text colours at the end of the palette are left alone so
+//! the score stays easy to read.
+
+use agb::display::tiled::VRamManager;
+
+use crate::background;
+use crate::colour::{self, rgb15};
+use crate::text;
+use crate::tuning::{fixed, Fixed, Frames, DAYLIGHT_COLOURS_PER_FRAME, DUSK_FRAMES, NIGHT_FRAMES};
+
+/// How many of the court's colours change, everything up to the text colours
+const COLOURS: usize = text::LOCKED as usize;
+
+/// A time of day, the colour the court is tinted towards and how strongly
+struct Sky {
+    tint: u16,
+    amount: Fixed,
+}
+
+const DAY: Sky = Sky {
+    tint: 0,
+    amount: fixed(0, 1),
+};
+const DUSK: Sky = Sky {
+    tint: rgb15(0xd77643),
+    amount: fixed(1, 3),
+};
+const NIGHT: Sky = Sky {
+    tint: rgb15(0x0b0d1a),
+    amount: fixed(1, 2),
+};
+
+impl Sky {
+    fn tinted(&self, colour: u16) -> u16 {
+        colour::lerp(colour, self.tint, self.amount)
+    }
+}
+
+#[derive(Default)]
+pub struct Daylight {
+    /// Frames of play since the match started
+    frame: Frames,
+    /// Which of the court's colours is updated next
+    next: usize,
+}
+
+impl Daylight {
+    pub fn update(&mut self) {
+        self.frame.tick();
+    }
+
+    /// Moves the next few colours on to the time of day, done just after vblank so the court
+    /// doesn't change colour halfway down the screen
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        let (from, to, amount) = if self.frame < DUSK_FRAMES {
+            (&DAY, &DUSK, progress(self.frame, DUSK_FRAMES))
+        } else {
+            let night = progress(self.frame - DUSK_FRAMES, NIGHT_FRAMES - DUSK_FRAMES);
+            (&DUSK, &NIGHT, night.min(1.into()))
+        };
+
+        for _ in 0..DAYLIGHT_COLOURS_PER_FRAME {
+            let original = background::court_colour(self.next);
+            let colour = colour::lerp(from.tinted(original), to.tinted(original), amount);
+            background::set_court_colour(vram, self.next, colour);
+            self.next = (self.next + 1) % COLOURS;
+        }
+    }
+
+    /// Puts the court's colours back to how they were before the match
+    pub fn reset(vram: &mut VRamManager) {
+        for index in 0..COLOURS {
+            background::set_court_colour(vram, index, background::court_colour(index));
+        }
+    }
+}
+
+/// How far `frame` is through `length` frames, from 0 to 1
+fn progress(frame: Frames, length: Frames) -> Fixed {
+    Fixed::new(frame.0 as i32) / length.0 as i32
+}
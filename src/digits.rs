@@ -0,0 +1,72 @@
+//! Small numbers drawn with a sprite for each digit, so they can go anywhere on screen without
+//! needing a background of their own
+
+use agb::display::object::{OamManaged, Object};
+use agb::fixnum::Vector2D;
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::arrayvec::ArrayVec;
+use crate::assets::SPRITES;
+use crate::layering::RenderLayer;
+
+/// How far apart the digits are drawn
+const DIGIT_WIDTH: i32 = 6;
+
+/// The most digits a `u32` can have
+const MOST_DIGITS: usize = 10;
+
+pub struct Digits<'a> {
+    object: &'a OamManaged<'a>,
+    sprites: Vec<Object<'a>, MatchArena>,
+}
+
+impl<'a> Digits<'a> {
+    /// Makes enough sprites for numbers up to `length` digits long
+    pub fn new(object: &'a OamManaged<'a>, length: usize) -> Self {
+        let sprites = arena::collect((0..length).map(|_| {
+            let mut digit = object.object_sprite(SPRITES.digits.sprite(0));
+            RenderLayer::Hud.apply(&mut digit).hide();
+            digit
+        }));
+
+        Self { object, sprites }
+    }
+
+    /// Shows `value` with its top left corner where `place` says, which is given how wide the
+    /// number is drawn so it can be lined up against the right of something. A number longer
+    /// than there are sprites for only shows its last digits.
+    pub fn show(&mut self, value: u32, place: impl FnOnce(i32) -> Vector2D<i32>) {
+        let digits = self.digits_of(value);
+        let position = place(DIGIT_WIDTH * digits.len() as i32);
+
+        for (index, sprite) in self.sprites.iter_mut().enumerate() {
+            match digits.get(index) {
+                Some(&digit) => {
+                    sprite
+                        .set_sprite(self.object.sprite(SPRITES.digits.sprite(digit)))
+                        .set_position(position + (DIGIT_WIDTH * index as i32, 0).into())
+                        .show();
+                }
+                None => {
+                    sprite.hide();
+                }
+            }
+        }
+    }
+
+    /// The digits of `value` from the left, as many of them as will fit
+    fn digits_of(&self, value: u32) -> ArrayVec<usize, MOST_DIGITS> {
+        let mut digits = ArrayVec::new();
+        let mut rest = value;
+        loop {
+            digits.push((rest % 10) as usize);
+            rest /= 10;
+            if rest == 0 || digits.len() == self.sprites.len() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+}
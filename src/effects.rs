@@ -0,0 +1,151 @@
+//! Visual effects that react to what happens in a match
+
+use agb::display::object::OamManaged;
+
+use crate::assets::SPRITES;
+use crate::events::{GameEvent, Impact};
+use crate::paddle::Side;
+use crate::particles::Particles;
+use crate::random;
+use crate::shimmer::Shimmer;
+use crate::tuning::{
+    fixed, Fixed, Frames, CONFETTI_FALL_SPEED, CONFETTI_FRAMES, CONFETTI_INTERVAL,
+    CONFETTI_LIFETIME, RALLY_CONFETTI_FRAMES, RALLY_SHIMMER_FRAMES, SCORE_POPUP_FRAMES,
+    SCORE_POPUP_INSET, SCORE_POPUP_VELOCITY, SHARD_FRAMES, SMASH_SHIMMER_FRAMES, SPARK_FRAMES,
+    SPARK_SPEED,
+};
+
+pub struct Effects<'a> {
+    pub shimmer: Shimmer,
+    particles: Particles<'a>,
+    /// Frames left of dropping confetti after a match is won
+    confetti_frames_left: Frames,
+}
+
+impl<'a> Effects<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            shimmer: Shimmer::default(),
+            particles: Particles::new(object),
+            confetti_frames_left: Frames::ZERO,
+        }
+    }
+
+    /// Arms any effects that should play for an event
+    pub fn handle_event(&mut self, event: GameEvent) {
+        match event {
+            GameEvent::PaddleHit { side, impact } => self.sparks(side, impact),
+            GameEvent::EdgeShot { side, impact } => {
+                self.shimmer.arm(SMASH_SHIMMER_FRAMES);
+                self.sparks(side, impact);
+            }
+            GameEvent::Goal {
+                scorer,
+                points,
+                position,
+                ..
+            } => {
+                // The ball goes out right at the edge of the screen, so the popup is moved in a
+                // little to make sure all of it can be seen
+                let x = match scorer {
+                    Side::Left => position.x - SCORE_POPUP_INSET,
+                    Side::Right => position.x + SCORE_POPUP_INSET,
+                };
+                let popup = if points > 1 {
+                    SPRITES.plus_two
+                } else {
+                    SPRITES.plus_one
+                };
+                self.particles.spawn(
+                    popup,
+                    (Fixed::new(x), Fixed::new(position.y)).into(),
+                    SCORE_POPUP_VELOCITY,
+                    SCORE_POPUP_FRAMES,
+                );
+            }
+            GameEvent::ShieldBroken { position } => {
+                // One shard flies off in each diagonal, all from the middle of the shield
+                for (x, y) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                    self.particles.spawn(
+                        SPRITES.shard,
+                        (Fixed::new(position.x - 8), Fixed::new(position.y - 8)).into(),
+                        (fixed(x, 1), fixed(y, 2)).into(),
+                        SHARD_FRAMES,
+                    );
+                }
+            }
+            GameEvent::MatchWon { .. } => self.confetti_frames_left = CONFETTI_FRAMES,
+            // Every milestone adds to the ones before it
+            GameEvent::RallyMilestone { milestone } => {
+                if milestone >= 2 {
+                    self.shimmer.arm(RALLY_SHIMMER_FRAMES);
+                }
+                if milestone >= 3 {
+                    self.confetti_frames_left = RALLY_CONFETTI_FRAMES;
+                }
+            }
+            GameEvent::WallBounce { .. }
+            | GameEvent::MatchPoint { .. }
+            | GameEvent::BumperHit
+            | GameEvent::SpecialUsed { .. }
+            | GameEvent::PowerUpCollected { .. } => {}
+        }
+    }
+
+    /// A couple of sparks flying off the paddle from where `side` hit the ball
+    fn sparks(&mut self, side: Side, impact: Impact) {
+        let away = match side {
+            Side::Left => SPARK_SPEED.0,
+            Side::Right => -SPARK_SPEED.0,
+        };
+        let position = impact.point - (8, 8).into();
+        for y in [-SPARK_SPEED.0 / 2, SPARK_SPEED.0 / 2] {
+            self.particles.spawn(
+                SPRITES.shard,
+                position.change_base(),
+                (away, y).into(),
+                SPARK_FRAMES,
+            );
+        }
+    }
+
+    /// Stops every effect, used when the point is restarted
+    pub fn reset(&mut self) {
+        self.shimmer.disarm();
+        self.particles.clear();
+        self.confetti_frames_left = Frames::ZERO;
+    }
+
+    /// Moves all the running effects on by a frame
+    pub fn update(&mut self) {
+        self.shimmer.update();
+        self.particles.update();
+
+        if self.confetti_frames_left > Frames::ZERO {
+            if self.confetti_frames_left % CONFETTI_INTERVAL == Frames::ZERO {
+                self.drop_confetti();
+            }
+            self.confetti_frames_left.tick_down();
+        }
+    }
+
+    /// Drops a piece of confetti from a random place along the top of the screen
+    fn drop_confetti(&mut self) {
+        let tag =
+            SPRITES.confetti[random::gen().rem_euclid(SPRITES.confetti.len() as i32) as usize];
+        let x = random::gen().rem_euclid(agb::display::WIDTH) - 8;
+        let drift = fixed(random::gen().rem_euclid(5) - 2, 8);
+
+        self.particles.spawn(
+            tag,
+            (Fixed::new(x), Fixed::new(-16)).into(),
+            (drift, CONFETTI_FALL_SPEED.0).into(),
+            CONFETTI_LIFETIME,
+        );
+    }
+
+    /// Moves the sprites used by effects to match where the effects are
+    pub fn update_sprites(&mut self) {
+        self.particles.update_sprites();
+    }
+}
@@ -0,0 +1,97 @@
+//! A paddle nobody plays that rides up and down the middle of the court when the elevator
+//! modifier is on, stopping for a moment at each end. Whatever hits it is knocked straight
+//! back off it. It belongs to neither side, so a bounce off it isn't a return for anybody.
+
+use agb::display::object::OamManaged;
+
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::character::{Ability, Character};
+use crate::collision::Collider;
+use crate::court::Court;
+use crate::events::{Events, GameEvent, Impact};
+use crate::paddle::Paddle;
+use crate::physics;
+use crate::tuning::{Fixed, Frames, ELEVATOR_PAUSE_FRAMES, ELEVATOR_SPEED};
+
+/// What the elevator is built like, the classic paddle but shorter. It never uses its special.
+static ELEVATOR: Character = Character {
+    name: "elevator",
+    speed: ELEVATOR_SPEED,
+    segments: 2,
+    ability: Ability::Wall,
+    end: SPRITES.paddle_end,
+    middle: SPRITES.paddle_mid,
+};
+
+pub struct Elevator<'a> {
+    paddle: Paddle<'a>,
+    /// 1 while it's going down and -1 going up
+    direction: i32,
+    /// How much longer it waits at the end it's reached
+    pause_frames_left: Frames,
+}
+
+impl<'a> Elevator<'a> {
+    pub fn new(object: &'a OamManaged<'a>, court: &Court) -> Self {
+        Self {
+            paddle: Paddle::neutral(object, &ELEVATOR, court),
+            direction: 1,
+            pause_frames_left: Frames::ZERO,
+        }
+    }
+
+    pub fn collider(&self, index: usize) -> Collider {
+        self.paddle.collider(index)
+    }
+
+    /// Moves on a frame, turning round once it has stopped at the top or bottom
+    pub fn update(&mut self, court: &Court) {
+        if self.pause_frames_left > Frames::ZERO {
+            self.pause_frames_left.tick_down();
+            return;
+        }
+
+        self.paddle
+            .move_paddle_with_input(Fixed::new(self.direction));
+        self.paddle.checks_and_keeps_in_bounds(court);
+
+        let y = self.paddle.y();
+        let at_end = match self.direction {
+            1 => y == court.bottom() - self.paddle.height(),
+            _ => y == court.top(),
+        };
+        if at_end {
+            self.direction = -self.direction;
+            self.pause_frames_left = ELEVATOR_PAUSE_FRAMES;
+        }
+    }
+
+    /// Bounces `ball` off whichever side of the elevator it ran into, if it's heading into it
+    pub fn deflect(&self, ball: &mut Ball, events: &mut Events) {
+        let velocity = ball.entity.velocity;
+        let relative_velocity = velocity - self.paddle.velocity();
+        let Some(contact) =
+            physics::contact(ball.entity.rect(), self.paddle.rect(), relative_velocity)
+        else {
+            return;
+        };
+        if contact.closing_speed() <= 0.into() {
+            return;
+        }
+
+        // Pushed back out so it doesn't get caught on the elevator as it moves
+        ball.entity.position += contact.normal.change_base() * contact.depth;
+        ball.entity.velocity = physics::reflect(velocity, contact.normal);
+        events.push(GameEvent::WallBounce {
+            impact: Impact {
+                point: contact.point.floor(),
+                speed: contact.closing_speed(),
+            },
+        });
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.paddle.update_sprite_positions();
+    }
+}
@@ -0,0 +1,81 @@
+use agb::display::object::{OamManaged, Object, Sprite};
+use agb::fixnum::{Rect, Vector2D};
+
+use crate::collision::{Collider, Layer};
+use crate::layering::RenderLayer;
+use crate::physics;
+use crate::tuning::Fixed;
+use crate::view;
+
+/// A simple entity struct that holds the sprite and position for any sprite
+pub struct Entity<'a> {
+    pub sprite: Object<'a>,
+    pub position: Vector2D<Fixed>,
+    pub velocity: Vector2D<Fixed>,
+    pub collision_mask: Vector2D<u16>,
+    /// What the entity is when it comes to working out what it can hit
+    pub layer: Layer,
+    /// How big the sprite is drawn, taken from the sprite so bounds follow the art
+    size: Vector2D<i32>,
+}
+
+/// impl of entity to allow for methods to interact with the sprite and setup
+impl<'a> Entity<'a> {
+    /// Creates the entity at `position`, already moving at `velocity`
+    pub fn new(
+        object: &'a OamManaged,
+        sprite: &'static Sprite,
+        collision_mask: Vector2D<u16>,
+        layer: Layer,
+        render_layer: RenderLayer,
+        position: Vector2D<Fixed>,
+        velocity: Vector2D<Fixed>,
+    ) -> Self {
+        let mut object_sprite = object.object_sprite(sprite);
+        render_layer.apply(&mut object_sprite);
+        let (width, height) = sprite.size().to_width_height();
+
+        let mut entity = Entity {
+            sprite: object_sprite,
+            collision_mask,
+            layer,
+            size: (width as i32, height as i32).into(),
+            position,
+            velocity,
+        };
+        entity.update_sprite_position();
+        entity
+    }
+
+    /// Updates the position of the sprite based on what has been set in the position variable
+    pub fn update_sprite_position(&mut self) {
+        self.sprite
+            .set_position(view::position(self.position.floor(), self.size));
+    }
+
+    /// How many pixels wide and tall the sprite is
+    pub fn size(&self) -> Vector2D<i32> {
+        self.size
+    }
+
+    /// The area of the screen the entity collides with
+    pub fn rect(&self) -> Rect<Fixed> {
+        let size: Vector2D<i32> =
+            (self.collision_mask.x as i32, self.collision_mask.y as i32).into();
+        Rect::new(self.position, size.into())
+    }
+
+    /// The entity as the `index`th collider on its layer
+    pub fn collider(&self, index: usize) -> Collider {
+        Collider {
+            layer: self.layer,
+            index,
+            rect: self.rect(),
+        }
+    }
+}
+
+/// Checks if two entities have collided with each other
+pub fn intersects(e1: &Entity, e2: &Entity) -> bool {
+    physics::overlaps(e1.rect(), e2.rect())
+}
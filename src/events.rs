@@ -0,0 +1,83 @@
+//! Things that happen during a match which other parts of the game, like sound, react to.
+//! The gameplay code pushes events as they happen and they are handled once per frame.
+
+use agb::fixnum::Vector2D;
+
+use crate::arrayvec::ArrayVec;
+use crate::paddle::Side;
+use crate::powerup::PowerUpKind;
+use crate::tuning::{Fixed, IMPACT_LOUDEST_SPEED};
+
+/// Where something got hit and how hard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Impact {
+    pub point: Vector2D<i32>,
+    /// How fast the two were coming together
+    pub speed: Fixed,
+}
+
+impl Impact {
+    /// As hard as anything gets hit in the middle of the screen, for the sound of a hit outside
+    /// of a match
+    pub const HARDEST: Impact = Impact {
+        point: Vector2D::new(agb::display::WIDTH / 2, agb::display::HEIGHT / 2),
+        speed: IMPACT_LOUDEST_SPEED.0,
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameEvent {
+    /// The ball bounced off the face of a paddle
+    PaddleHit { side: Side, impact: Impact },
+    /// The ball clipped the top or bottom corner of a paddle and was sent back faster
+    EdgeShot { side: Side, impact: Impact },
+    /// The ball bounced off the top or bottom of the screen, or some other wall
+    WallBounce { impact: Impact },
+    /// The ball went past a paddle and off the side of the screen
+    Goal {
+        scorer: Side,
+        /// How many points the goal was worth
+        points: u32,
+        /// Where the ball was when it went out
+        position: Vector2D<i32>,
+        /// Nobody touched the ball after it was served, so the receiver never got to it
+        ace: bool,
+    },
+    /// A player spent their energy on their special
+    SpecialUsed { side: Side },
+    /// The ball went through a power up
+    PowerUpCollected { side: Side, kind: PowerUpKind },
+    /// The ball smashed into a shield and broke it
+    ShieldBroken {
+        /// The middle of the shield
+        position: Vector2D<i32>,
+    },
+    /// The ball bounced off one of the bumpers
+    BumperHit,
+    /// A goal left `side` one point away from winning the match
+    MatchPoint { side: Side },
+    /// The winning point of the match was scored
+    MatchWon { winner: Side },
+    /// The rally got long enough to reach a milestone, 1 for the first and going up from there
+    RallyMilestone { milestone: u32 },
+}
+
+/// The most events that can happen in a single frame
+const MAX_EVENTS: usize = 16;
+
+/// Queue of the events that happened this frame
+#[derive(Default)]
+pub struct Events {
+    pending: ArrayVec<GameEvent, MAX_EVENTS>,
+}
+
+impl Events {
+    pub fn push(&mut self, event: GameEvent) {
+        self.pending.push(event);
+    }
+
+    /// Takes all the events that have happened since the last drain
+    pub fn drain(&mut self) -> impl Iterator<Item = GameEvent> + '_ {
+        self.pending.drain()
+    }
+}
@@ -0,0 +1,134 @@
+//! Picks the looks and cheats that have been unlocked, L on the select screen gets here. Up and
+//! down move through them and A picks one, B goes back. The ones still locked are greyed out
+//! with what needs doing to unlock them. A on a ball skin opens the gallery to get a better look
+//! before picking it.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::options::Options;
+use crate::profile::Achievements;
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+use crate::unlocks::{Unlock, UNLOCKS};
+
+/// One of the lists of entries, either the unlocked ones or the locked ones. Both are drawn down
+/// the same lines with blanks where the other list's entries go, so each can have its own colour.
+struct List<'o> {
+    options: &'o Options,
+    achievements: Achievements,
+    unlocked: bool,
+    cursor: usize,
+}
+
+impl Display for List<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let language = self.options.language;
+        for (index, entry) in UNLOCKS.iter().enumerate() {
+            if entry.is_unlocked(self.achievements) != self.unlocked {
+                writeln!(f)?;
+                continue;
+            }
+
+            let arrow = if index == self.cursor { ">" } else { " " };
+            let kind = entry.unlock.kind().get(language);
+            let name = entry.name.get(language);
+            write!(f, "{arrow} {kind}: {name}")?;
+            match entry.requirement {
+                Some(requirement) if !self.unlocked => {
+                    write!(f, " - {}", requirement.description().get(language))?;
+                }
+                _ if entry.unlock.is_chosen(self.options) => f.write_str(" *")?,
+                _ => {}
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Extras<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    unlocked: Label<'a>,
+    locked: Label<'a>,
+    cursor: usize,
+}
+
+impl<'a> Extras<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut heading = Label::new(resources.tiled, frame.vram, (12, 1));
+        let heading_text = Text::Extras.get(frame.options.language);
+        heading.write(frame.vram, text::TEXT, format_args!("{heading_text}"));
+
+        let mut extras = Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            heading,
+            unlocked: Label::new(resources.tiled, frame.vram, (3, 4)),
+            locked: Label::new(resources.tiled, frame.vram, (3, 4)),
+            cursor: 0,
+        };
+        extras.show(frame);
+        extras
+    }
+
+    fn show(&mut self, frame: &mut Frame) {
+        let achievements = frame.save.profiles.current().achievements;
+        let list = |unlocked| List {
+            options: frame.options,
+            achievements,
+            unlocked,
+            cursor: self.cursor,
+        };
+        let (unlocked, locked) = (list(true), list(false));
+        self.unlocked
+            .write(frame.vram, text::TEXT, format_args!("{unlocked}"));
+        self.locked
+            .write(frame.vram, text::LOCKED, format_args!("{locked}"));
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Select(None));
+        }
+
+        let entry = &UNLOCKS[self.cursor];
+        if frame.input.is_just_pressed(Button::A) && matches!(entry.unlock, Unlock::BallSkin(_)) {
+            return Some(Next::Gallery);
+        }
+        let achievements = frame.save.profiles.current().achievements;
+        let chose = frame.input.is_just_pressed(Button::A) && entry.is_unlocked(achievements);
+        if chose {
+            entry.unlock.choose(frame.options);
+            frame.save.profiles.current_mut().options = *frame.options;
+            frame.save.write();
+            frame.apply_options();
+        }
+
+        let cursor_change = if frame.input.is_just_pressed(Button::UP) {
+            UNLOCKS.len() - 1
+        } else if frame.input.is_just_pressed(Button::DOWN) {
+            1
+        } else {
+            0
+        };
+        self.cursor = (self.cursor + cursor_change) % UNLOCKS.len();
+
+        if chose || cursor_change != 0 {
+            self.show(frame);
+        }
+
+        None
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.unlocked.clear(vram);
+        self.locked.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
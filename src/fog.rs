@@ -0,0 +1,90 @@
+//! Hides the opponent's half of the court with the windows so you can only see your own side.
+//! The ball is still shown while it is close to the net so a return isn't a complete surprise.
+//! In link play each console would fog the half away from its own player, for now that's only
+//! ever the computer's half.
+
+use agb::display::tiled::BackgroundID;
+use agb::display::window::{MovableWindow, WinIn, Windows};
+use agb::fixnum::Rect;
+
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::paddle::Side;
+use crate::tuning::{FOG_REVEAL_DISTANCE, FOG_REVEAL_SIZE};
+use crate::view;
+
+pub struct Fog {
+    /// Whoever the fog is hiding the other half from
+    viewer: Side,
+    /// The backgrounds that make up the court and its counters, shown on the viewer's half
+    backgrounds: [BackgroundID; 3],
+}
+
+impl Fog {
+    pub fn new(viewer: Side, backgrounds: [BackgroundID; 3]) -> Self {
+        Self {
+            viewer,
+            backgrounds,
+        }
+    }
+
+    /// Moves the windows to cover the far half and cut a hole around the ball if it's near the
+    /// net. Outside both windows only the backdrop is drawn.
+    pub fn update(&self, windows: &mut Windows, balls: &[Ball], court: &Court) {
+        let half = court.centre_x();
+        let own_half = match self.viewer {
+            Side::Left => Rect::new((0, 0).into(), (half, agb::display::HEIGHT).into()),
+            Side::Right => Rect::new(
+                (half, 0).into(),
+                (agb::display::WIDTH - half, agb::display::HEIGHT).into(),
+            ),
+        };
+        self.show_everything(windows.win_in(WinIn::Win0))
+            .set_position(&view::rect(own_half))
+            .enable();
+
+        windows.win_out().reset().enable();
+
+        // Only a ball that has just crossed into the fog is worth showing
+        let revealed = balls.iter().find(|ball| {
+            let past_net = match self.viewer {
+                Side::Left => ball.entity.position.x.floor() - half,
+                Side::Right => half - ball.entity.position.x.floor() - ball.entity.size().x,
+            };
+            (0..FOG_REVEAL_DISTANCE).contains(&past_net)
+        });
+
+        let reveal = windows.win_in(WinIn::Win1);
+        match revealed {
+            Some(ball) => {
+                let centre = ball.entity.position.floor() + ball.entity.size() / 2;
+                let corner = centre - (FOG_REVEAL_SIZE / 2, FOG_REVEAL_SIZE / 2).into();
+                let hole = Rect::new(corner, (FOG_REVEAL_SIZE, FOG_REVEAL_SIZE).into());
+                self.show_everything(reveal)
+                    .set_position(&view::rect(hole))
+                    .enable();
+            }
+            None => {
+                reveal.disable();
+            }
+        }
+    }
+
+    fn show_everything<'w>(&self, window: &'w mut MovableWindow) -> &'w mut MovableWindow {
+        window
+            .reset()
+            .set_object_enable(true)
+            .set_blend_enable(true);
+        for background in self.backgrounds {
+            window.set_background_enable(background, true);
+        }
+        window
+    }
+
+    /// Takes the fog away so the whole screen is drawn normally again
+    pub fn clear(windows: &mut Windows) {
+        windows.win_in(WinIn::Win0).reset();
+        windows.win_in(WinIn::Win1).reset();
+        windows.win_out().reset();
+    }
+}
@@ -0,0 +1,40 @@
+//! Measures how much of a frame the game's own work takes, by seeing which line the screen has
+//! got to when it's done. A new slowest frame goes to the debug log once it's using most of the
+//! frame, so it's easy to spot when something has pushed the 16MHz CPU close to dropping frames.
+
+use crate::tuning::FRAME_TIME_WARNING_LINES;
+
+/// The line the screen is drawing right now
+const VCOUNT: *const u16 = 0x0400_0006 as *const u16;
+/// How many lines go by each frame, counting the ones in vblank
+const LINES_PER_FRAME: u16 = 228;
+
+#[derive(Default)]
+pub struct FrameTime {
+    started: u16,
+    /// The most lines any frame has taken so far
+    peak: u16,
+}
+
+impl FrameTime {
+    fn line() -> u16 {
+        // Safety: reading VCOUNT has no side effects
+        unsafe { VCOUNT.read_volatile() }
+    }
+
+    pub fn start(&mut self) {
+        self.started = Self::line();
+    }
+
+    /// Ends the frame's measurement. Anything longer than a whole frame wraps round and looks
+    /// quick, but that frame will already have been dropped.
+    pub fn finish(&mut self) {
+        let lines = (Self::line() + LINES_PER_FRAME - self.started) % LINES_PER_FRAME;
+        if lines > self.peak {
+            self.peak = lines;
+            if lines >= FRAME_TIME_WARNING_LINES {
+                agb::println!("frame took {} of {} lines", lines, LINES_PER_FRAME);
+            }
+        }
+    }
+}
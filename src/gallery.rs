@@ -0,0 +1,164 @@
+//! A closer look at the ball skins, picking a ball skin in the extras gets here. The skin is
+//! shown big and spinning through its animation, left and right flick between them and A picks
+//! the one shown if it's unlocked. B goes back to the extras.
+
+use agb::display::affine::AffineMatrix;
+use agb::display::object::{AffineMatrixInstance, AffineMode, OamManaged, Object};
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::fixnum::Vector2D;
+use agb::input::Button;
+
+use crate::assets::{BallSkin, SPRITES};
+use crate::background::{self, Backdrop};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+use crate::tuning::{Fixed, GALLERY_ANIMATION_FRAMES, GALLERY_PREVIEW_SCALE, GALLERY_SPIN_SPEED};
+use crate::unlocks::{Unlock, UNLOCKS};
+
+pub struct Gallery<'a> {
+    object: &'a OamManaged<'a>,
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    name: Label<'a>,
+    /// What needs doing to unlock the skin, or that it's the one picked
+    detail: Label<'a>,
+    preview: Object<'a>,
+    /// Which of `BallSkin::ALL` is shown
+    shown: usize,
+    rotation: Fixed,
+    frame: usize,
+}
+
+impl<'a> Gallery<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut heading = Label::new(resources.tiled, frame.vram, (11, 1));
+        let heading_text = Text::BallSkins.get(frame.options.language);
+        heading.write(frame.vram, text::TEXT, format_args!("{heading_text}"));
+
+        let shown = BallSkin::ALL
+            .iter()
+            .position(|&skin| skin == frame.options.ball_skin)
+            .unwrap_or(0);
+        let mut preview = resources
+            .object
+            .object_sprite(SPRITES.ball_skin(BallSkin::ALL[shown]).sprite(0));
+        // A double sized affine object takes up twice the sprite, so it's moved back by that
+        let position: Vector2D<i32> =
+            (agb::display::WIDTH / 2 - 16, agb::display::HEIGHT / 2 - 16).into();
+        preview.set_position(position);
+
+        let mut gallery = Self {
+            object: resources.object,
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            heading,
+            name: Label::new(resources.tiled, frame.vram, (11, 4)),
+            detail: Label::new(resources.tiled, frame.vram, (8, 15)),
+            preview,
+            shown,
+            rotation: 0.into(),
+            frame: 0,
+        };
+        gallery.show(frame);
+        gallery
+    }
+
+    fn skin(&self) -> BallSkin {
+        BallSkin::ALL[self.shown]
+    }
+
+    fn is_unlocked(&self, frame: &Frame) -> bool {
+        let achievements = frame.save.profiles.current().achievements;
+        UNLOCKS
+            .iter()
+            .find(|entry| entry.unlock == Unlock::BallSkin(self.skin()))
+            .is_some_and(|entry| entry.is_unlocked(achievements))
+    }
+
+    /// Writes out the name of the skin shown and whether it can be picked
+    fn show(&mut self, frame: &mut Frame) {
+        let language = frame.options.language;
+        let entry = UNLOCKS
+            .iter()
+            .find(|entry| entry.unlock == Unlock::BallSkin(self.skin()));
+        let Some(entry) = entry else {
+            return;
+        };
+
+        let unlocked = self.is_unlocked(frame);
+        let colour = if unlocked {
+            text::HIGHLIGHT
+        } else {
+            text::LOCKED
+        };
+        let name = entry.name.get(language);
+        self.name
+            .write(frame.vram, colour, format_args!("< {name} >"));
+
+        let detail = match entry.requirement {
+            Some(requirement) if !unlocked => requirement.description(),
+            _ if frame.options.ball_skin == self.skin() => Text::Picked,
+            _ => Text::PressAToPick,
+        };
+        let detail = detail.get(language);
+        self.detail
+            .write(frame.vram, text::TEXT, format_args!("{detail}"));
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Extras);
+        }
+
+        let picked = frame.input.is_just_pressed(Button::A) && self.is_unlocked(frame);
+        if picked {
+            frame.options.ball_skin = self.skin();
+            frame.save.profiles.current_mut().options = *frame.options;
+            frame.save.write();
+        }
+
+        let change = if frame.input.is_just_pressed(Button::LEFT) {
+            BallSkin::ALL.len() - 1
+        } else if frame.input.is_just_pressed(Button::RIGHT) {
+            1
+        } else {
+            0
+        };
+        if change != 0 {
+            self.shown = (self.shown + change) % BallSkin::ALL.len();
+            self.frame = 0;
+        }
+
+        if picked || change != 0 {
+            self.show(frame);
+        }
+
+        self.rotation += GALLERY_SPIN_SPEED;
+        self.frame += 1;
+        None
+    }
+
+    /// Spins the preview and steps it through the skin's animation
+    pub fn update_sprites(&mut self) {
+        let tag = SPRITES.ball_skin(self.skin());
+        let sprite =
+            tag.sprite((self.frame / GALLERY_ANIMATION_FRAMES.0 as usize) % tag.sprites().len());
+
+        // The scale is the inverse of how big the sprite appears, the gba maps from the screen
+        // back to the sprite
+        let scale = Fixed::new(1) / GALLERY_PREVIEW_SCALE;
+        let matrix = AffineMatrix::from_rotation(self.rotation)
+            * AffineMatrix::from_scale((scale, scale).into());
+        self.preview
+            .set_sprite(self.object.sprite(sprite))
+            .set_affine_matrix(AffineMatrixInstance::new(matrix.to_object_wrapping()))
+            .show_affine(AffineMode::AffineDouble);
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.name.clear(vram);
+        self.detail.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
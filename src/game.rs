@@ -0,0 +1,944 @@
+//! A match of pong between the player on the left and the computer on the right
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::dma::{Dma, DmaTransferHandle};
+use agb::input::Button;
+use alloc::vec::Vec;
+
+use crate::ai::{Ai, Difficulty};
+use crate::arena::MatchArena;
+use crate::arrayvec::ArrayVec;
+use crate::background;
+use crate::ball::{Ball, BallKind};
+use crate::bumper::Bumpers;
+use crate::celebration::Celebration;
+#[cfg(feature = "bonus-modes")]
+use crate::challenge::Challenge;
+use crate::character::{Ability, Character};
+use crate::collision::{self, Collider, Colliders, Layer, MAX_COLLIDERS};
+#[cfg(feature = "debug-tools")]
+use crate::console::TuningConsole;
+use crate::controller::{Controller, Dpad, InputBuffer};
+use crate::controls::Controls;
+use crate::court::Court;
+use crate::crowd::Crowd;
+use crate::daylight::Daylight;
+use crate::digits::Digits;
+use crate::effects::Effects;
+use crate::elevator::Elevator;
+use crate::events::{Events, GameEvent};
+use crate::fog::Fog;
+use crate::hint::Hint;
+use crate::modifiers::Modifiers;
+use crate::paddle::{Paddle, Side};
+use crate::photo::PhotoFinish;
+use crate::portal::Portals;
+use crate::powerup::{PowerUpKind, PowerUps};
+use crate::preview::ServePreview;
+use crate::rally::Rally;
+use crate::random;
+use crate::replay::{Recording, Replay};
+use crate::scene::{Frame, Next, Resources};
+use crate::score::Score;
+use crate::shield::Shield;
+use crate::shimmer::ScanlineOffsets;
+use crate::special::Special;
+use crate::stats::Stats;
+use crate::taunt::Taunt;
+#[cfg(feature = "debug-tools")]
+use crate::trace::InputTrace;
+use crate::trail::Trail;
+use crate::tuning::{LOPSIDED_SPEEDS, PADDLE_SHRINK_SCORES, POINTS_TO_WIN};
+#[cfg(feature = "bonus-modes")]
+use crate::tweaks::{self, Tweaks};
+use crate::view;
+use crate::warmup::Warmup;
+use crate::watchdog::Watchdog;
+use crate::weather::Weather;
+
+/// The real ball and the copy from a split
+const MAX_BALLS: usize = 2;
+/// The elevator's collider index on the paddle layer, after the two players' paddles
+const ELEVATOR_INDEX: usize = 2;
+
+/// Who is playing and by what rules, kept so a rematch can be played the same way
+#[derive(Clone, Copy)]
+pub struct MatchSettings {
+    pub player: &'static Character,
+    pub cpu: &'static Character,
+    pub difficulty: Difficulty,
+    pub modifiers: Modifiers,
+    /// Where the match's seed and ball speed come from, if it's a challenge
+    #[cfg(feature = "bonus-modes")]
+    pub challenge: Option<Challenge>,
+}
+
+/// Which part of the match is being played out
+enum Phase<'a> {
+    Playing,
+    /// A goal has just gone in and everything has stopped for a moment
+    Frozen(PhotoFinish),
+    /// The winning point has gone in and is being shown again slowly
+    Replaying(Replay<'a>),
+    /// Someone has won and the match is finishing up
+    Celebrating(Celebration<'a>),
+}
+
+pub struct Game<'a> {
+    resources: Resources<'a>,
+    phase: Phase<'a>,
+    court_background: MapLoan<'a, RegularMap>,
+    court: Court,
+    modifiers: Modifiers,
+    net: MapLoan<'a, RegularMap>,
+    /// Darkens the court as the match goes on
+    daylight: Daylight,
+    events: Events,
+    effects: Effects<'a>,
+    crowd: Crowd<'a>,
+    /// Whatever is falling on the court, if its theme has weather and motion isn't reduced
+    weather: Option<Weather<'a>>,
+    score: Score,
+    /// How the match has gone, for the results screen
+    stats: Stats,
+    /// Each side's score under their meter
+    left_score: Digits<'a>,
+    right_score: Digits<'a>,
+    watchdog: Watchdog,
+    /// Every ball in play. The first is always the real one, any others are copies from a split.
+    balls: Vec<Ball<'a>, MatchArena>,
+    /// Follows the real ball in the colour of whoever hit it last
+    trail: Trail<'a>,
+    left_paddle: Paddle<'a>,
+    right_paddle: Paddle<'a>,
+    left_special: Special<'a>,
+    right_special: Special<'a>,
+    left_controls: Controls<'a>,
+    right_controls: Controls<'a>,
+    left_controller: Controller,
+    right_controller: Controller,
+    /// Who is holding the ball waiting to serve, `None` during a rally
+    server: Option<Side>,
+    /// The computer's idle bobbing and showing off
+    taunt: Taunt,
+    /// Where the ball is going to reach the player, if coaching hints are on
+    hint: Option<Hint<'a>>,
+    /// Dots showing where the player's serve will go while they aim it
+    serve_preview: ServePreview<'a>,
+    /// The player bouncing the ball on their paddle while they wait to serve
+    warmup: Warmup,
+    power_ups: PowerUps<'a>,
+    shields: Vec<Shield<'a>, MatchArena>,
+    /// The pair of portals, when the portals modifier is on
+    portals: Option<Portals<'a>>,
+    /// The bumpers in the middle of the court, when the bumpers modifier is on
+    bumpers: Option<Bumpers<'a>>,
+    /// The paddle riding up and down the middle, when the elevator modifier is on
+    elevator: Option<Elevator<'a>>,
+    /// Hides the computer's half of the court when the fog modifier is on
+    fog: Option<Fog>,
+    /// How many returns there have been since the serve
+    rally: Rally<'a>,
+    /// Where everything has been since the serve, for replaying the winning point
+    recording: Recording,
+    /// The player's buttons since the start of the match, for bug reports
+    #[cfg(feature = "debug-tools")]
+    trace: InputTrace,
+    /// The debug tuning console, the match is paused while it's open
+    #[cfg(feature = "debug-tools")]
+    console: Option<TuningConsole<'a>>,
+    /// What the match was started with, handed on to the results for a rematch
+    settings: MatchSettings,
+    /// What the tweaks were before a challenge changed the ball speed, put back afterwards
+    #[cfg(feature = "bonus-modes")]
+    tweaks_before: Option<Tweaks>,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame, settings: MatchSettings) -> Self {
+        let MatchSettings {
+            player,
+            cpu,
+            difficulty,
+            modifiers,
+            #[cfg(feature = "bonus-modes")]
+            challenge,
+        } = settings;
+        // A fresh seed for every match unless the challenge has one, kept in the trace so the
+        // match can be played again
+        let fresh_seed = || core::array::from_fn(|_| agb::rng::gen() as u32 | 1);
+        #[cfg(feature = "bonus-modes")]
+        let seed = challenge.map_or_else(fresh_seed, |challenge| challenge.seed);
+        #[cfg(not(feature = "bonus-modes"))]
+        let seed = fresh_seed();
+        random::seed(seed);
+        #[cfg(feature = "bonus-modes")]
+        let tweaks_before = challenge.map(|challenge| {
+            let before = tweaks::get();
+            tweaks::set(Tweaks {
+                minimum_horizontal_speed: challenge.ball_speed,
+                ..before
+            });
+            before
+        });
+        // Set before anything is drawn so the paddles face the right way from the start
+        view::set_mirrored(frame.options.mirrored);
+
+        let mut court = Court::default();
+        let backdrop = frame.options.court_theme.backdrop();
+        let mut court_background = background::backdrop(resources.tiled, frame.vram, backdrop);
+        if modifiers.lopsided_court {
+            court.set_half_speeds(LOPSIDED_SPEEDS);
+            background::tint_halves(&mut court_background, frame.vram, backdrop, &court);
+        }
+        let net = background::net(resources.tiled, frame.vram, frame.options.crt_overlay);
+        frame
+            .blending
+            .set_translucent(net.background(), court_background.background());
+        let rally = Rally::new(resources.tiled, frame.vram);
+        let fog = modifiers.fog.then(|| {
+            Fog::new(
+                Side::Left,
+                [
+                    court_background.background(),
+                    net.background(),
+                    rally.background(),
+                ],
+            )
+        });
+
+        let mut balls = Vec::with_capacity_in(MAX_BALLS, MatchArena);
+        balls.push(Ball::new(resources.object, frame.options.ball_skin));
+
+        Self {
+            resources,
+            phase: Phase::Playing,
+            court_background,
+            court,
+            modifiers,
+            net,
+            daylight: Daylight::default(),
+            events: Events::default(),
+            effects: Effects::new(resources.object),
+            crowd: Crowd::new(resources.object),
+            weather: frame
+                .options
+                .court_theme
+                .weather()
+                .filter(|_| !frame.options.reduced_motion)
+                .map(|kind| Weather::new(resources.object, kind)),
+            score: Score::default(),
+            stats: Stats::default(),
+            left_score: Digits::new(resources.object, 2),
+            right_score: Digits::new(resources.object, 2),
+            watchdog: Watchdog::default(),
+            balls,
+            trail: Trail::new(resources.object),
+            left_paddle: Paddle::new(resources.object, player, Side::Left, &court),
+            right_paddle: Paddle::new(resources.object, cpu, Side::Right, &court),
+            left_special: Special::new(resources.object, player.ability, Side::Left),
+            right_special: Special::new(resources.object, cpu.ability, Side::Right),
+            left_controls: Controls::new(resources.object, Side::Left),
+            right_controls: Controls::new(resources.object, Side::Right),
+            // Starts aimed at the flat serve in the middle
+            left_controller: Controller::Player {
+                aim: 2,
+                buffer: InputBuffer::default(),
+                dpad: Dpad::default(),
+            },
+            right_controller: Controller::Computer(Ai::new(difficulty)),
+            // The player always starts with the ball
+            server: Some(Side::Left),
+            taunt: Taunt::new(Side::Right),
+            // Hints are to help with learning, so they never turn up on hard
+            hint: (frame.options.coaching_hints && difficulty != Difficulty::Hard)
+                .then(|| Hint::new(resources.object)),
+            serve_preview: ServePreview::new(resources.object),
+            warmup: Warmup::default(),
+            power_ups: PowerUps::new(resources.object),
+            // One a side
+            shields: Vec::with_capacity_in(2, MatchArena),
+            portals: modifiers
+                .portals
+                .then(|| Portals::new(resources.object, &court)),
+            bumpers: modifiers
+                .bumpers
+                .then(|| Bumpers::new(resources.object, &court)),
+            elevator: modifiers
+                .elevator
+                .then(|| Elevator::new(resources.object, &court)),
+            fog,
+            rally,
+            recording: Recording::new(),
+            #[cfg(feature = "debug-tools")]
+            trace: InputTrace::new(seed),
+            #[cfg(feature = "debug-tools")]
+            console: None,
+            settings,
+            #[cfg(feature = "bonus-modes")]
+            tweaks_before,
+        }
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        #[cfg(feature = "debug-tools")]
+        self.trace.record(frame.input);
+        self.crowd.update();
+        if let Some(weather) = &mut self.weather {
+            weather.update();
+        }
+        self.left_controller.record(frame.input);
+        self.right_controller.record(frame.input);
+        #[cfg(feature = "debug-tools")]
+        if InputTrace::wants_dump(frame.input) {
+            self.trace.dump();
+        }
+
+        let next = match &mut self.phase {
+            Phase::Playing => {
+                if !self.update_console(frame) {
+                    self.play(frame);
+                }
+                if let Some(fog) = &self.fog {
+                    fog.update(frame.windows, &self.balls, &self.court);
+                }
+                None
+            }
+            Phase::Frozen(photo_finish) => {
+                frame
+                    .blending
+                    .set_flash(self.court_background.background(), photo_finish.flash());
+                self.court_background.set_scroll_pos(photo_finish.nudge());
+                if photo_finish.update() {
+                    let server = photo_finish.server;
+                    frame.blending.clear_flash();
+                    self.court_background.set_scroll_pos((0_i16, 0_i16));
+                    self.phase = Phase::Playing;
+                    self.finish_point(server, frame);
+                }
+                None
+            }
+            Phase::Replaying(replay) => {
+                Fog::clear(frame.windows);
+                let finished = replay.update(
+                    &mut self.balls[0],
+                    &mut self.left_paddle,
+                    &mut self.right_paddle,
+                ) || frame.input.is_just_pressed(Button::START);
+                if finished {
+                    replay.leave(frame.vram);
+                    let winner = replay.winner;
+                    self.celebrate(winner, frame.vram);
+                }
+                None
+            }
+            Phase::Celebrating(celebration) => {
+                // The fog lifts so everyone can see the winner celebrate
+                Fog::clear(frame.windows);
+                let winning_paddle = match celebration.winner {
+                    Side::Left => &mut self.left_paddle,
+                    Side::Right => &mut self.right_paddle,
+                };
+                let finished =
+                    celebration.update(winning_paddle, frame.vram, frame.options.language);
+                finished.then(|| Next::Results(celebration.score(), self.stats, self.settings))
+            }
+        };
+
+        for event in self.events.drain() {
+            frame.audio.handle_event(event);
+            self.effects.handle_event(event);
+            self.crowd.handle_event(event);
+            self.taunt.handle_event(event);
+            self.stats.handle_event(event);
+        }
+
+        next
+    }
+
+    /// Runs the tuning console while it's open or opens it, returning true while it has the
+    /// match paused
+    #[cfg(feature = "debug-tools")]
+    fn update_console(&mut self, frame: &mut Frame) -> bool {
+        if let Some(console) = &mut self.console {
+            if console.update(frame.input, frame.vram) {
+                console.leave(frame.vram);
+                self.console = None;
+            }
+            true
+        } else if TuningConsole::wants_open(frame.input) {
+            self.console = Some(TuningConsole::new(self.resources.tiled, frame.vram));
+            true
+        } else {
+            false
+        }
+    }
+
+    #[cfg(not(feature = "debug-tools"))]
+    fn update_console(&mut self, _frame: &mut Frame) -> bool {
+        false
+    }
+
+    /// Runs a frame of the rally
+    fn play(&mut self, frame: &mut Frame) {
+        self.left_special.update();
+        self.right_special.update();
+        self.rally.update();
+        self.daylight.update();
+        self.stats.frames.tick();
+
+        if self.left_controller.wants_special(&self.balls) && self.left_special.trigger(&self.court)
+        {
+            self.left_controller.used_special();
+            self.events
+                .push(GameEvent::SpecialUsed { side: Side::Left });
+        }
+        if self.right_controller.wants_special(&self.balls)
+            && self.right_special.trigger(&self.court)
+        {
+            self.right_controller.used_special();
+            self.events
+                .push(GameEvent::SpecialUsed { side: Side::Right });
+        }
+        self.left_paddle
+            .set_boosted(self.left_special.is(Ability::SpeedBurst));
+        self.right_paddle
+            .set_boosted(self.right_special.is(Ability::SpeedBurst));
+
+        // Decide where the paddles want to go this frame before anything moves. Both
+        // controllers go through their controls so a curse can swap up and down on either.
+        self.left_controls.update();
+        self.right_controls.update();
+        let followed = ball_for_ai(&self.balls);
+        let left_input = self
+            .left_controller
+            .vertical(frame.input, &self.left_paddle, followed);
+        let right_input = self
+            .right_controller
+            .vertical(frame.input, &self.right_paddle, followed);
+        self.left_paddle
+            .move_paddle_with_input(self.left_controls.vertical(left_input));
+        self.right_paddle
+            .move_paddle_with_input(self.right_controls.vertical(right_input));
+        self.left_paddle.checks_and_keeps_in_bounds(&self.court);
+        self.right_paddle.checks_and_keeps_in_bounds(&self.court);
+
+        if let Some(hint) = &mut self.hint {
+            match self.server {
+                Some(_) => hint.hide(),
+                None => hint.update(&self.balls[0], &self.left_paddle, &self.court),
+            }
+        }
+
+        self.balls.retain_mut(|ball| ball.update_lifetime());
+        self.shields.retain_mut(|shield| shield.update());
+        if let Some(portals) = &mut self.portals {
+            portals.update();
+        }
+        if let Some(bumpers) = &mut self.bumpers {
+            bumpers.update();
+        }
+        if let Some(elevator) = &mut self.elevator {
+            elevator.update(&self.court);
+        }
+        self.power_ups.update(&self.court);
+        self.taunt.update(self.server.is_some());
+
+        // Nothing else can happen to the ball until it has been served
+        if let Some(server) = self.server {
+            self.hold_serve(server, frame);
+            return;
+        }
+
+        for ball in &mut self.balls {
+            // This will calculate the new position and enforce the position
+            // of the ball remains within the screen
+            ball.checks_and_keeps_in_bounds(&self.court);
+
+            // We check if the ball reaches the top or bottom of the screen and reverse it's direction
+            ball.bounce_if_hits_screen_bounds(&self.court, &mut self.events);
+        }
+
+        let mut contacts = Contacts::default();
+        for &(ball, other) in self.find_contacts().iter() {
+            self.handle_contact(ball, other, &mut contacts, frame);
+        }
+
+        // The shields that were hit go once every ball has been checked against them
+        contacts.broken_shields.sort_unstable();
+        for &index in contacts.broken_shields.iter().rev() {
+            self.shields.remove(index);
+        }
+
+        let mut goals: ArrayVec<_, MAX_BALLS> = ArrayVec::new();
+        for (index, ball) in self.balls.iter_mut().enumerate() {
+            // Make sure the bounces this frame haven't left the ball going nearly straight up and down
+            ball.keep_moving_horizontally();
+
+            // The ball can be on the goal line when it hits a shield, so that stops it being
+            // counted as a goal
+            if contacts.shielded_balls.contains(&index) {
+                continue;
+            }
+
+            let Some(scorer) = ball.scoring_side(&self.court) else {
+                continue;
+            };
+
+            // A wall special in front of the goal sends the ball back instead of letting it score
+            let defender = match scorer.opposite() {
+                Side::Left => &self.left_special,
+                Side::Right => &self.right_special,
+            };
+            if defender.is(Ability::Wall) {
+                ball.bounce_off_goal_wall(&mut self.events);
+            } else {
+                goals.push((index, scorer));
+            }
+        }
+
+        self.recording
+            .record(&self.balls[0], &self.left_paddle, &self.right_paddle);
+        self.trail.update(&self.balls[0]);
+
+        if let Some((kind, side)) = contacts.collected {
+            self.collect_power_up(kind, side);
+        }
+
+        if !goals.is_empty() {
+            self.score_goals(&goals, frame);
+            return;
+        }
+
+        // If the ball has ended up somewhere it shouldn't be, log why and start the point again
+        if let Some(problem) = self.watchdog.check(
+            &self.balls[0],
+            [&self.left_paddle, &self.right_paddle],
+            &self.court,
+        ) {
+            agb::println!("Watchdog re-serving the ball: {:?}", problem);
+            self.serve(Side::Left, frame.vram);
+            self.effects.reset();
+            self.power_ups.clear();
+            self.left_controls.reset();
+            self.right_controls.reset();
+        }
+    }
+
+    /// Every ball paired with something it might be touching, the ball always first
+    #[link_section = ".iwram.collision"]
+    #[instruction_set(arm::a32)]
+    fn find_contacts(&self) -> collision::Pairs {
+        let mut colliders: Colliders = self
+            .balls
+            .iter()
+            .enumerate()
+            .map(|(index, ball)| ball.entity.collider(index))
+            .collect();
+        colliders.push(self.left_paddle.collider(0));
+        colliders.push(self.right_paddle.collider(1));
+        if let Some(elevator) = &self.elevator {
+            colliders.push(elevator.collider(ELEVATOR_INDEX));
+        }
+        if let Some(rect) = self.power_ups.rect() {
+            colliders.push(Collider {
+                layer: Layer::PowerUp,
+                index: 0,
+                rect,
+            });
+        }
+        colliders.extend(
+            self.shields
+                .iter()
+                .enumerate()
+                .map(|(index, shield)| Collider {
+                    layer: Layer::Shield,
+                    index,
+                    rect: shield.rect(),
+                }),
+        );
+        if let Some(portals) = &self.portals {
+            colliders.extend(portals.colliders());
+        }
+        if let Some(bumpers) = &self.bumpers {
+            colliders.extend(bumpers.colliders());
+        }
+
+        collision::broadphase(&colliders)
+            .iter()
+            .map(|&(first, second)| {
+                if first.layer == Layer::Ball {
+                    (first, second)
+                } else {
+                    (second, first)
+                }
+            })
+            .collect()
+    }
+
+    /// Works out what happens when a ball touches something, by what that something is
+    fn handle_contact(
+        &mut self,
+        ball: Collider,
+        other: Collider,
+        contacts: &mut Contacts,
+        frame: &mut Frame,
+    ) {
+        let ball_index = ball.index;
+        let ball = &mut self.balls[ball_index];
+        match other.layer {
+            Layer::Paddle if other.index == ELEVATOR_INDEX => {
+                if let Some(elevator) = &self.elevator {
+                    elevator.deflect(ball, &mut self.events);
+                }
+            }
+            Layer::Paddle => {
+                let (paddle, special) = match other.index {
+                    0 => (&mut self.left_paddle, &mut self.left_special),
+                    _ => (&mut self.right_paddle, &mut self.right_special),
+                };
+                if paddle.checks_all_collisions(ball, &mut self.events) {
+                    ball.last_hit_by = Some(paddle.side());
+                    if let Some(milestone) = self.rally.add_return(frame.vram) {
+                        self.events.push(GameEvent::RallyMilestone { milestone });
+                    }
+                    special.fill();
+                    if special.is(Ability::Curveball) {
+                        ball.curve();
+                        special.finish();
+                    }
+                }
+            }
+            Layer::PowerUp => {
+                if let Some(power_up) = self.power_ups.collect(ball) {
+                    contacts.collected = Some(power_up);
+                }
+            }
+            Layer::Shield => {
+                let shield = &self.shields[other.index];
+                if !contacts.broken_shields.contains(&other.index) && shield.is_hit_by(ball) {
+                    ball.bounce_off_goal_wall(&mut self.events);
+                    self.events.push(GameEvent::ShieldBroken {
+                        position: shield.centre(),
+                    });
+                    contacts.broken_shields.push(other.index);
+                    contacts.shielded_balls.push(ball_index);
+                }
+            }
+            Layer::Portal => {
+                if let Some(portals) = &self.portals {
+                    if ball.can_teleport() {
+                        ball.teleport(portals.exit(other.index, ball.entity.size()));
+                    }
+                }
+            }
+            Layer::Bumper => {
+                if let Some(bumpers) = &mut self.bumpers {
+                    bumpers.bounce(other.index, ball, &mut self.events);
+                }
+            }
+            // Balls go through each other
+            Layer::Ball => {}
+        }
+    }
+
+    fn collect_power_up(&mut self, kind: PowerUpKind, side: Side) {
+        self.events.push(GameEvent::PowerUpCollected { side, kind });
+
+        match kind {
+            PowerUpKind::Shield => {
+                // Picking up another shield just gives the side a fresh one
+                self.shields.retain(|shield| shield.side != side);
+                self.shields
+                    .push(Shield::new(self.resources.object, side, &self.court));
+            }
+            PowerUpKind::Split => {
+                // Only ever one copy at a time, getting another split starts it again
+                self.balls.truncate(1);
+                let split = self.balls[0].split(self.resources.object);
+                self.balls.push(split);
+            }
+            PowerUpKind::Curse => match side.opposite() {
+                Side::Left => self.left_controls.reverse(),
+                Side::Right => self.right_controls.reverse(),
+            },
+        }
+    }
+
+    /// Keeps the ball in front of the serving paddle until its controller serves it. The player
+    /// bounces it off their paddle while they wait, though it's always served from the paddle.
+    fn hold_serve(&mut self, server: Side, frame: &mut Frame) {
+        let (paddle, controller, away) = match server {
+            Side::Left => (&self.left_paddle, &mut self.left_controller, 1),
+            Side::Right => (&self.right_paddle, &mut self.right_controller, -1),
+        };
+
+        let ball = &mut self.balls[0];
+        let rect = paddle.rect();
+        let size = ball.entity.size();
+        let x = match server {
+            Side::Left => rect.position.x + rect.size.x,
+            Side::Right => rect.position.x - size.x,
+        };
+        let y = paddle.centre_y() - size.y / 2;
+        ball.hold((x, y).into());
+
+        if let Some(velocity) = controller.serve(frame.input) {
+            ball.launch((velocity.x * away, velocity.y).into());
+            self.server = None;
+            self.serve_preview.hide();
+        } else if let Some(aim) = controller.aim() {
+            self.serve_preview
+                .update(ball, (aim.x * away, aim.y).into(), &self.court);
+            if !frame.options.reduced_motion {
+                let height = self.warmup.update();
+                ball.hold((x + height * away, y).into());
+            }
+        }
+    }
+
+    /// Adds up the goals scored this frame as `(ball index, scorer)`. The real ball is worth
+    /// double while it has been split, a copy going in is worth a single point and it vanishes.
+    fn score_goals(&mut self, goals: &[(usize, Side)], frame: &mut Frame) {
+        let split = self.balls.len() > 1;
+        let score_before = self.score;
+        let mut real_ball_scorer = None;
+        let mut goal_position = (0, 0).into();
+
+        // Going backwards keeps the indices of the rest right while copies are removed
+        for &(index, scorer) in goals.iter().rev() {
+            let ball = &self.balls[index];
+            let mut points = match ball.kind {
+                BallKind::Real if split => 2,
+                _ => 1,
+            };
+            if scorer == Side::Left && frame.options.double_points {
+                points *= 2;
+            }
+
+            self.score.add_points(scorer, points);
+            self.events.push(GameEvent::Goal {
+                scorer,
+                points,
+                position: ball.entity.position.floor(),
+                ace: ball.last_hit_by.is_none(),
+            });
+            goal_position = ball.entity.position.floor();
+
+            match ball.kind {
+                BallKind::Real => real_ball_scorer = Some(scorer),
+                BallKind::Split { .. } => {
+                    self.balls.remove(index);
+                }
+            }
+        }
+
+        if self.modifiers.shrinking_court
+            && self
+                .court
+                .shrink_for_points(self.score.left + self.score.right)
+        {
+            background::draw_walls(&mut self.court_background, frame.vram, &self.court);
+        }
+        if self.modifiers.shrinking_paddles {
+            self.shrink_leader(score_before);
+        }
+        if self.score.winner().is_none() {
+            for (side, before, now) in [
+                (Side::Left, score_before.left, self.score.left),
+                (Side::Right, score_before.right, self.score.right),
+            ] {
+                if before < POINTS_TO_WIN - 1 && now >= POINTS_TO_WIN - 1 {
+                    self.events.push(GameEvent::MatchPoint { side });
+                }
+            }
+        }
+
+        // Whoever let the point in gets to serve the next one, once the freeze is over
+        self.phase = Phase::Frozen(PhotoFinish::new(
+            goal_position,
+            real_ball_scorer.map(Side::opposite),
+        ));
+    }
+
+    /// Takes a segment off the leader's paddle for each of the shrink scores they've just reached
+    fn shrink_leader(&mut self, score_before: Score) {
+        let Some(leader) = self.score.leader() else {
+            return;
+        };
+        let (before, now, paddle) = match leader {
+            Side::Left => (score_before.left, self.score.left, &mut self.left_paddle),
+            Side::Right => (score_before.right, self.score.right, &mut self.right_paddle),
+        };
+        for &score in &PADDLE_SHRINK_SCORES {
+            if before < score && score <= now {
+                paddle.shrink();
+            }
+        }
+    }
+
+    /// Carries on after the photo finish, replaying the winning point if that was the last one
+    /// or handing the ball to `server` if the point is over
+    fn finish_point(&mut self, server: Option<Side>, frame: &mut Frame) {
+        if let Some(winner) = self.score.winner() {
+            // Only the real ball was recorded, so any copies go before the replay
+            self.balls.truncate(1);
+            self.phase = Phase::Replaying(Replay::new(
+                winner,
+                &mut self.recording,
+                self.resources.tiled,
+                frame.vram,
+                frame.options.language,
+            ));
+        } else if let Some(server) = server {
+            self.serve(server, frame.vram);
+        }
+    }
+
+    /// Starts the celebration once the replay of the winning point is over
+    fn celebrate(&mut self, winner: Side, vram: &mut VRamManager) {
+        self.events.push(GameEvent::MatchWon { winner });
+        let winning_paddle = match winner {
+            Side::Left => &self.left_paddle,
+            Side::Right => &self.right_paddle,
+        };
+        self.phase = Phase::Celebrating(Celebration::new(
+            winner,
+            self.score,
+            winning_paddle,
+            self.resources.tiled,
+            vram,
+        ));
+    }
+
+    /// Gets rid of any copies of the ball and hands the real one to `server`
+    fn serve(&mut self, server: Side, vram: &mut VRamManager) {
+        self.rally.reset(vram);
+        self.balls.truncate(1);
+        self.balls[0].serve();
+        self.trail.clear();
+        self.warmup.reset();
+        self.watchdog.reset();
+        self.recording.clear();
+        self.server = Some(server);
+    }
+
+    /// All the logic for this frame is done, so move every sprite to match its entity in
+    /// one pass. Nothing moves after this until the next frame so the sprites never lag.
+    pub fn update_sprites(&mut self) {
+        for ball in &mut self.balls {
+            match self.phase {
+                Phase::Playing | Phase::Frozen(_) | Phase::Replaying(_) => ball.update_sprite(),
+                // The winning point has gone in, so there's nothing to draw until the next match
+                Phase::Celebrating(_) => {
+                    ball.entity.sprite.hide();
+                }
+            }
+        }
+        if let (Phase::Replaying(_) | Phase::Celebrating(_), Some(hint)) =
+            (&self.phase, &mut self.hint)
+        {
+            hint.hide();
+        }
+        // The replay isn't recorded with the trail, so it goes once the point is over
+        match self.phase {
+            Phase::Playing | Phase::Frozen(_) => self.trail.update_sprites(),
+            Phase::Replaying(_) | Phase::Celebrating(_) => self.trail.hide(),
+        }
+        self.left_paddle
+            .set_sprite_offset((self.left_controls.wobble(), 0).into());
+        self.right_paddle
+            .set_sprite_offset((self.right_controls.wobble(), self.taunt.bob()).into());
+        self.right_paddle.set_flashing(self.taunt.is_flashing());
+        self.left_paddle.update_sprite_positions();
+        self.right_paddle.update_sprite_positions();
+        self.left_controls.update_sprites();
+        self.right_controls.update_sprites();
+        self.left_special.update_sprites();
+        self.right_special.update_sprites();
+        self.power_ups.update_sprites();
+        // Each score sits under its own meter, wherever the mirroring puts it
+        self.left_score
+            .show(self.score.left, |width| (view::x(4, width), 12).into());
+        self.right_score.show(self.score.right, |width| {
+            (view::x(agb::display::WIDTH - 4 - width, width), 12).into()
+        });
+        for shield in &mut self.shields {
+            shield.update_sprites();
+        }
+        if let Some(portals) = &mut self.portals {
+            portals.update_sprites();
+        }
+        if let Some(bumpers) = &mut self.bumpers {
+            bumpers.update_sprites();
+        }
+        if let Some(elevator) = &mut self.elevator {
+            elevator.update_sprites();
+        }
+        self.effects.update_sprites();
+        self.crowd.update_sprites();
+        if let Some(weather) = &mut self.weather {
+            weather.update_sprites();
+        }
+    }
+
+    /// Committing the court puts its scroll back to normal in case the last transfer left it offset
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        self.effects.update();
+        self.daylight.after_vblank(vram);
+        self.court_background.commit(vram);
+    }
+
+    pub fn start_transfer<'t>(
+        &self,
+        dma: &'t Dma,
+        offsets: &'t mut ScanlineOffsets,
+    ) -> Option<DmaTransferHandle<'t, i16>> {
+        self.effects
+            .shimmer
+            .start_transfer(dma, &self.court_background, offsets)
+    }
+
+    pub fn leave(&mut self, frame: &mut Frame) {
+        match &mut self.phase {
+            Phase::Playing => {}
+            Phase::Frozen(_) => frame.blending.clear_flash(),
+            Phase::Replaying(replay) => replay.leave(frame.vram),
+            Phase::Celebrating(celebration) => celebration.leave(frame.vram),
+        }
+        #[cfg(feature = "debug-tools")]
+        if let Some(console) = &mut self.console {
+            console.leave(frame.vram);
+        }
+        self.rally.leave(frame.vram);
+        frame.blending.clear_translucent();
+        Fog::clear(frame.windows);
+        self.court_background.clear(frame.vram);
+        self.net.clear(frame.vram);
+        Daylight::reset(frame.vram);
+        view::set_mirrored(false);
+        #[cfg(feature = "bonus-modes")]
+        if let Some(tweaks) = self.tweaks_before {
+            tweaks::set(tweaks);
+        }
+    }
+}
+
+/// What came of the balls touching things this frame
+#[derive(Default)]
+struct Contacts {
+    collected: Option<(PowerUpKind, Side)>,
+    broken_shields: ArrayVec<usize, MAX_COLLIDERS>,
+    /// The balls a shield sent back, which can't score this frame
+    shielded_balls: ArrayVec<usize, MAX_BALLS>,
+}
+
+/// The ball the computer should be following. That's whichever is heading its way closest
+/// to it, or the real ball if none of them are coming.
+fn ball_for_ai<'a, 'b>(balls: &'b [Ball<'a>]) -> &'b Ball<'a> {
+    balls
+        .iter()
+        .filter(|ball| ball.entity.velocity.x > 0.into())
+        .max_by_key(|ball| ball.entity.position.x)
+        .unwrap_or(&balls[0])
+}
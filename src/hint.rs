@@ -0,0 +1,48 @@
+//! The coaching hint. A faint ring on the player's side of the court showing where the ball is
+//! going to arrive, so new players can learn where to be.
+
+use agb::display::object::{OamManaged, Object};
+
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::paddle::Paddle;
+use crate::trajectory;
+use crate::tuning::HINT_LOOKAHEAD_FRAMES;
+use crate::view;
+
+pub struct Hint<'a> {
+    marker: Object<'a>,
+}
+
+impl<'a> Hint<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        let mut marker = object.object_sprite(SPRITES.hint.sprite(0));
+        RenderLayer::Decor.apply(&mut marker).hide();
+        Self { marker }
+    }
+
+    /// Moves the marker to where `ball` will reach the front of `paddle`, hiding it while the
+    /// ball is going the other way
+    pub fn update(&mut self, ball: &Ball, paddle: &Paddle, court: &Court) {
+        let rect = paddle.rect();
+        let plane_x = rect.position.x + rect.size.x;
+
+        match trajectory::crossing_left(&ball.entity, court, plane_x, HINT_LOOKAHEAD_FRAMES) {
+            Some(y) => {
+                let position = (plane_x.floor(), y.floor()).into();
+                self.marker
+                    .set_position(view::sprite_position(position, SPRITES.hint.sprite(0)))
+                    .show();
+            }
+            None => {
+                self.marker.hide();
+            }
+        }
+    }
+
+    pub fn hide(&mut self) {
+        self.marker.hide();
+    }
+}
@@ -0,0 +1,108 @@
+//! A toy hidden in the title screen. A ball bounces around behind the name, left and right push
+//! it sideways and up kicks it back into the air while it's falling low down. Keeping it off the
+//! floor for long enough shows a message, though nothing says there's anything to find.
+
+use agb::display::object::OamManaged;
+use agb::display::tiled::{Tiled0, VRamManager};
+use agb::fixnum::{Rect, Vector2D};
+use agb::input::{Button, ButtonController};
+
+use crate::assets::SPRITES;
+use crate::collision::Layer;
+use crate::court::Court;
+use crate::entity::Entity;
+use crate::layering::RenderLayer;
+use crate::physics;
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+use crate::tuning::{
+    Frames, BALL_COLLISION_MASK, JUGGLE_GRAVITY, JUGGLE_KICK_SPEED, JUGGLE_MAX_SIDEWAYS_SPEED,
+    JUGGLE_MILESTONES, JUGGLE_NUDGE,
+};
+
+pub struct Juggle<'a> {
+    ball: Entity<'a>,
+    /// Where the ball can go, a little way in from the edges of the screen
+    court: Court,
+    airborne_frames: Frames,
+    /// How many of the milestones have been reached since the title screen came up
+    reached: usize,
+    message: Label<'a>,
+}
+
+impl<'a> Juggle<'a> {
+    pub fn new(object: &'a OamManaged<'a>, tiled: &'a Tiled0, vram: &mut VRamManager) -> Self {
+        let size: Vector2D<i32> = (agb::display::WIDTH - 16, agb::display::HEIGHT - 16).into();
+        let court = Court::new(Rect::new((8, 8).into(), size));
+
+        // Dropped in from the top left, drifting across
+        let mut ball = Entity::new(
+            object,
+            SPRITES.ball.sprite(0),
+            BALL_COLLISION_MASK.into(),
+            Layer::Ball,
+            RenderLayer::Ball,
+            (court.left() + 16, court.top()).into(),
+            (1, 0).into(),
+        );
+        ball.sprite.show();
+
+        Self {
+            ball,
+            court,
+            airborne_frames: Frames::ZERO,
+            reached: 0,
+            message: Label::new(tiled, vram, (9, 17)),
+        }
+    }
+
+    pub fn update(&mut self, input: &ButtonController, vram: &mut VRamManager, language: Language) {
+        let ball = &mut self.ball;
+        ball.velocity.y += JUGGLE_GRAVITY;
+        ball.velocity.x = (ball.velocity.x + JUGGLE_NUDGE * input.x_tri() as i32)
+            .clamp(-JUGGLE_MAX_SIDEWAYS_SPEED.0, JUGGLE_MAX_SIDEWAYS_SPEED.0);
+
+        let low_down = ball.position.y > (self.court.top() + self.court.bottom()) / 2;
+        if input.is_just_pressed(Button::UP) && low_down && ball.velocity.y > 0.into() {
+            ball.velocity.y = -JUGGLE_KICK_SPEED.0;
+        }
+
+        let position = physics::integrate(ball.position, ball.velocity, Frames(1));
+        ball.position = physics::clamp_to_court(position, &self.court, ball.size());
+
+        let size = ball.size();
+        let at_side =
+            ball.position.x == self.court.left() || ball.position.x == self.court.right() - size.x;
+        if at_side {
+            ball.velocity = physics::reflect(ball.velocity, (1, 0).into());
+        }
+        let at_top = ball.position.y == self.court.top();
+        let at_floor = ball.position.y == self.court.bottom() - size.y;
+        if at_top || at_floor {
+            ball.velocity = physics::reflect(ball.velocity, (0, 1).into());
+        }
+
+        if at_floor {
+            self.airborne_frames = Frames::ZERO;
+            return;
+        }
+
+        self.airborne_frames.tick();
+        if let Some(&seconds) = JUGGLE_MILESTONES.get(self.reached) {
+            if self.airborne_frames >= Frames(seconds * 60) {
+                self.reached += 1;
+                let message = Text::KeptItUp.get(language);
+                self.message
+                    .write(vram, text::HIGHLIGHT, format_args!("{message} {seconds}s"));
+            }
+        }
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.ball.update_sprite_position();
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.message.clear(vram);
+    }
+}
@@ -0,0 +1,43 @@
+//! Which layer each kind of sprite is drawn on. A layer picks both the priority, so what it's in
+//! front of among the backgrounds, and where it goes in OAM, so what it's in front of among the
+//! other sprites on the same priority. Sprites should always be put on a layer here rather than
+//! given a priority of their own, so nothing new ends up drawn over the HUD by accident.
+//!
+//! OAM is filled in layer order, so if there are ever too many sprites it's the decor that goes
+//! missing rather than the ball.
+
+use agb::display::object::Object;
+use agb::display::Priority;
+
+/// From the front to the back
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderLayer {
+    /// Scores, meters and text made of sprites, in front of everything else
+    Hud,
+    Ball,
+    Paddles,
+    /// Power-ups, and the shields, walls, portals and bumpers that get put on the court
+    PowerUps,
+    /// Sparks, trails and the serve preview
+    Particles,
+    /// The crowd, the weather and the coaching hint, behind the net and the court lines
+    Decor,
+}
+
+impl RenderLayer {
+    fn priority(self) -> Priority {
+        match self {
+            RenderLayer::Hud => Priority::P0,
+            RenderLayer::Ball
+            | RenderLayer::Paddles
+            | RenderLayer::PowerUps
+            | RenderLayer::Particles => Priority::P1,
+            RenderLayer::Decor => Priority::P2,
+        }
+    }
+
+    /// Puts `object` on this layer
+    pub fn apply<'o, 'a>(self, object: &'o mut Object<'a>) -> &'o mut Object<'a> {
+        object.set_priority(self.priority()).set_z(self as i32)
+    }
+}
@@ -0,0 +1,111 @@
+//! The records kept on the cartridge, B on the title screen gets here. One board is shown at a
+//! time, left and right flip between them and B goes back.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::records::{Board, Leaderboard};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+
+/// The entries on a board, one a line with the score and the date each was set
+struct Entries<'b> {
+    board: Board,
+    leaderboard: &'b Leaderboard,
+    language: Language,
+}
+
+impl Display for Entries<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries = self.leaderboard.entries().peekable();
+        if entries.peek().is_none() {
+            return f.write_str(Text::NoRecords.get(self.language));
+        }
+
+        for (place, entry) in entries.enumerate() {
+            write!(f, "{}.  ", place + 1)?;
+            match self.board {
+                Board::LongestRally => write!(f, "{}  ", entry.value)?,
+                Board::QuickestWin => write!(f, "{}:{:02}  ", entry.value / 60, entry.value % 60)?,
+                Board::DailyChallenge => {}
+            }
+            write!(f, "{} - {}", entry.score.left, entry.score.right)?;
+            if let Some(date) = entry.date {
+                write!(f, "  {:02}/{:02}/{:02}", date.day, date.month, date.year)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Leaderboards<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    entries: Label<'a>,
+    /// Which of `Board::SHOWN` is showing
+    board: usize,
+}
+
+impl<'a> Leaderboards<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut leaderboards = Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            heading: Label::new(resources.tiled, frame.vram, (2, 1)),
+            entries: Label::new(resources.tiled, frame.vram, (3, 5)),
+            board: 0,
+        };
+        leaderboards.show(frame);
+        leaderboards
+    }
+
+    fn show(&mut self, frame: &mut Frame) {
+        let language = frame.options.language;
+        let board = Board::SHOWN[self.board];
+        let records = Text::Records.get(language);
+        let name = board.name().get(language);
+        self.heading.write(
+            frame.vram,
+            text::HIGHLIGHT,
+            format_args!("{records}: < {name} >"),
+        );
+
+        let entries = Entries {
+            board,
+            leaderboard: frame.save.records.board(board),
+            language,
+        };
+        self.entries
+            .write(frame.vram, text::TEXT, format_args!("{entries}"));
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Title);
+        }
+
+        let board_change = if frame.input.is_just_pressed(Button::LEFT) {
+            Board::SHOWN.len() - 1
+        } else if frame.input.is_just_pressed(Button::RIGHT) {
+            1
+        } else {
+            0
+        };
+        if board_change != 0 {
+            self.board = (self.board + board_change) % Board::SHOWN.len();
+            self.show(frame);
+        }
+
+        None
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.entries.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
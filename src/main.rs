@@ -9,24 +9,124 @@
 // using the #[agb::entry] proc macro. Failing to do so will cause failure in linking
 // which won't be a particularly clear error message.
 #![no_main]
+// Lets collections belonging to a match be given their own allocator
+#![feature(allocator_api)]
 // This is required to allow writing tests
 #![cfg_attr(test, feature(custom_test_frameworks))]
 #![cfg_attr(test, reexport_test_harness_main = "test_main")]
 #![cfg_attr(test, test_runner(agb::test_runner::test_runner))]
 
-use agb::display::object::{OamManaged, Object};
-use agb::display::Priority;
-use agb::fixnum::Vector2D;
-use agb::{
-    display::object::{Graphics, Tag},
-    include_aseprite,
-};
-
-const GRAPHICS: &Graphics = include_aseprite!("gfx/sprites.aseprite");
-
-const PADDLE_END: &Tag = GRAPHICS.tags().get("Paddle End");
-const PADDLE_MID: &Tag = GRAPHICS.tags().get("Paddle Mid");
-const BALL: &Tag = GRAPHICS.tags().get("Ball");
+extern crate alloc;
+
+use agb::input::{Button, ButtonController};
+use agb::interrupt::VBlank;
+use audio::Audio;
+use blending::Blending;
+#[cfg(feature = "debug-tools")]
+use frametime::FrameTime;
+use save::Save;
+use scene::{Frame, Next, Resources, Scene};
+use session::Session;
+use shimmer::{ScanlineOffsets, SCANLINES};
+use sleep::Idle;
+
+mod ai;
+mod animation;
+mod announcer;
+mod arena;
+mod arrayvec;
+mod assets;
+mod audio;
+mod background;
+mod ball;
+mod blending;
+mod bumper;
+mod celebration;
+#[cfg(feature = "bonus-modes")]
+mod challenge;
+mod character;
+mod collision;
+mod colour;
+#[cfg(feature = "debug-tools")]
+mod console;
+mod controller;
+mod controls;
+mod court;
+mod credits;
+mod crowd;
+mod daylight;
+mod digits;
+mod effects;
+mod elevator;
+mod entity;
+mod events;
+#[cfg(feature = "bonus-modes")]
+mod extras;
+mod fog;
+#[cfg(feature = "debug-tools")]
+mod frametime;
+#[cfg(feature = "bonus-modes")]
+mod gallery;
+mod game;
+mod hint;
+mod juggle;
+mod layering;
+mod leaderboards;
+mod marquee;
+mod modifiers;
+mod music;
+mod naming;
+#[cfg(feature = "debug-tools")]
+mod oam;
+mod options;
+mod paddle;
+mod particles;
+mod password;
+mod photo;
+mod physics;
+mod pool;
+mod portal;
+mod powerup;
+mod preview;
+mod profile;
+mod rally;
+mod random;
+mod records;
+mod replay;
+mod results;
+mod rom;
+mod rtc;
+mod save;
+mod scene;
+mod score;
+mod scroller;
+mod select;
+mod session;
+mod shield;
+mod shimmer;
+mod sleep;
+mod sounds;
+mod special;
+mod splash;
+mod stats;
+mod strings;
+mod taunt;
+mod text;
+mod title;
+#[cfg(feature = "debug-tools")]
+mod trace;
+mod trail;
+mod trajectory;
+mod tuning;
+mod tutorial;
+mod tweaks;
+#[cfg(feature = "bonus-modes")]
+mod unlocks;
+mod versus;
+mod view;
+mod warmup;
+mod watchdog;
+mod weather;
 
 // The main function must take 1 arguments and never return. The agb::entry decorator
 // ensures that everything is in order. `agb` will call this after setting up the stack
@@ -36,245 +136,132 @@ fn main(mut gba: agb::Gba) -> ! {
     // Get the object manager
     let object = gba.display.object.get_managed();
 
-    let mut input = agb::input::ButtonController::new();
-
-    let mut ball: Ball = Ball::new(&object);
-    let mut right_paddle = Paddle::new(&object, Side::Right);
-    let mut left_paddle: Paddle = Paddle::new(&object, Side::Left);
-
-    loop {
-        // This will calculate the new position and enforce the position
-        // of the entities remains within the screen
-        ball.checks_and_keeps_in_bounds();
-        left_paddle.checks_and_keeps_in_bounds();
-        right_paddle.checks_and_keeps_in_bounds();
-
-        // We check if the ball reaches the edge of the screen and reverse it's direction
-        ball.bounce_if_hits_screen_bounds();
-
-        //Simple collision detection that is quite faulty at times, but it works for learning
-        left_paddle.checks_all_collisions(&mut ball);
-        right_paddle.checks_all_collisions(&mut ball);
-
-        //Updates sprites with input
-
-        // Set the position of the ball to match our new calculated position
-        ball.entity.update_sprite_position();
-
-        left_paddle.move_paddle_with_input(input.y_tri() as i32);
-        // right_paddle.move_paddle_with_input(input.y_tri() as i32);
-        right_paddle.update_ai_paddle(&ball.entity, 1);
-
-        // Wait for vblank, then commit the objects to the screen
-        agb::display::busy_wait_for_vblank();
-        object.commit();
-
-        input.update()
-    }
-
-    /// Ball struct that holds the sprite of the ball
-    pub struct Ball<'a> {
-        entity: Entity<'a>,
-    }
-
-    /// Impl of ball to allow for methods to interact with the sprite
-    impl<'a> Ball<'a> {
-        pub fn new(object: &'a OamManaged) -> Self {
-            let mut ball: Entity = Entity::new(&object, (16_u16, 16_u16).into());
-            ball.sprite.set_sprite(object.sprite(BALL.sprite(0)));
-            ball.velocity.x = 1;
-            ball.velocity.y = 1;
-            ball.set_spawn((50, 50).into());
-            ball.sprite.show();
-            Self { entity: ball }
-        }
-
-        /// Keeps the ball within the bounds of the screen not allowing it to move pass the limit
-        pub fn checks_and_keeps_in_bounds(&mut self) {
-            self.entity.position.x = (self.entity.position.x + self.entity.velocity.x)
-                .clamp(0, agb::display::WIDTH - 16);
-            self.entity.position.y = (self.entity.position.y + self.entity.velocity.y)
-                .clamp(0, agb::display::HEIGHT - 16);
-        }
-
-        /// Bounces the ball if it hits the edge of the screen
-        pub fn bounce_if_hits_screen_bounds(&mut self) {
-            if self.entity.position.x == 0 || self.entity.position.x == agb::display::WIDTH - 16 {
-                self.entity.velocity.x = -self.entity.velocity.x;
-            }
-
-            if self.entity.position.y == 0 || self.entity.position.y == agb::display::HEIGHT - 16 {
-                self.entity.velocity.y = -self.entity.velocity.y;
-            }
-        }
-    }
-
-    /// Which side of the screen the sprint is on
-    pub enum Side {
-        Left,
-        Right,
-    }
-
-    /// A simple entity struct that holds the sprite and position for a paddle object
-    pub struct Paddle<'a> {
-        top: Entity<'a>,
-        middle: Entity<'a>,
-        bottom: Entity<'a>,
-        velocity: Vector2D<i32>,
+    let (tiled, mut vram) = gba.display.video.tiled0();
+    background::set_palettes(&mut vram);
+
+    let mut blending = Blending::new(gba.display.blend.get());
+    let mut windows = gba.display.window.get();
+    let mut save = Save::new(&mut gba.save, gba.timers.timers().timer3);
+    let mut options = save.profiles.current().options;
+    let mut session = Session::default();
+    let dma = gba.dma.dma();
+    let mut shimmer_offsets: ScanlineOffsets = [0; SCANLINES];
+    // Kept alive until the next vblank, dropping it stops the transfer
+    let mut _shimmer_transfer = None;
+
+    let mut input = ButtonController::new();
+    // Halts until the vblank interrupt rather than spinning, which saves the batteries
+    let vblank = VBlank::get();
+    let mut idle = Idle::default();
+    #[cfg(feature = "debug-tools")]
+    let mut oam_audit = oam::Audit::default();
+    #[cfg(feature = "debug-tools")]
+    let mut frame_time = FrameTime::default();
+    let mut audio = Audio::new(&mut gba.mixer);
+    #[cfg(feature = "debug-tools")]
+    {
+        rom::log_usage();
+        sounds::log_usage();
     }
 
-    /// Impl of paddle to allow for methods to interact with the sprite and setup
-    /// The paddle is made up of 3 sprites, top, middle and bottom.
-    impl<'a> Paddle<'a> {
-        pub fn new(object: &'a OamManaged, which_side: Side) -> Self {
-            let x_pos_of_paddle = match which_side {
-                Side::Left => 1,
-                Side::Right => 224,
-            };
+    let resources = Resources {
+        object: &object,
+        tiled: &tiled,
+    };
+    let mut scene = Scene::enter(
+        Next::Splash,
+        resources,
+        &mut Frame {
+            input: &input,
+            vram: &mut vram,
+            audio: &mut audio,
+            blending: &mut blending,
+            windows: &mut windows,
+            options: &mut options,
+            save: &mut save,
+            session: &mut session,
+        },
+    );
 
-            let paddle_collision_mask: Vector2D<u16> = (14_u16, 14_u16).into();
-
-            let mut paddle_middle: Entity = Entity::new(&object, paddle_collision_mask);
-            paddle_middle
-                .sprite
-                .set_sprite(object.sprite(PADDLE_MID.sprite(0)));
-            paddle_middle.velocity.y = 3;
-
-            paddle_middle.set_spawn((x_pos_of_paddle, 50).into());
-            paddle_middle.sprite.show();
-
-            let mut paddle_top: Entity = Entity::new(&object, paddle_collision_mask);
-            paddle_top
-                .sprite
-                .set_sprite(object.sprite(PADDLE_END.sprite(0)));
-            paddle_top.velocity.y = 3;
-            paddle_top.set_spawn((x_pos_of_paddle, 34).into());
-            paddle_top.sprite.show();
-
-            let mut paddle_bottom: Entity = Entity::new(&object, paddle_collision_mask);
-            paddle_bottom
-                .sprite
-                .set_sprite(object.sprite(PADDLE_END.sprite(0)));
-            paddle_bottom.velocity.y = 3;
-            paddle_bottom.sprite.set_vflip(true);
-            paddle_bottom.set_spawn((x_pos_of_paddle, 66).into());
-            paddle_bottom.sprite.show();
-
-            if matches!(which_side, Side::Right) {
-                paddle_top.sprite.set_hflip(true);
-                paddle_middle.sprite.set_hflip(true);
-                paddle_bottom.sprite.set_hflip(true);
-            }
-
-            Paddle {
-                top: paddle_top,
-                middle: paddle_middle,
-                bottom: paddle_bottom,
-                velocity: (0, 0).into(),
-            }
-        }
-
-        /// Checks to make sure the paddle is within the bounds of the screen
-        pub fn checks_and_keeps_in_bounds(&mut self) {
-            self.top.position.y =
-                (self.top.position.y + self.top.velocity.y).clamp(0, agb::display::HEIGHT - 48);
-            self.middle.position.y = (self.middle.position.y + self.middle.velocity.y)
-                .clamp(16, agb::display::HEIGHT - 32);
-            self.bottom.position.y = (self.bottom.position.y + self.bottom.velocity.y)
-                .clamp(32, agb::display::HEIGHT - 16);
-        }
-
-        /// Moves the paddle based on the input of the y axis of the dpad
-        pub fn move_paddle_with_input(&mut self, y_input: i32) {
-            self.top.velocity.y = y_input;
-            self.middle.velocity.y = y_input;
-            self.bottom.velocity.y = y_input;
-
-            self.top.update_sprite_position();
-            self.middle.update_sprite_position();
-            self.bottom.update_sprite_position();
-        }
-
-        /// Checks if any of the three sprites has collided with the ball and bounces it back
-        pub fn checks_all_collisions(&mut self, ball: &mut Ball) {
-            if intersects(&ball.entity, &self.top) {
-                ball.entity.velocity.x = -ball.entity.velocity.x;
-                return;
-            }
-
-            if intersects(&ball.entity, &self.middle) {
-                ball.entity.velocity.x = -ball.entity.velocity.x;
-                return;
-            }
-
-            if intersects(&ball.entity, &self.bottom) {
-                ball.entity.velocity.x = -ball.entity.velocity.x;
-                return;
-            }
+    loop {
+        if idle.wants_sleep(scene.can_sleep()) {
+            idle.sleep();
+            // Whatever woke the console up is already held, so it doesn't count as a press
+            input.update();
         }
 
-        // This function will make the AI paddle move towards the ball.
-        pub fn update_ai_paddle(&mut self, ball: &Entity, speed: i32) {
-            if ball.position.y < self.middle.position.y {
-                self.velocity.y = -speed;
-            } else if ball.position.y > self.middle.position.y {
-                self.velocity.y = speed;
-            } else {
-                self.velocity.y = 0;
-            }
-
-            self.move_paddle_with_input(self.velocity.y);
+        let mut frame = Frame {
+            input: &input,
+            vram: &mut vram,
+            audio: &mut audio,
+            blending: &mut blending,
+            windows: &mut windows,
+            options: &mut options,
+            save: &mut save,
+            session: &mut session,
+        };
+
+        let next = if wants_soft_reset(&input) {
+            // Back to the start as if the console had just been switched on
+            frame.audio.stop();
+            *frame.options = frame.save.profiles.current().options;
+            *frame.session = Session::default();
+            Some(Next::Splash)
+        } else {
+            scene.update(&mut frame)
+        };
+        if let Some(next) = next {
+            // The old scene has to be gone before the next one is made so its backgrounds and
+            // sprites are free to be used again
+            scene.leave(&mut frame);
+            drop(scene);
+            arena::reset();
+            scene = Scene::enter(next, resources, &mut frame);
+            idle = Idle::default();
         }
-    }
 
-    /// A simple entity struct that holds the sprite and position for any sprite
-    pub struct Entity<'a> {
-        sprite: Object<'a>,
-        position: Vector2D<i32>,
-        velocity: Vector2D<i32>,
-        collision_mask: Vector2D<u16>,
-    }
-
-    /// impl of entity to allow for methods to interact with the sprite and setup
-    impl<'a> Entity<'a> {
-        pub fn new(object: &'a OamManaged, collision_mask: Vector2D<u16>) -> Self {
-            let mut dummy_object = object.object_sprite(BALL.sprite(0));
+        scene.update_sprites();
 
-            dummy_object.set_priority(Priority::P1);
-            Entity {
-                sprite: dummy_object,
-                collision_mask,
-                position: (0, 0).into(),
-                velocity: (12_u16, 48_u16).into(),
-            }
+        // A menu that's been left alone skips some frames, the mixer still runs in each of them
+        // so the sound doesn't skip as well
+        let frames = idle.frames_per_update(scene.is_menu());
+        #[cfg(feature = "debug-tools")]
+        frame_time.finish();
+        for _ in 0..frames.0 {
+            wait_for_frame(&vblank, &mut audio);
         }
 
-        /// Updates the position of the sprite based on what has been set in the position variable
-        fn update_sprite_position(&mut self) {
-            self.sprite
-                .set_x(self.position.x as u16)
-                .set_y(self.position.y as u16);
-        }
-
-        /// Set where the entity should spawn the sprite
-        fn set_spawn(&mut self, spawn: Vector2D<i32>) {
-            self.position = spawn;
-            self.sprite
-                .set_x(self.position.x as u16)
-                .set_y(self.position.y as u16);
-        }
+        // Now it's vblank, commit the objects to the screen
+        object.commit();
+        #[cfg(feature = "debug-tools")]
+        frame_time.start();
+        #[cfg(feature = "debug-tools")]
+        oam_audit.check();
+        blending.commit();
+        windows.commit();
+
+        // Restart any scanline effects now that the new frame has started
+        _shimmer_transfer = None;
+        scene.after_vblank(&mut vram);
+        _shimmer_transfer = scene.start_transfer(&dma.dma0, &mut shimmer_offsets);
+
+        input.update();
+        idle.update(&input, frames);
     }
+}
 
-    /// Checks if two entities have collided with each other
-    fn intersects(e1: &Entity, e2: &Entity) -> bool {
-        let e1_right = e1.position.x + e1.collision_mask.x as i32;
-        let e1_bottom = e1.position.y + e1.collision_mask.y as i32;
-        let e2_right = e2.position.x + e2.collision_mask.x as i32;
-        let e2_bottom = e2.position.y + e2.collision_mask.y as i32;
+/// Mixes the sound for the frame that's going and waits for vblank. Every frame goes through
+/// here, whatever the scene is doing, so the mixer is run exactly once a frame. Nothing else
+/// should wait for vblank or call `Audio::frame`.
+fn wait_for_frame(vblank: &VBlank, audio: &mut Audio) {
+    audio.frame();
+    vblank.wait_for_vblank();
+}
 
-        e1.position.x < e2_right
-            && e1_right > e2.position.x
-            && e1.position.y < e2_bottom
-            && e1_bottom > e2.position.y
-    }
+/// A, B, start and select all held together restarts the game from wherever it is. Only the
+/// press that completes the combo counts, so holding it doesn't keep on restarting.
+fn wants_soft_reset(input: &ButtonController) -> bool {
+    let combo = [Button::A, Button::B, Button::START, Button::SELECT];
+    combo.iter().all(|&button| input.is_pressed(button))
+        && combo.iter().any(|&button| input.is_just_pressed(button))
 }
@@ -0,0 +1,119 @@
+//! Words drawn with a sprite for each letter, so every letter can move about on its own. The
+//! letters can bounce in a wave, cycle through colours and type themselves out one at a time.
+
+use agb::display::object::{OamManaged, Object};
+use agb::fixnum::Vector2D;
+use alloc::vec::Vec;
+
+use crate::animation::Timeline;
+use crate::arena::MatchArena;
+use crate::assets::SPRITES;
+use crate::layering::RenderLayer;
+use crate::tuning::{
+    Fixed, MARQUEE_BOUNCE_FRAMES, MARQUEE_BOUNCE_HEIGHT, MARQUEE_COLOUR_FRAMES, MARQUEE_TYPE_FRAMES,
+};
+
+/// The characters there are sprites for, in the order they are in the letters sheet. The sheet
+/// has every one of them again in each colour.
+const GLYPHS: [char; 28] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '!', 'Ü',
+];
+const COLOURS: usize = 3;
+
+/// How far along each letter is drawn from the one before, and the gap left for a space
+const LETTER_WIDTH: i32 = 6;
+const SPACE_WIDTH: i32 = 4;
+
+/// Which effects the text is drawn with, they can be mixed however
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextEffects {
+    /// The letters bob up and down in a wave
+    pub bounce: bool,
+    /// The letters change colour one after another
+    pub colour_cycle: bool,
+    /// The letters appear one at a time
+    pub typewriter: bool,
+}
+
+struct Letter<'a> {
+    sprite: Object<'a>,
+    glyph: usize,
+    /// Where the letter sits along the line
+    x: i32,
+}
+
+pub struct Marquee<'a> {
+    object: &'a OamManaged<'a>,
+    letters: Vec<Letter<'a>, MatchArena>,
+    /// The top left of the line of text
+    position: Vector2D<i32>,
+    effects: TextEffects,
+    timeline: Timeline,
+}
+
+impl<'a> Marquee<'a> {
+    /// Lays `text` out in the middle of the screen with its top at `y`. It's drawn in capitals,
+    /// anything there isn't a sprite for leaves a gap like a space.
+    pub fn new(object: &'a OamManaged<'a>, text: &str, y: i32, effects: TextEffects) -> Self {
+        let mut letters = Vec::new_in(MatchArena);
+        let mut x = 0;
+        for character in text.chars() {
+            let capital = character.to_uppercase().next().unwrap_or(character);
+            let Some(glyph) = GLYPHS.iter().position(|&glyph| glyph == capital) else {
+                x += SPACE_WIDTH;
+                continue;
+            };
+
+            let mut sprite = object.object_sprite(SPRITES.letters.sprite(glyph));
+            RenderLayer::Hud.apply(&mut sprite).hide();
+            letters.push(Letter { sprite, glyph, x });
+            x += LETTER_WIDTH;
+        }
+
+        let mut marquee = Self {
+            object,
+            letters,
+            position: ((agb::display::WIDTH - x) / 2, y).into(),
+            effects,
+            timeline: Timeline::default(),
+        };
+        marquee.update_sprites();
+        marquee
+    }
+
+    /// Moves the effects on a frame and draws every letter where it should be
+    pub fn update_sprites(&mut self) {
+        let frame = self.timeline.frame();
+        self.timeline.advance();
+
+        for (index, letter) in self.letters.iter_mut().enumerate() {
+            let index = index as u32;
+            if self.effects.typewriter && frame < index * MARQUEE_TYPE_FRAMES.0 {
+                letter.sprite.hide();
+                continue;
+            }
+
+            // Each letter is a little further round the wave than the one before it
+            let y = if self.effects.bounce {
+                let turn = Fixed::new((frame + index * 4) as i32) / MARQUEE_BOUNCE_FRAMES.0 as i32;
+                (turn.sin() * MARQUEE_BOUNCE_HEIGHT).floor()
+            } else {
+                0
+            };
+
+            let colour = if self.effects.colour_cycle {
+                (frame / MARQUEE_COLOUR_FRAMES.0 + index) as usize % COLOURS
+            } else {
+                0
+            };
+
+            let sprite = SPRITES.letters.sprite(colour * GLYPHS.len() + letter.glyph);
+            letter
+                .sprite
+                .set_sprite(self.object.sprite(sprite))
+                .set_position(self.position + (letter.x, y).into())
+                .show();
+        }
+    }
+}
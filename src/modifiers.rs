@@ -0,0 +1,82 @@
+//! Optional rules that change how a match plays, picked on the character select before it starts
+
+use crate::strings::Text;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modifier {
+    /// The opponent's half of the court is hidden
+    Fog,
+    /// The top and bottom walls close in as points are scored
+    ShrinkingCourt,
+    /// The ball speeds up in the player's half and slows down in the computer's
+    LopsidedCourt,
+    /// A ball going into one of a pair of portals comes out of the other
+    Portals,
+    /// Round bumpers in the middle of the court kick the ball away
+    Bumpers,
+    /// A paddle nobody plays rides up and down the middle of the court
+    Elevator,
+    /// Whoever is ahead loses a piece of their paddle as they reach certain scores
+    ShrinkingPaddles,
+}
+
+impl Modifier {
+    pub const ALL: [Modifier; 7] = [
+        Modifier::Fog,
+        Modifier::ShrinkingCourt,
+        Modifier::LopsidedCourt,
+        Modifier::Portals,
+        Modifier::Bumpers,
+        Modifier::Elevator,
+        Modifier::ShrinkingPaddles,
+    ];
+
+    pub fn name(self) -> Text {
+        match self {
+            Modifier::Fog => Text::Fog,
+            Modifier::ShrinkingCourt => Text::ShrinkingCourt,
+            Modifier::LopsidedCourt => Text::LopsidedCourt,
+            Modifier::Portals => Text::Portals,
+            Modifier::Bumpers => Text::Bumpers,
+            Modifier::Elevator => Text::Elevator,
+            Modifier::ShrinkingPaddles => Text::ShrinkingPaddles,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub fog: bool,
+    pub shrinking_court: bool,
+    pub lopsided_court: bool,
+    pub portals: bool,
+    pub bumpers: bool,
+    pub elevator: bool,
+    pub shrinking_paddles: bool,
+}
+
+impl Modifiers {
+    pub fn is_on(&self, modifier: Modifier) -> bool {
+        match modifier {
+            Modifier::Fog => self.fog,
+            Modifier::ShrinkingCourt => self.shrinking_court,
+            Modifier::LopsidedCourt => self.lopsided_court,
+            Modifier::Portals => self.portals,
+            Modifier::Bumpers => self.bumpers,
+            Modifier::Elevator => self.elevator,
+            Modifier::ShrinkingPaddles => self.shrinking_paddles,
+        }
+    }
+
+    pub fn toggle(&mut self, modifier: Modifier) {
+        match modifier {
+            Modifier::Fog => self.fog = !self.fog,
+            Modifier::ShrinkingCourt => self.shrinking_court = !self.shrinking_court,
+            Modifier::LopsidedCourt => self.lopsided_court = !self.lopsided_court,
+            Modifier::Portals => self.portals = !self.portals,
+            Modifier::Bumpers => self.bumpers = !self.bumpers,
+            Modifier::Elevator => self.elevator = !self.elevator,
+            Modifier::ShrinkingPaddles => self.shrinking_paddles = !self.shrinking_paddles,
+        }
+    }
+}
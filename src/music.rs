@@ -0,0 +1,127 @@
+//! The music behind each scene. Changing to a different piece fades the old one out while the
+//! new one fades in, so there are two channels of music going for the length of the crossfade.
+//! The volume from the options is set on the channels every frame, so changing it is heard
+//! straight away.
+
+use agb::fixnum::Num;
+use agb::sound::mixer::{ChannelId, Mixer, SoundChannel};
+
+use crate::sounds::{Sample, MUSIC_MATCH, MUSIC_TITLE, MUSIC_VICTORY};
+use crate::tuning::{Fixed, MUSIC_CROSSFADE_FRAMES, MUSIC_VOLUME};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Music {
+    /// The title screen and all the menus
+    Title,
+    Match,
+    /// Once the match has been won, through to the results
+    Victory,
+}
+
+impl Music {
+    fn sample(self) -> Sample {
+        match self {
+            Music::Title => MUSIC_TITLE,
+            Music::Match => MUSIC_MATCH,
+            Music::Victory => MUSIC_VICTORY,
+        }
+    }
+}
+
+/// A piece of music that's playing and how far it has faded in
+struct Track {
+    music: Music,
+    channel: ChannelId,
+    volume: Fixed,
+}
+
+impl Track {
+    fn start(music: Music, mixer: &mut Mixer) -> Option<Self> {
+        let sample = music.sample();
+        // High priority so the music is never dropped for lack of a free channel
+        let mut channel = SoundChannel::new_high_priority(sample.data);
+        channel
+            .should_loop()
+            .playback(Num::from_raw(sample.speed().to_raw() as u32))
+            .volume(0);
+        let channel = mixer.play_sound(channel)?;
+        Some(Self {
+            music,
+            channel,
+            volume: 0.into(),
+        })
+    }
+
+    /// Moves the volume on by `step` towards `loudest`, returning false once it has faded right
+    /// out
+    fn fade(&mut self, step: Fixed, loudest: Fixed, mixer: &mut Mixer) -> bool {
+        self.volume = (self.volume + step).clamp(0.into(), 1.into());
+        let Some(channel) = mixer.channel(&self.channel) else {
+            return false;
+        };
+        let volume = self.volume * loudest;
+        channel.volume(Num::from_raw(volume.to_raw() as i16));
+        if self.volume == 0.into() {
+            channel.stop();
+            return false;
+        }
+        true
+    }
+}
+
+pub struct Jukebox {
+    /// The music playing or fading in
+    current: Option<Track>,
+    /// The music being faded out
+    outgoing: Option<Track>,
+    /// How loud the music is once it's faded in
+    volume: Fixed,
+}
+
+impl Default for Jukebox {
+    fn default() -> Self {
+        Self {
+            current: None,
+            outgoing: None,
+            volume: MUSIC_VOLUME,
+        }
+    }
+}
+
+impl Jukebox {
+    pub fn set_volume(&mut self, volume: Fixed) {
+        self.volume = volume;
+    }
+
+    /// Crossfades to `music`, or fades out to silence for `None`. Asking for what's already
+    /// playing carries on with it.
+    pub fn play(&mut self, music: Option<Music>, mixer: &mut Mixer) {
+        if self.current.as_ref().map(|track| track.music) == music {
+            return;
+        }
+
+        // Something still fading out from the last change is cut off to keep to two tracks
+        if let Some(outgoing) = self.outgoing.take() {
+            if let Some(channel) = mixer.channel(&outgoing.channel) {
+                channel.stop();
+            }
+        }
+        self.outgoing = self.current.take();
+        self.current = music.and_then(|music| Track::start(music, mixer));
+    }
+
+    /// Moves both tracks on a frame of the crossfade
+    pub fn frame(&mut self, mixer: &mut Mixer) {
+        let step = Fixed::new(1) / MUSIC_CROSSFADE_FRAMES.0 as i32;
+        if let Some(current) = &mut self.current {
+            if !current.fade(step, self.volume, mixer) {
+                self.current = None;
+            }
+        }
+        if let Some(outgoing) = &mut self.outgoing {
+            if !outgoing.fade(-step, self.volume, mixer) {
+                self.outgoing = None;
+            }
+        }
+    }
+}
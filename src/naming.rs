@@ -0,0 +1,125 @@
+//! Typing in a name for the profile that's playing, got to from the name under the profile on
+//! the select screen. Up and down change a letter and left and right move between them, the same
+//! as a password. A keeps the name and B goes back without it, either way back to the select
+//! screen with the paddle and options as they were left.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::profile::{Name, NAME_LENGTH, NAME_LETTERS};
+use crate::scene::{Frame, Next, Resources};
+use crate::select::Picks;
+use crate::strings::Text;
+use crate::text::{self, Label};
+
+/// The name being typed in, with brackets round the letter being changed
+struct Typing<'n> {
+    letters: &'n [usize; NAME_LENGTH],
+    cursor: usize,
+}
+
+impl Display for Typing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &letter) in self.letters.iter().enumerate() {
+            let letter = NAME_LETTERS[letter] as char;
+            if index == self.cursor {
+                write!(f, "[{letter}]")?;
+            } else {
+                write!(f, " {letter} ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Naming<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    typed: Label<'a>,
+    /// Which of `NAME_LETTERS` each place is on
+    letters: [usize; NAME_LENGTH],
+    cursor: usize,
+    /// What was picked on the select screen, for going back to it
+    picks: Picks,
+}
+
+impl<'a> Naming<'a> {
+    pub fn new(picks: Picks, resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut heading = Label::new(resources.tiled, frame.vram, (8, 4));
+        let heading_text = Text::EnterName.get(frame.options.language);
+        heading.write(frame.vram, text::TEXT, format_args!("{heading_text}"));
+
+        // Starts from the name the profile already has
+        let name = frame.save.profiles.current().name;
+        let letters = name.0.map(|letter| {
+            NAME_LETTERS
+                .iter()
+                .position(|&known| known == letter)
+                .unwrap_or(0)
+        });
+
+        let mut naming = Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            heading,
+            typed: Label::new(resources.tiled, frame.vram, (7, 8)),
+            letters,
+            cursor: 0,
+            picks,
+        };
+        naming.show_typed(frame.vram);
+        naming
+    }
+
+    fn show_typed(&mut self, vram: &mut VRamManager) {
+        let typing = Typing {
+            letters: &self.letters,
+            cursor: self.cursor,
+        };
+        self.typed
+            .write(vram, text::HIGHLIGHT, format_args!("{typing}"));
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Select(Some(self.picks)));
+        }
+        if frame.input.is_just_pressed(Button::A) {
+            frame.save.profiles.current_mut().name =
+                Name(self.letters.map(|letter| NAME_LETTERS[letter]));
+            frame.save.write();
+            return Some(Next::Select(Some(self.picks)));
+        }
+
+        let letter_change = if frame.input.is_just_pressed(Button::UP) {
+            1
+        } else if frame.input.is_just_pressed(Button::DOWN) {
+            NAME_LETTERS.len() - 1
+        } else {
+            0
+        };
+        let cursor_change = if frame.input.is_just_pressed(Button::RIGHT) {
+            1
+        } else if frame.input.is_just_pressed(Button::LEFT) {
+            NAME_LENGTH - 1
+        } else {
+            0
+        };
+        if letter_change != 0 || cursor_change != 0 {
+            let letter = &mut self.letters[self.cursor];
+            *letter = (*letter + letter_change) % NAME_LETTERS.len();
+            self.cursor = (self.cursor + cursor_change) % NAME_LENGTH;
+            self.show_typed(frame.vram);
+        }
+
+        None
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.typed.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
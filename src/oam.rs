@@ -0,0 +1,35 @@
+//! Keeps an eye on how full OAM is, which slots each sprite lands in is down to its layer (see
+//! `layering`). The audit counts how many slots were used after each commit and says so in the
+//! debug log when it gets close to running out.
+
+use crate::tuning::OAM_WARNING_OBJECTS;
+
+/// How many objects OAM has room for
+const OAM_SLOTS: usize = 128;
+const OAM: *const u16 = 0x0700_0000 as *const u16;
+/// The bits of an object's first attribute that pick how it's drawn, this one is hidden
+const MODE_MASK: u16 = 0b11 << 8;
+const HIDDEN: u16 = 0b10 << 8;
+
+/// The most objects seen in OAM so far
+#[derive(Default)]
+pub struct Audit {
+    peak: usize,
+}
+
+impl Audit {
+    /// Counts the objects in OAM, must be done straight after it's committed
+    pub fn check(&mut self) {
+        // Safety: OAM is only read here, and it's read between the commit and the next one
+        let used = (0..OAM_SLOTS)
+            .filter(|slot| unsafe { OAM.add(slot * 4).read_volatile() } & MODE_MASK != HIDDEN)
+            .count();
+
+        if used > self.peak {
+            self.peak = used;
+            if used >= OAM_WARNING_OBJECTS {
+                agb::println!("{} of {} OAM slots used", used, OAM_SLOTS);
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Settings that stick around from match to match, unlike the modifiers picked for one match.
+//!
+//! A menu changing them changes them straight away so the player can see and hear what they
+//! get, with `Frame::apply_options` putting them into effect. It takes a `Snapshot` first, so
+//! backing out of the menu can put them back how they were and only keeping them writes the
+//! save.
+
+use crate::assets::BallSkin;
+use crate::background::CourtTheme;
+use crate::save::Save;
+use crate::strings::Language;
+use crate::text::Palette;
+use crate::tuning::{Fixed, MUSIC_VOLUME, MUSIC_VOLUME_STEPS};
+
+/// How loud the music is, in steps from silent up to `MUSIC_VOLUME`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MusicVolume(u8);
+
+impl MusicVolume {
+    /// Anything past the loudest step is taken as the loudest
+    pub fn from_step(step: u8) -> Self {
+        Self(step.min(MUSIC_VOLUME_STEPS))
+    }
+
+    pub fn step(self) -> u8 {
+        self.0
+    }
+
+    /// One step louder, going round to silent after the loudest
+    pub fn next(self) -> Self {
+        Self((self.0 + 1) % (MUSIC_VOLUME_STEPS + 1))
+    }
+
+    /// What the music's channels are turned down to
+    pub fn level(self) -> Fixed {
+        MUSIC_VOLUME * self.0 as i32 / MUSIC_VOLUME_STEPS as i32
+    }
+}
+
+impl Default for MusicVolume {
+    fn default() -> Self {
+        Self(MUSIC_VOLUME_STEPS)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Shows where the ball is going to reach the player's paddle, to help new players
+    pub coaching_hints: bool,
+    /// Draws the court the other way round so the player's paddle is on the right
+    pub mirrored: bool,
+    /// Turns off the effects that are only there to move about, like the weather
+    pub reduced_motion: bool,
+    /// Draws scanlines and dark corners over the court like an old television
+    pub crt_overlay: bool,
+    /// What all the menus and text in the match are written in
+    pub language: Language,
+    /// The unlockable looks, each only picked once it's unlocked
+    pub palette: Palette,
+    pub ball_skin: BallSkin,
+    pub court_theme: CourtTheme,
+    /// A cheat that makes every goal the player scores worth double
+    pub double_points: bool,
+    pub music_volume: MusicVolume,
+}
+
+/// The options as they were when a menu was opened
+#[derive(Clone, Copy)]
+pub struct Snapshot(Options);
+
+impl Snapshot {
+    pub fn take(options: &Options) -> Self {
+        Self(*options)
+    }
+
+    /// Puts `options` back how they were, they have to be applied again afterwards
+    pub fn revert(self, options: &mut Options) {
+        *options = self.0;
+    }
+
+    /// Keeps `options` for the profile playing, writing the save if they've changed since the
+    /// snapshot. The snapshot moves on to them, so reverting after goes back to here.
+    pub fn commit(&mut self, options: &Options, save: &mut Save) {
+        if *options != self.0 {
+            save.profiles.current_mut().options = *options;
+            save.write();
+        }
+        self.0 = *options;
+    }
+}
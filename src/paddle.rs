@@ -0,0 +1,359 @@
+use agb::display::object::{OamManaged, Tag};
+use agb::fixnum::{Rect, Vector2D};
+
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::character::Character;
+use crate::collision::{Collider, Layer};
+use crate::court::Court;
+use crate::entity::{intersects, Entity};
+use crate::events::{Events, GameEvent, Impact};
+use crate::layering::RenderLayer;
+use crate::physics::{self, ContactKind};
+use crate::tuning::{
+    Fixed, PixelsPerFrame, EDGE_SHOT_VELOCITY, MOMENTUM_SLOW_BALL_SPEED, PADDLE_COLLISION_MASK,
+    PADDLE_GOAL_GAP, PADDLE_MOMENTUM_MAX, PADDLE_MOMENTUM_TRANSFER, PADDLE_SQUEEZE, PADDLE_START_Y,
+    SPEED_BURST_MULTIPLIER,
+};
+use crate::tweaks;
+use crate::view;
+
+/// Which side of the screen the sprint is on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// A simple entity struct that holds the sprite and position for a paddle object
+pub struct Paddle<'a> {
+    object: &'a OamManaged<'a>,
+    /// Sprites for the ends and middle
+    end: &'static Tag,
+    middle: &'static Tag,
+    /// The 16 pixel tall pieces the paddle is made of from top to bottom
+    segments: Vec<Entity<'a>, MatchArena>,
+    /// Where the top of the paddle is, each segment sits below it by its index
+    y: Fixed,
+    /// How far the bottom end is pulled up into the top one, once there's nothing left between
+    /// them to take out
+    squeeze: i32,
+    velocity_y: Fixed,
+    /// How far the paddle really moved last frame, which is less than `velocity_y` against a wall
+    moved_y: Fixed,
+    speed: PixelsPerFrame,
+    /// Moves faster than normal during a speed burst
+    boosted: bool,
+    /// How far the sprites are drawn from where the paddle really is, for wobbles and bobs
+    sprite_offset: Vector2D<i32>,
+    flashing: bool,
+    side: Side,
+}
+
+/// Impl of paddle to allow for methods to interact with the sprite and setup
+/// The paddle is made up of an end sprite at the top and bottom with middle sprites between.
+impl<'a> Paddle<'a> {
+    pub fn new(
+        object: &'a OamManaged<'a>,
+        character: &Character,
+        which_side: Side,
+        court: &Court,
+    ) -> Self {
+        let (width, _) = character.end.sprite(0).size().to_width_height();
+        let x_pos_of_paddle = match which_side {
+            Side::Left => court.left().floor() + PADDLE_GOAL_GAP,
+            Side::Right => court.right().floor() - PADDLE_GOAL_GAP - width as i32,
+        };
+
+        // Every segment starts at the top, `set_y` below stacks them up properly
+        let segments = arena::collect((0..character.segments).map(|index| {
+            let is_top = index == 0;
+            let is_bottom = index == character.segments - 1;
+            let tag = if is_top || is_bottom {
+                character.end
+            } else {
+                character.middle
+            };
+
+            let mut segment = Entity::new(
+                object,
+                tag.sprite(0),
+                PADDLE_COLLISION_MASK.into(),
+                Layer::Paddle,
+                RenderLayer::Paddles,
+                (x_pos_of_paddle, 0).into(),
+                (0, 0).into(),
+            );
+            segment.sprite.set_vflip(is_bottom);
+            segment
+                .sprite
+                .set_hflip(view::hflip(matches!(which_side, Side::Right)));
+            segment.sprite.show();
+
+            segment
+        }));
+
+        let mut paddle = Paddle {
+            object,
+            end: character.end,
+            middle: character.middle,
+            segments,
+            y: 0.into(),
+            squeeze: 0,
+            velocity_y: 0.into(),
+            moved_y: 0.into(),
+            speed: character.speed,
+            boosted: false,
+            sprite_offset: (0, 0).into(),
+            flashing: false,
+            side: which_side,
+        };
+        paddle.set_y(PADDLE_START_Y.into());
+        paddle
+    }
+
+    /// A paddle in the middle of the court, for one that isn't on either side. Its side only
+    /// decides which way its sprites face.
+    pub fn neutral(object: &'a OamManaged<'a>, character: &Character, court: &Court) -> Self {
+        let mut paddle = Self::new(object, character, Side::Left, court);
+        let x = court.centre_x() - paddle.segments[0].size().x / 2;
+        for segment in &mut paddle.segments {
+            segment.position.x = x.into();
+        }
+        paddle
+    }
+
+    /// Moves the paddle and makes sure all of it stays within the walls of the court
+    pub fn checks_and_keeps_in_bounds(&mut self, court: &Court) {
+        let y = (self.y + self.velocity_y).clamp(court.top(), court.bottom() - self.height());
+        self.moved_y = y - self.y;
+        self.set_y(y);
+    }
+
+    /// Sets the velocity of the paddle based on the input of the y axis of the dpad, the
+    /// paddle moves at its character's speed when `checks_and_keeps_in_bounds` is called.
+    /// Anything between -1 and 1 moves it that fraction of its full speed. It can only get up to
+    /// that speed as quickly as the paddle acceleration allows.
+    pub fn move_paddle_with_input(&mut self, y_input: Fixed) {
+        let mut speed = (self.speed * y_input).0;
+        if self.boosted {
+            speed *= SPEED_BURST_MULTIPLIER;
+        }
+
+        let acceleration = tweaks::get().paddle_acceleration.0;
+        self.velocity_y += (speed - self.velocity_y).clamp(-acceleration, acceleration);
+    }
+
+    pub fn set_boosted(&mut self, boosted: bool) {
+        self.boosted = boosted;
+    }
+
+    /// Draws the sprites `offset` pixels away without moving the paddle itself
+    pub fn set_sprite_offset(&mut self, offset: Vector2D<i32>) {
+        self.sprite_offset = offset;
+    }
+
+    /// Switches the paddle over to its bright flash frame or back again
+    pub fn set_flashing(&mut self, flashing: bool) {
+        if flashing == self.flashing {
+            return;
+        }
+        self.flashing = flashing;
+
+        let (end, middle) = if flashing {
+            (SPRITES.flash_end, SPRITES.flash_mid)
+        } else {
+            (self.end, self.middle)
+        };
+        let last = self.segments.len() - 1;
+        for (index, segment) in self.segments.iter_mut().enumerate() {
+            let tag = if index == 0 || index == last {
+                end
+            } else {
+                middle
+            };
+            segment.sprite.set_sprite(self.object.sprite(tag.sprite(0)));
+        }
+    }
+
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// How fast the paddle really moved last frame
+    pub fn velocity(&self) -> Vector2D<Fixed> {
+        (0.into(), self.moved_y).into()
+    }
+
+    /// Where the top of the paddle is
+    pub fn y(&self) -> Fixed {
+        self.y
+    }
+
+    /// Moves the whole paddle so its top is at `y`, keeping the segments stacked together
+    pub fn set_y(&mut self, y: Fixed) {
+        self.y = y;
+        let segment_height = self.segment_height();
+        let last = self.segments.len() - 1;
+        for (index, segment) in self.segments.iter_mut().enumerate() {
+            segment.position.y = y + segment_height * index as i32;
+            if index == last {
+                segment.position.y -= self.squeeze;
+            }
+        }
+    }
+
+    /// Takes a middle segment out of the paddle, keeping it centred where it was. Once there's
+    /// nothing left but the ends they're pushed into each other instead, and after that it stops.
+    pub fn shrink(&mut self) {
+        let taken = if self.segments.len() > 2 {
+            let segment_height = self.segment_height();
+            self.segments.remove(self.segments.len() - 2);
+            segment_height
+        } else if self.squeeze == 0 {
+            self.squeeze = PADDLE_SQUEEZE;
+            PADDLE_SQUEEZE
+        } else {
+            return;
+        };
+        self.set_y(self.y + taken / 2);
+    }
+
+    /// Every segment is the same size as the ends
+    fn segment_height(&self) -> i32 {
+        self.segments[0].size().y
+    }
+
+    /// Where the middle of the paddle is
+    pub fn centre_y(&self) -> Fixed {
+        self.y + self.height() / 2
+    }
+
+    /// How tall the paddle is drawn, all the segments stacked together
+    pub fn height(&self) -> i32 {
+        self.segment_height() * self.segments.len() as i32 - self.squeeze
+    }
+
+    /// Moves the sprites of all the segments to match their positions
+    pub fn update_sprite_positions(&mut self) {
+        for segment in &mut self.segments {
+            let position = segment.position.floor() + self.sprite_offset;
+            segment
+                .sprite
+                .set_position(view::position(position, segment.size()));
+        }
+    }
+
+    /// The whole paddle as one collider, the segment it hits is worked out afterwards
+    pub fn collider(&self, index: usize) -> Collider {
+        Collider {
+            layer: self.segments[0].layer,
+            index,
+            rect: self.rect(),
+        }
+    }
+
+    /// The area covered by all the segments of the paddle
+    pub fn rect(&self) -> Rect<Fixed> {
+        let top = &self.segments[0];
+        let bottom = &self.segments[self.segments.len() - 1];
+        let height = bottom.position.y + bottom.collision_mask.y as i32 - top.position.y;
+        Rect::new(
+            top.position,
+            (Fixed::new(top.collision_mask.x as i32), height).into(),
+        )
+    }
+
+    /// Checks if any of the segments has collided with the ball and bounces it back
+    /// at the angle of the segment it hit. Clipping the very top or bottom corner of
+    /// the paddle sends the ball back faster as an edge shot. Returns true if the ball was hit.
+    #[link_section = ".iwram.collision"]
+    #[instruction_set(arm::a32)]
+    pub fn checks_all_collisions(&mut self, ball: &mut Ball, events: &mut Events) -> bool {
+        // Which way the ball has to travel in x to go away from this paddle
+        let away = match self.side {
+            Side::Left => 1,
+            Side::Right => -1,
+        };
+
+        // A ball that is already heading away has been dealt with, this stops it getting
+        // bounced again on the next frame while it is still overlapping
+        if ball.entity.velocity.x * away > 0.into() {
+            return false;
+        }
+
+        let relative_velocity = ball.entity.velocity - self.velocity();
+        let Some(contact) = physics::contact(ball.entity.rect(), self.rect(), relative_velocity)
+        else {
+            return false;
+        };
+        let impact = Impact {
+            point: contact.point.floor(),
+            speed: contact.closing_speed(),
+        };
+
+        if contact.kind == ContactKind::Corner {
+            ball.hit_by_paddle(
+                (
+                    EDGE_SHOT_VELOCITY.x * away,
+                    EDGE_SHOT_VELOCITY.y * contact.normal.y,
+                )
+                    .into(),
+            );
+            ball.smash();
+            events.push(GameEvent::EdgeShot {
+                side: self.side,
+                impact,
+            });
+            return true;
+        }
+
+        let Some(hit_segment) = self
+            .segments
+            .iter()
+            .position(|segment| intersects(&ball.entity, segment))
+        else {
+            return false;
+        };
+
+        // The ends bounce like the ends of the table, everything between them like the middle
+        let bounces = tweaks::get().segment_bounces;
+        let bounce = if hit_segment == 0 {
+            &bounces[0]
+        } else if hit_segment == self.segments.len() - 1 {
+            &bounces[2]
+        } else {
+            &bounces[1]
+        };
+        let mut velocity_y = bounce.velocity.y;
+        if bounce.keep_vertical_direction && ball.entity.velocity.y < 0.into() {
+            velocity_y = -velocity_y;
+        }
+
+        // A slow ball gets swept along by a paddle moving into it
+        if ball.entity.velocity.x.abs() < MOMENTUM_SLOW_BALL_SPEED.0 {
+            let transfer = (self.moved_y * PADDLE_MOMENTUM_TRANSFER)
+                .clamp(-PADDLE_MOMENTUM_MAX.0, PADDLE_MOMENTUM_MAX.0);
+            velocity_y += transfer;
+        }
+
+        ball.hit_by_paddle((bounce.velocity.x * away, velocity_y).into());
+        events.push(GameEvent::PaddleHit {
+            side: self.side,
+            impact,
+        });
+        true
+    }
+}
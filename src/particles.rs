@@ -0,0 +1,98 @@
+//! Short lived animated sprites like score popups. Each particle plays through its tag once
+//! over its lifetime and is removed when it finishes.
+
+use agb::display::object::{OamManaged, Object, Tag};
+use agb::fixnum::Vector2D;
+
+use crate::arrayvec::ArrayVec;
+use crate::layering::RenderLayer;
+use crate::tuning::{Fixed, Frames};
+use crate::view;
+
+/// The most particles there can be at once, any more than this aren't spawned
+const MAX_PARTICLES: usize = 32;
+
+pub struct Particle<'a> {
+    sprite: Object<'a>,
+    tag: &'static Tag,
+    position: Vector2D<Fixed>,
+    velocity: Vector2D<Fixed>,
+    age: Frames,
+    lifetime: Frames,
+    frame: usize,
+}
+
+pub struct Particles<'a> {
+    object: &'a OamManaged<'a>,
+    particles: ArrayVec<Particle<'a>, MAX_PARTICLES>,
+}
+
+impl<'a> Particles<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            object,
+            particles: ArrayVec::new(),
+        }
+    }
+
+    /// Spawns a particle that drifts by `velocity` every frame and is gone after `lifetime` frames
+    pub fn spawn(
+        &mut self,
+        tag: &'static Tag,
+        position: Vector2D<Fixed>,
+        velocity: Vector2D<Fixed>,
+        lifetime: Frames,
+    ) {
+        if self.particles.is_full() {
+            return;
+        }
+
+        let mut sprite = self.object.object_sprite(tag.sprite(0));
+        RenderLayer::Particles
+            .apply(&mut sprite)
+            .set_position(view::sprite_position(position.floor(), tag.sprite(0)))
+            .show();
+
+        self.particles.push(Particle {
+            sprite,
+            tag,
+            position,
+            velocity,
+            age: Frames::ZERO,
+            lifetime,
+            frame: 0,
+        });
+    }
+
+    /// Moves every particle on by a frame and removes the ones that have finished
+    pub fn update(&mut self) {
+        self.particles.retain_mut(|particle| {
+            particle.age.tick();
+            particle.position += particle.velocity;
+            particle.age < particle.lifetime
+        });
+    }
+
+    /// Moves the sprites to match the particles and picks the animation frame for their age
+    pub fn update_sprites(&mut self) {
+        for particle in &mut self.particles {
+            let frame = particle.age.0 as usize * particle.tag.sprites().len()
+                / particle.lifetime.0 as usize;
+            if frame != particle.frame {
+                particle.frame = frame;
+                particle
+                    .sprite
+                    .set_sprite(self.object.sprite(particle.tag.sprite(frame)));
+            }
+
+            let sprite = particle.tag.sprite(particle.frame);
+            particle
+                .sprite
+                .set_position(view::sprite_position(particle.position.floor(), sprite));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+}
@@ -0,0 +1,265 @@
+//! Passwords for carrying progress on without a save. The results screen shows one for the
+//! profile that played and R on the title screen gets to this screen to type one back in, up
+//! and down change a letter and left and right move between them. A takes it and B goes back.
+//!
+//! A password packs how many matches have been played and won and the achievements, with a
+//! checksum so a mistyped one is turned away rather than giving the wrong record.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::profile::{Achievements, Profile};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+
+/// The letters a password is made of, without the ones that look like each other
+const ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+pub const PASSWORD_LENGTH: usize = 8;
+
+/// Each letter holds 5 bits, made up of the record and then the checksum
+const COUNT_BITS: u32 = 10;
+const CHECKSUM_BITS: u32 = 12;
+/// Scrambles the bits so similar records don't give similar passwords
+const KEY: u64 = 0x96_c3_3c_5a_a5;
+
+/// The progress a password carries
+pub struct Progress {
+    pub played: u16,
+    pub won: u16,
+    pub achievements: Achievements,
+}
+
+fn checksum(data: u64) -> u64 {
+    let mut sum: u64 = 0x5a5;
+    for byte in data.to_le_bytes() {
+        sum = (sum * 31 + byte as u64) & ((1 << CHECKSUM_BITS) - 1);
+    }
+    sum
+}
+
+/// The password for a profile's progress, anything played or won past what fits is left off
+pub fn encode(profile: &Profile) -> [u8; PASSWORD_LENGTH] {
+    let most = (1 << COUNT_BITS) - 1;
+    let played = profile.lifetime.played.min(most) as u64;
+    let won = profile.lifetime.won.min(most) as u64;
+    let data = played | (won << COUNT_BITS) | ((profile.achievements.bits() as u64) << 20);
+    let bits = ((data << CHECKSUM_BITS) | checksum(data)) ^ KEY;
+
+    core::array::from_fn(|index| ALPHABET[((bits >> (index * 5)) & 0x1f) as usize])
+}
+
+/// The progress in a password, as long as its checksum matches
+pub fn decode(password: &[u8; PASSWORD_LENGTH]) -> Option<Progress> {
+    let mut bits = 0;
+    for (index, letter) in password.iter().enumerate() {
+        let value = ALPHABET.iter().position(|a| a == letter)? as u64;
+        bits |= value << (index * 5);
+    }
+    let bits = bits ^ KEY;
+
+    let data = bits >> CHECKSUM_BITS;
+    if bits & ((1 << CHECKSUM_BITS) - 1) != checksum(data) {
+        return None;
+    }
+
+    let mask = (1 << COUNT_BITS) - 1;
+    Some(Progress {
+        played: (data & mask) as u16,
+        won: ((data >> COUNT_BITS) & mask) as u16,
+        achievements: Achievements::from_bits((data >> 20) as u8),
+    })
+}
+
+/// A password written out in two groups of four so it's easier to copy down
+pub struct Written<'p>(pub &'p [u8; PASSWORD_LENGTH]);
+
+impl Display for Written<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &letter) in self.0.iter().enumerate() {
+            if index == PASSWORD_LENGTH / 2 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", letter as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// The password being typed in, with brackets round the letter being changed
+struct Typing<'p> {
+    letters: &'p [usize; PASSWORD_LENGTH],
+    cursor: usize,
+}
+
+impl Display for Typing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &letter) in self.letters.iter().enumerate() {
+            let letter = ALPHABET[letter] as char;
+            if index == self.cursor {
+                write!(f, "[{letter}]")?;
+            } else {
+                write!(f, " {letter} ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Password<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    typed: Label<'a>,
+    message: Label<'a>,
+    /// Which letter of the alphabet each place is on
+    letters: [usize; PASSWORD_LENGTH],
+    cursor: usize,
+}
+
+impl<'a> Password<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut heading = Label::new(resources.tiled, frame.vram, (8, 4));
+        let heading_text = Text::EnterPassword.get(frame.options.language);
+        heading.write(frame.vram, text::TEXT, format_args!("{heading_text}"));
+
+        let mut password = Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            heading,
+            typed: Label::new(resources.tiled, frame.vram, (6, 8)),
+            message: Label::new(resources.tiled, frame.vram, (8, 12)),
+            letters: [0; PASSWORD_LENGTH],
+            cursor: 0,
+        };
+        password.show_typed(frame.vram);
+        password
+    }
+
+    fn show_typed(&mut self, vram: &mut VRamManager) {
+        let typing = Typing {
+            letters: &self.letters,
+            cursor: self.cursor,
+        };
+        self.typed
+            .write(vram, text::HIGHLIGHT, format_args!("{typing}"));
+    }
+
+    /// Checks what's been typed, adding its progress to the profile that's playing if it's right
+    fn enter(&mut self, frame: &mut Frame) {
+        let password = self.letters.map(|letter| ALPHABET[letter]);
+        let message = match decode(&password) {
+            Some(progress) => {
+                frame.save.profiles.current_mut().restore(&progress);
+                frame.save.write();
+                Text::PasswordAccepted
+            }
+            None => Text::WrongPassword,
+        };
+        let message = message.get(frame.options.language);
+        self.message
+            .write(frame.vram, text::TEXT, format_args!("{message}"));
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Title);
+        }
+        if frame.input.is_just_pressed(Button::A) {
+            self.enter(frame);
+            return None;
+        }
+
+        let letter_change = if frame.input.is_just_pressed(Button::UP) {
+            1
+        } else if frame.input.is_just_pressed(Button::DOWN) {
+            ALPHABET.len() - 1
+        } else {
+            0
+        };
+        let cursor_change = if frame.input.is_just_pressed(Button::RIGHT) {
+            1
+        } else if frame.input.is_just_pressed(Button::LEFT) {
+            PASSWORD_LENGTH - 1
+        } else {
+            0
+        };
+        if letter_change != 0 || cursor_change != 0 {
+            let letter = &mut self.letters[self.cursor];
+            *letter = (*letter + letter_change) % ALPHABET.len();
+            self.cursor = (self.cursor + cursor_change) % PASSWORD_LENGTH;
+            self.show_typed(frame.vram);
+        }
+
+        None
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.typed.clear(vram);
+        self.message.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::Achievement;
+
+    /// The most played or won a password can carry
+    const MOST: u16 = (1 << COUNT_BITS) - 1;
+
+    fn profile(played: u16, won: u16, achievements: &[Achievement]) -> Profile {
+        let mut profile = Profile::default();
+        profile.lifetime.played = played;
+        profile.lifetime.won = won;
+        for &achievement in achievements {
+            profile.achievements.insert(achievement);
+        }
+        profile
+    }
+
+    /// A few records, from nothing at all up to the most a password holds
+    fn profiles() -> [Profile; 4] {
+        [
+            profile(0, 0, &[]),
+            profile(12, 7, &[Achievement::FirstWin]),
+            profile(MOST, 0, &[Achievement::LongRally]),
+            profile(
+                MOST,
+                MOST,
+                &[
+                    Achievement::FirstWin,
+                    Achievement::LongRally,
+                    Achievement::Shutout,
+                ],
+            ),
+        ]
+    }
+
+    #[test_case]
+    fn decode_gives_back_what_was_encoded(_gba: &mut agb::Gba) {
+        for profile in profiles() {
+            let progress = decode(&encode(&profile)).expect("the password should be accepted");
+            assert_eq!(progress.played, profile.lifetime.played);
+            assert_eq!(progress.won, profile.lifetime.won);
+            assert_eq!(progress.achievements, profile.achievements);
+        }
+    }
+
+    #[test_case]
+    fn decode_turns_away_a_changed_letter(_gba: &mut agb::Gba) {
+        for profile in profiles() {
+            let password = encode(&profile);
+            for place in 0..PASSWORD_LENGTH {
+                for &letter in ALPHABET.iter().filter(|&&letter| letter != password[place]) {
+                    let mut mistyped = password;
+                    mistyped[place] = letter;
+                    assert!(decode(&mistyped).is_none());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! The action stops for a moment when a goal goes in. The court flashes and nudges towards
+//! where the ball went in, then play carries on with the serve.
+
+use agb::fixnum::{Num, Vector2D};
+
+use crate::paddle::Side;
+use crate::tuning::{Frames, PHOTO_FINISH_FRAMES, PHOTO_FINISH_NUDGE};
+
+pub struct PhotoFinish {
+    frames_left: Frames,
+    /// How far the court is scrolled towards the goal
+    nudge: Vector2D<i16>,
+    /// Who serves once the freeze is over, nobody if it was a copy of the ball that went in
+    pub server: Option<Side>,
+}
+
+impl PhotoFinish {
+    pub fn new(position: Vector2D<i32>, server: Option<Side>) -> Self {
+        let centre_x = agb::display::WIDTH / 2;
+        let centre_y = agb::display::HEIGHT / 2;
+        let nudge_x = if position.x < centre_x {
+            -PHOTO_FINISH_NUDGE
+        } else {
+            PHOTO_FINISH_NUDGE
+        };
+        let nudge_y = (position.y - centre_y) * PHOTO_FINISH_NUDGE / centre_y;
+
+        Self {
+            frames_left: PHOTO_FINISH_FRAMES,
+            nudge: (nudge_x as i16, nudge_y as i16).into(),
+            server,
+        }
+    }
+
+    /// Counts down the freeze, returns true once it's over
+    pub fn update(&mut self) -> bool {
+        self.frames_left.tick_down();
+        self.frames_left == Frames::ZERO
+    }
+
+    /// How white the court is flashed, fading back to normal over the freeze
+    pub fn flash(&self) -> Num<u8, 4> {
+        Num::from_raw((self.frames_left * 16 / PHOTO_FINISH_FRAMES).min(16) as u8)
+    }
+
+    pub fn nudge(&self) -> Vector2D<i16> {
+        self.nudge
+    }
+}
@@ -0,0 +1,397 @@
+//! Collision and velocity helpers that work on plain rectangles and vectors, so they don't
+//! care which sprites are involved
+//!
+//! The ones run for every ball every frame, along with the broadphase, the computer's paddle
+//! and the trajectory, are put in IWRAM as ARM code with `#[link_section = ".iwram.*"]` and
+//! `#[instruction_set(arm::a32)]`. Code in IWRAM runs at full speed rather than waiting on the
+//! cartridge for every instruction. There's only 32K of it, shared with the stack, so anything
+//! that isn't in the per-frame path (menus, saving, setting up a match) is left where the
+//! compiler puts it by default, in ROM.
+
+use agb::fixnum::{Rect, Vector2D};
+
+use crate::court::Court;
+use crate::tuning::{Fixed, Frames, PixelsPerFrame};
+
+/// Which part of an obstacle something ran into
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContactKind {
+    /// Hit one of the flat sides
+    Face,
+    /// Hit right on a corner, the normal points diagonally away from it
+    Corner,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Contact {
+    /// Direction pointing out of the obstacle towards the mover, each axis is -1, 0 or 1
+    pub normal: Vector2D<i32>,
+    pub kind: ContactKind,
+    /// How far the mover is into the obstacle along the normal, the shallower way on a corner
+    pub depth: Fixed,
+    /// The middle of where the two overlap
+    pub point: Vector2D<Fixed>,
+    /// How fast the mover is going compared to the obstacle
+    pub relative_velocity: Vector2D<Fixed>,
+}
+
+impl Contact {
+    /// How fast the mover is going into the obstacle, negative if it's already moving away
+    pub fn closing_speed(&self) -> Fixed {
+        -(self.relative_velocity.x * self.normal.x + self.relative_velocity.y * self.normal.y)
+    }
+}
+
+/// Works out where `mover` touched `obstacle`, with `relative_velocity` being how fast the mover
+/// is going compared to the obstacle. The side is decided by where the center of `mover` is, if
+/// it is past the obstacle on both axes then it must have come in over a corner.
+#[link_section = ".iwram.physics"]
+#[instruction_set(arm::a32)]
+pub fn contact(
+    mover: Rect<Fixed>,
+    obstacle: Rect<Fixed>,
+    relative_velocity: Vector2D<Fixed>,
+) -> Option<Contact> {
+    if !overlaps(mover, obstacle) {
+        return None;
+    }
+
+    let overlap_start: Vector2D<Fixed> = (
+        mover.position.x.max(obstacle.position.x),
+        mover.position.y.max(obstacle.position.y),
+    )
+        .into();
+    let overlap_end: Vector2D<Fixed> = (
+        (mover.position.x + mover.size.x).min(obstacle.position.x + obstacle.size.x),
+        (mover.position.y + mover.size.y).min(obstacle.position.y + obstacle.size.y),
+    )
+        .into();
+    let overlap = overlap_end - overlap_start;
+    let point = overlap_start + overlap / 2;
+
+    let center = mover.position + mover.size / 2;
+    let obstacle_center = obstacle.position + obstacle.size / 2;
+
+    let outside_x =
+        center.x < obstacle.position.x || center.x >= obstacle.position.x + obstacle.size.x;
+    let outside_y =
+        center.y < obstacle.position.y || center.y >= obstacle.position.y + obstacle.size.y;
+
+    let direction_x = sign(center.x - obstacle_center.x);
+    let direction_y = sign(center.y - obstacle_center.y);
+
+    let (normal, kind, depth) = match (outside_x, outside_y) {
+        (true, true) => (
+            (direction_x, direction_y).into(),
+            ContactKind::Corner,
+            overlap.x.min(overlap.y),
+        ),
+        (false, true) => ((0, direction_y).into(), ContactKind::Face, overlap.y),
+        _ => ((direction_x, 0).into(), ContactKind::Face, overlap.x),
+    };
+
+    Some(Contact {
+        normal,
+        kind,
+        depth,
+        point,
+        relative_velocity,
+    })
+}
+
+/// Checks if two rectangles overlap, just touching edges doesn't count
+pub fn overlaps(a: Rect<Fixed>, b: Rect<Fixed>) -> bool {
+    a.position.x < b.position.x + b.size.x
+        && a.position.x + a.size.x > b.position.x
+        && a.position.y < b.position.y + b.size.y
+        && a.position.y + a.size.y > b.position.y
+}
+
+/// Where something at `position` moving at `velocity` ends up `ticks` frames later
+#[link_section = ".iwram.physics"]
+#[instruction_set(arm::a32)]
+pub fn integrate(
+    position: Vector2D<Fixed>,
+    velocity: Vector2D<Fixed>,
+    ticks: Frames,
+) -> Vector2D<Fixed> {
+    position + velocity * ticks.0 as i32
+}
+
+/// Bounces a velocity off a surface facing `normal`. Each axis the normal points along is
+/// turned around and the other is left alone, so a corner turns both.
+pub fn reflect(velocity: Vector2D<Fixed>, normal: Vector2D<i32>) -> Vector2D<Fixed> {
+    let flip = |speed: Fixed, normal: i32| if normal == 0 { speed } else { -speed };
+    (flip(velocity.x, normal.x), flip(velocity.y, normal.y)).into()
+}
+
+/// Bounces a velocity off a surface facing `normal`, which can point any way as long as it's
+/// one long. The part of the velocity along the normal is turned around.
+pub fn reflect_off(velocity: Vector2D<Fixed>, normal: Vector2D<Fixed>) -> Vector2D<Fixed> {
+    velocity - normal * (velocity.dot(normal) * 2)
+}
+
+/// Works out whether a circle touches `rect`, going by the closest point of the rect to its
+/// centre. The normal points from the centre of the circle towards the middle of the rect.
+#[link_section = ".iwram.physics"]
+#[instruction_set(arm::a32)]
+pub fn circle_contact(
+    centre: Vector2D<Fixed>,
+    radius: Fixed,
+    rect: Rect<Fixed>,
+) -> Option<Vector2D<Fixed>> {
+    let closest: Vector2D<Fixed> = (
+        centre
+            .x
+            .clamp(rect.position.x, rect.position.x + rect.size.x),
+        centre
+            .y
+            .clamp(rect.position.y, rect.position.y + rect.size.y),
+    )
+        .into();
+    if (closest - centre).magnitude_squared() >= radius * radius {
+        return None;
+    }
+
+    // Right on top of the middle there's no telling which way is out, so it goes up
+    let away = rect.position + rect.size / 2 - centre;
+    if away == (0, 0).into() {
+        return Some((0, -1).into());
+    }
+    Some(away.normalise())
+}
+
+/// Keeps something `size` big at `position` inside the court
+pub fn clamp_to_court(
+    position: Vector2D<Fixed>,
+    court: &Court,
+    size: Vector2D<i32>,
+) -> Vector2D<Fixed> {
+    (
+        position.x.clamp(court.left(), court.right() - size.x),
+        position.y.clamp(court.top(), court.bottom() - size.y),
+    )
+        .into()
+}
+
+/// Rebalances a velocity so the horizontal speed is at least `minimum`. The vertical speed
+/// is reduced to keep the overall speed the same, so this only changes the angle.
+pub fn with_minimum_horizontal_speed(
+    velocity: Vector2D<Fixed>,
+    minimum: PixelsPerFrame,
+) -> Vector2D<Fixed> {
+    let minimum = minimum.0;
+    if velocity.x.abs() >= minimum {
+        return velocity;
+    }
+
+    // A ball with no horizontal speed at all is sent right rather than picking a side at random
+    let direction_x = if velocity.x < 0.into() { -1 } else { 1 };
+    let speed = velocity.magnitude();
+
+    if speed <= minimum {
+        return (minimum * direction_x, velocity.y).into();
+    }
+
+    let speed_y = (speed * speed - minimum * minimum).sqrt();
+    let direction_y = if velocity.y < 0.into() { -1 } else { 1 };
+
+    (minimum * direction_x, speed_y * direction_y).into()
+}
+
+fn sign(value: Fixed) -> i32 {
+    match value.cmp(&0.into()) {
+        core::cmp::Ordering::Less => -1,
+        core::cmp::Ordering::Equal => 0,
+        core::cmp::Ordering::Greater => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tuning::{fixed, BALL_COLLISION_MASK};
+
+    /// The size of the ball, for clamping
+    const SIZE: Vector2D<i32> =
+        Vector2D::new(BALL_COLLISION_MASK.0 as i32, BALL_COLLISION_MASK.1 as i32);
+
+    /// The smallest step a `Fixed` can take
+    fn sub_pixel() -> Fixed {
+        Fixed::from_raw(1)
+    }
+
+    fn at(x: Fixed, y: Fixed) -> Vector2D<Fixed> {
+        (x, y).into()
+    }
+
+    /// Somewhere well inside the court, to leave the other axis alone while testing one
+    fn middle(court: &Court) -> Vector2D<Fixed> {
+        at(
+            (court.left() + court.right()) / 2,
+            (court.top() + court.bottom()) / 2,
+        )
+    }
+
+    #[test_case]
+    fn integrate_for_no_ticks_stays_put(_gba: &mut agb::Gba) {
+        let position = at(fixed(10, 1), fixed(20, 1));
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        assert_eq!(integrate(position, velocity, Frames::ZERO), position);
+    }
+
+    #[test_case]
+    fn integrate_moves_by_the_velocity_each_tick(_gba: &mut agb::Gba) {
+        let position = at(fixed(10, 1), fixed(20, 1));
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        assert_eq!(
+            integrate(position, velocity, Frames(1)),
+            at(fixed(23, 2), fixed(75, 4))
+        );
+        assert_eq!(
+            integrate(position, velocity, Frames(4)),
+            at(fixed(16, 1), fixed(15, 1))
+        );
+    }
+
+    #[test_case]
+    fn integrate_keeps_sub_pixel_movement(_gba: &mut agb::Gba) {
+        let position = at(fixed(10, 1), fixed(20, 1));
+        let velocity = at(sub_pixel(), -sub_pixel());
+        let moved = integrate(position, velocity, Frames(3));
+        assert_eq!(
+            moved,
+            at(
+                fixed(10, 1) + sub_pixel() * 3,
+                fixed(20, 1) - sub_pixel() * 3
+            )
+        );
+        assert_eq!(moved.floor(), (10, 19).into());
+    }
+
+    #[test_case]
+    fn reflect_off_a_side_turns_round_x(_gba: &mut agb::Gba) {
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        assert_eq!(
+            reflect(velocity, (1, 0).into()),
+            at(fixed(-3, 2), fixed(-5, 4))
+        );
+        assert_eq!(
+            reflect(velocity, (-1, 0).into()),
+            at(fixed(-3, 2), fixed(-5, 4))
+        );
+    }
+
+    #[test_case]
+    fn reflect_off_a_wall_turns_round_y(_gba: &mut agb::Gba) {
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        assert_eq!(
+            reflect(velocity, (0, 1).into()),
+            at(fixed(3, 2), fixed(5, 4))
+        );
+        assert_eq!(
+            reflect(velocity, (0, -1).into()),
+            at(fixed(3, 2), fixed(5, 4))
+        );
+    }
+
+    #[test_case]
+    fn reflect_off_a_corner_turns_round_both(_gba: &mut agb::Gba) {
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        assert_eq!(
+            reflect(velocity, (1, -1).into()),
+            at(fixed(-3, 2), fixed(5, 4))
+        );
+    }
+
+    #[test_case]
+    fn reflect_twice_is_back_where_it_started(_gba: &mut agb::Gba) {
+        let velocity = at(fixed(3, 2), fixed(-5, 4));
+        let normal = (1, 1).into();
+        assert_eq!(reflect(reflect(velocity, normal), normal), velocity);
+    }
+
+    #[test_case]
+    fn clamp_leaves_the_middle_alone(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let position = middle(&court);
+        assert_eq!(clamp_to_court(position, &court, SIZE), position);
+    }
+
+    #[test_case]
+    fn clamp_to_the_left(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let y = middle(&court).y;
+        let left = court.left();
+
+        for x in [left, left + 1, left + sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, y));
+        }
+        for x in [left - 1, left - sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(left, y));
+        }
+    }
+
+    #[test_case]
+    fn clamp_to_the_right(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let y = middle(&court).y;
+        let right = court.right() - SIZE.x;
+
+        for x in [right, right - 1, right - sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, y));
+        }
+        for x in [right + 1, right + sub_pixel(), court.right()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(right, y));
+        }
+    }
+
+    #[test_case]
+    fn clamp_to_the_top(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let x = middle(&court).x;
+        let top = court.top();
+
+        for y in [top, top + 1, top + sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, y));
+        }
+        for y in [top - 1, top - sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, top));
+        }
+    }
+
+    #[test_case]
+    fn clamp_to_the_bottom(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let x = middle(&court).x;
+        let bottom = court.bottom() - SIZE.y;
+
+        for y in [bottom, bottom - 1, bottom - sub_pixel()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, y));
+        }
+        for y in [bottom + 1, bottom + sub_pixel(), court.bottom()] {
+            assert_eq!(clamp_to_court(at(x, y), &court, SIZE), at(x, bottom));
+        }
+    }
+
+    #[test_case]
+    fn clamp_a_corner_on_both_axes(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let past = at(court.right() + 3, court.top() - sub_pixel());
+        let corner = at(court.right() - SIZE.x, court.top());
+        assert_eq!(clamp_to_court(past, &court, SIZE), corner);
+    }
+
+    /// What the ball used to do in one go, a sub pixel step over the edge ends up on it
+    #[test_case]
+    fn integrate_then_clamp_stops_at_the_edge(_gba: &mut agb::Gba) {
+        let court = Court::default();
+        let start = at(
+            court.right() - SIZE.x - sub_pixel(),
+            court.top() + sub_pixel(),
+        );
+        let velocity = at(sub_pixel() * 2, -sub_pixel() * 2);
+        let moved = clamp_to_court(integrate(start, velocity, Frames(1)), &court, SIZE);
+        assert_eq!(moved, at(court.right() - SIZE.x, court.top()));
+    }
+}
@@ -0,0 +1,75 @@
+//! A fixed set of sprites made once up front and shown wherever they're needed each frame,
+//! for things like dotted lines where the number drawn changes but never goes over a limit.
+//! Each copy can be moved onto a different sprite too, for crowds that animate out of step.
+
+use agb::display::object::{OamManaged, Object, Sprite};
+use agb::fixnum::Vector2D;
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::layering::RenderLayer;
+use crate::view;
+
+pub struct SpritePool<'a> {
+    object: &'a OamManaged<'a>,
+    sprite: &'static Sprite,
+    /// Each copy and the sprite it's showing right now
+    sprites: Vec<(Object<'a>, &'static Sprite), MatchArena>,
+}
+
+impl<'a> SpritePool<'a> {
+    /// Makes `count` hidden copies of `sprite` on `layer`
+    pub fn new(
+        object: &'a OamManaged<'a>,
+        sprite: &'static Sprite,
+        count: usize,
+        layer: RenderLayer,
+    ) -> Self {
+        let sprites = arena::collect((0..count).map(|_| {
+            let mut pooled = object.object_sprite(sprite);
+            layer.apply(&mut pooled).hide();
+            (pooled, sprite)
+        }));
+
+        Self {
+            object,
+            sprite,
+            sprites,
+        }
+    }
+
+    /// Shows one sprite at each position, hiding the ones that aren't needed. Anything past the
+    /// size of the pool isn't drawn.
+    pub fn place(&mut self, positions: impl IntoIterator<Item = Vector2D<i32>>) {
+        let sprite = self.sprite;
+        self.place_sprites(positions.into_iter().map(|position| (position, sprite)));
+    }
+
+    /// Like `place`, but with which sprite to show at each position
+    pub fn place_sprites(
+        &mut self,
+        placed: impl IntoIterator<Item = (Vector2D<i32>, &'static Sprite)>,
+    ) {
+        let mut placed = placed.into_iter();
+        for (pooled, showing) in &mut self.sprites {
+            match placed.next() {
+                Some((position, sprite)) => {
+                    if !core::ptr::eq(*showing, sprite) {
+                        pooled.set_sprite(self.object.sprite(sprite));
+                        *showing = sprite;
+                    }
+                    pooled
+                        .set_position(view::sprite_position(position, sprite))
+                        .show();
+                }
+                None => {
+                    pooled.hide();
+                }
+            }
+        }
+    }
+
+    pub fn hide(&mut self) {
+        self.place(core::iter::empty());
+    }
+}
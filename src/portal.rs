@@ -0,0 +1,86 @@
+//! A pair of portals on the court when the portals modifier is on, one in each half. A ball
+//! going into either comes straight out of the other one, heading the same way just as fast. It
+//! can't go back in for a moment afterwards, otherwise it would come out inside the other
+//! portal and go straight back.
+
+use agb::display::object::{OamManaged, Object};
+use agb::fixnum::{Rect, Vector2D};
+
+use crate::assets::SPRITES;
+use crate::collision::{Collider, Layer};
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::tuning::{Fixed, Frames, PORTAL_OFFSETS};
+use crate::view;
+
+/// How many pixels across a portal is, the same as its sprite
+const SIZE: i32 = 16;
+/// How many frames each step of the swirl is shown for
+const SWIRL_FRAMES: Frames = Frames(6);
+
+pub struct Portals<'a> {
+    object: &'a OamManaged<'a>,
+    /// Where each portal is on the court, the left one first
+    positions: [Vector2D<i32>; 2],
+    sprites: [Object<'a>; 2],
+    frame: Frames,
+}
+
+impl<'a> Portals<'a> {
+    pub fn new(object: &'a OamManaged<'a>, court: &Court) -> Self {
+        let positions =
+            PORTAL_OFFSETS.map(|(x, y)| (court.centre_x() + x - SIZE / 2, y - SIZE / 2).into());
+        let sprites = positions.map(|position| {
+            let mut sprite = object.object_sprite(SPRITES.portal.sprite(0));
+            RenderLayer::PowerUps
+                .apply(&mut sprite)
+                .set_position(view::sprite_position(position, SPRITES.portal.sprite(0)))
+                .show();
+            sprite
+        });
+
+        Self {
+            object,
+            positions,
+            sprites,
+            frame: Frames::ZERO,
+        }
+    }
+
+    /// Both portals, for the broadphase
+    pub fn colliders(&self) -> impl Iterator<Item = Collider> + '_ {
+        self.positions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| Collider {
+                layer: Layer::Portal,
+                index,
+                rect: Rect::new(position.into(), Vector2D::new(SIZE, SIZE).into()),
+            })
+    }
+
+    /// Where something `size` big that went into portal `index` comes out, right in the middle
+    /// of the other one
+    pub fn exit(&self, index: usize, size: Vector2D<i32>) -> Vector2D<Fixed> {
+        let other = self.positions[1 - index];
+        (other + (Vector2D::new(SIZE, SIZE) - size) / 2).into()
+    }
+
+    pub fn update(&mut self) {
+        self.frame.tick();
+    }
+
+    /// Turns the swirls on a step, the right one spinning the other way
+    pub fn update_sprites(&mut self) {
+        let frames = SPRITES.portal.sprites().len();
+        let step = (self.frame / SWIRL_FRAMES) as usize;
+        for (index, sprite) in self.sprites.iter_mut().enumerate() {
+            let step = if index == 0 {
+                step
+            } else {
+                frames - 1 - step % frames
+            };
+            sprite.set_sprite(self.object.sprite(SPRITES.portal.sprite(step % frames)));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Power ups that turn up in the middle of the court now and then. The ball collects one by
+//! passing through it, and it goes to whoever last hit the ball.
+
+use agb::display::object::{OamManaged, Object, Tag};
+use agb::fixnum::{Rect, Vector2D};
+
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::physics;
+use crate::random;
+use crate::tuning::{Fixed, Frames, POWER_UP_BLINK_FRAMES, POWER_UP_INTERVAL, POWER_UP_LIFETIME};
+use crate::view;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerUpKind {
+    /// Puts a shield in front of the collector's goal that stops one shot
+    Shield,
+    /// Splits the ball in two for a while
+    Split,
+    /// Turns the opponent's controls upside down for a while
+    Curse,
+}
+
+impl PowerUpKind {
+    const ALL: [PowerUpKind; 3] = [PowerUpKind::Shield, PowerUpKind::Split, PowerUpKind::Curse];
+
+    fn tag(self) -> &'static Tag {
+        match self {
+            PowerUpKind::Shield => SPRITES.power_up_shield,
+            PowerUpKind::Split => SPRITES.power_up_split,
+            PowerUpKind::Curse => SPRITES.power_up_curse,
+        }
+    }
+}
+
+/// A power up sitting on the court waiting to be collected
+struct PowerUp<'a> {
+    kind: PowerUpKind,
+    sprite: Object<'a>,
+    position: Vector2D<Fixed>,
+    frames_left: Frames,
+}
+
+pub struct PowerUps<'a> {
+    object: &'a OamManaged<'a>,
+    current: Option<PowerUp<'a>>,
+    /// Frames until the next power up appears
+    frames_until_next: Frames,
+}
+
+impl<'a> PowerUps<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            object,
+            current: None,
+            frames_until_next: POWER_UP_INTERVAL,
+        }
+    }
+
+    /// Spawns new power ups and takes away ones that have been waiting too long
+    pub fn update(&mut self, court: &Court) {
+        let Some(power_up) = &mut self.current else {
+            self.frames_until_next.tick_down();
+            if self.frames_until_next == Frames::ZERO {
+                self.spawn(court);
+            }
+            return;
+        };
+
+        power_up.frames_left.tick_down();
+        if power_up.frames_left == Frames::ZERO {
+            self.clear();
+        }
+    }
+
+    /// Where the power up is, if there is one
+    pub fn rect(&self) -> Option<Rect<Fixed>> {
+        let power_up = self.current.as_ref()?;
+        let size: Vector2D<i32> = (16, 16).into();
+        Some(Rect::new(power_up.position, size.into()))
+    }
+
+    /// Checks if `ball` has gone through the power up, returning its kind and who it goes to
+    pub fn collect(&mut self, ball: &Ball) -> Option<(PowerUpKind, Side)> {
+        let rect = self.rect()?;
+        let collector = ball.last_hit_by?;
+        if !physics::overlaps(ball.entity.rect(), rect) {
+            return None;
+        }
+
+        let kind = self.current.as_ref()?.kind;
+        self.clear();
+        Some((kind, collector))
+    }
+
+    /// Puts a random power up somewhere in the middle of the court, away from the paddles
+    fn spawn(&mut self, court: &Court) {
+        let kind =
+            PowerUpKind::ALL[random::gen().rem_euclid(PowerUpKind::ALL.len() as i32) as usize];
+        let area = court.rect();
+        let position: Vector2D<i32> = (
+            area.position.x + 64 + random::gen().rem_euclid(area.size.x - 128 - 16),
+            area.position.y + 16 + random::gen().rem_euclid(area.size.y - 32 - 16),
+        )
+            .into();
+
+        let mut sprite = self.object.object_sprite(kind.tag().sprite(0));
+        RenderLayer::PowerUps
+            .apply(&mut sprite)
+            .set_position(view::sprite_position(position, kind.tag().sprite(0)))
+            .show();
+
+        self.current = Some(PowerUp {
+            kind,
+            sprite,
+            position: position.into(),
+            frames_left: POWER_UP_LIFETIME,
+        });
+    }
+
+    /// Makes a power up that's about to disappear blink
+    pub fn update_sprites(&mut self) {
+        if let Some(power_up) = &mut self.current {
+            let blinking = power_up.frames_left < POWER_UP_BLINK_FRAMES;
+            if blinking && (power_up.frames_left.0 / 8) % 2 == 0 {
+                power_up.sprite.hide();
+            } else {
+                power_up.sprite.show();
+            }
+        }
+    }
+
+    /// Takes any power up off the court
+    pub fn clear(&mut self) {
+        self.current = None;
+        self.frames_until_next = POWER_UP_INTERVAL;
+    }
+}
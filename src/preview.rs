@@ -0,0 +1,57 @@
+//! The dotted line showing where the player's serve is going to go while they aim it
+
+use agb::display::object::OamManaged;
+use agb::fixnum::Vector2D;
+
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::pool::SpritePool;
+use crate::trajectory::Trajectory;
+use crate::tuning::{Fixed, SERVE_PREVIEW_DOTS, SERVE_PREVIEW_FRAMES};
+
+pub struct ServePreview<'a> {
+    dots: SpritePool<'a>,
+    /// The ball position and aim the dots were last worked out for
+    drawn_for: Option<(Vector2D<Fixed>, Vector2D<Fixed>)>,
+}
+
+impl<'a> ServePreview<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            dots: SpritePool::new(
+                object,
+                SPRITES.aim_dot.sprite(0),
+                SERVE_PREVIEW_DOTS,
+                RenderLayer::Particles,
+            ),
+            drawn_for: None,
+        }
+    }
+
+    /// Draws the path the held `ball` would take if it was served with `velocity`. The path is
+    /// only worked out again when the ball or the aim has moved.
+    pub fn update(&mut self, ball: &Ball, velocity: Vector2D<Fixed>, court: &Court) {
+        let key = (ball.entity.position, velocity);
+        if self.drawn_for == Some(key) {
+            return;
+        }
+        self.drawn_for = Some(key);
+
+        // The dot sprite is the same size as the ball with the dot in the middle
+        let frames = SERVE_PREVIEW_FRAMES.0 as usize;
+        let spacing = frames / SERVE_PREVIEW_DOTS;
+        let path = Trajectory::new(ball.entity.position, velocity, ball.entity.size(), court)
+            .take(frames)
+            .skip(spacing - 1)
+            .step_by(spacing)
+            .map(|position| position.floor());
+        self.dots.place(path);
+    }
+
+    pub fn hide(&mut self) {
+        self.dots.hide();
+        self.drawn_for = None;
+    }
+}
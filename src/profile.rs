@@ -0,0 +1,228 @@
+//! The two profiles kept on the cartridge so two people sharing it keep their own records. Each
+//! has a name, the settings its player likes, how their matches have gone and what they've
+//! achieved.
+
+use core::fmt::{self, Display};
+
+use crate::assets::BallSkin;
+use crate::background::CourtTheme;
+use crate::options::{MusicVolume, Options};
+use crate::paddle::Side;
+use crate::password::Progress;
+use crate::score::Score;
+use crate::stats::Stats;
+use crate::strings::Language;
+#[cfg(feature = "bonus-modes")]
+use crate::strings::Text;
+use crate::text::Palette;
+use crate::tuning::ACHIEVEMENT_RALLY;
+
+/// How many bytes a profile takes up in the save
+pub const PROFILE_BYTES: usize = 13 + NAME_LENGTH;
+
+/// How many letters a name can have
+pub const NAME_LENGTH: usize = 5;
+/// What a name can be spelt with, a space leaves that place empty
+pub const NAME_LETTERS: &[u8; 27] = b" ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// What a profile is called, padded out with spaces. It's all spaces until one is typed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Name(pub [u8; NAME_LENGTH]);
+
+impl Default for Name {
+    fn default() -> Self {
+        Self([b' '; NAME_LENGTH])
+    }
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let length = self
+            .0
+            .iter()
+            .rposition(|&letter| letter != b' ')
+            .map_or(0, |last| last + 1);
+        for &letter in &self.0[..length] {
+            write!(f, "{}", letter as char)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Achievement {
+    /// Beat the computer
+    FirstWin,
+    /// Kept a rally going for `ACHIEVEMENT_RALLY` returns
+    LongRally,
+    /// Won without the computer scoring
+    Shutout,
+}
+
+impl Achievement {
+    /// What has to be done to get it
+    #[cfg(feature = "bonus-modes")]
+    pub fn description(self) -> Text {
+        match self {
+            Achievement::FirstWin => Text::WinAMatch,
+            Achievement::LongRally => Text::KeepALongRally,
+            Achievement::Shutout => Text::WinFiveNil,
+        }
+    }
+}
+
+/// A set of achievements, one bit each
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Achievements(u8);
+
+impl Achievements {
+    pub fn insert(&mut self, achievement: Achievement) {
+        self.0 |= 1 << achievement as u8;
+    }
+
+    #[cfg(feature = "bonus-modes")]
+    pub fn contains(self, achievement: Achievement) -> bool {
+        self.0 & (1 << achievement as u8) != 0
+    }
+
+    pub fn count(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+}
+
+/// Every match a profile has played added together
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Lifetime {
+    pub played: u16,
+    pub won: u16,
+    pub goals: u16,
+    pub longest_rally: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub name: Name,
+    pub options: Options,
+    pub lifetime: Lifetime,
+    pub achievements: Achievements,
+}
+
+impl Profile {
+    /// Adds a finished match to the record. The player is always on the left.
+    pub fn record(&mut self, score: Score, stats: &Stats) {
+        let lifetime = &mut self.lifetime;
+        lifetime.played = lifetime.played.saturating_add(1);
+        lifetime.goals = lifetime.goals.saturating_add(score.left as u16);
+        lifetime.longest_rally = lifetime.longest_rally.max(stats.longest_rally as u16);
+
+        if score.winner() == Some(Side::Left) {
+            lifetime.won = lifetime.won.saturating_add(1);
+            self.achievements.insert(Achievement::FirstWin);
+            if score.right == 0 {
+                self.achievements.insert(Achievement::Shutout);
+            }
+        }
+        if stats.longest_rally >= ACHIEVEMENT_RALLY {
+            self.achievements.insert(Achievement::LongRally);
+        }
+    }
+
+    /// Takes on the progress from a password, keeping anything this profile has done past it
+    pub fn restore(&mut self, progress: &Progress) {
+        let lifetime = &mut self.lifetime;
+        lifetime.played = lifetime.played.max(progress.played);
+        lifetime.won = lifetime.won.max(progress.won);
+        self.achievements.0 |= progress.achievements.0;
+    }
+
+    pub fn to_bytes(self) -> [u8; PROFILE_BYTES] {
+        let options = self.options;
+        let lifetime = self.lifetime;
+        let mut bytes = [0; PROFILE_BYTES];
+        bytes[0] = options.coaching_hints as u8
+            | ((options.mirrored as u8) << 1)
+            | ((options.reduced_motion as u8) << 2)
+            | ((options.crt_overlay as u8) << 3);
+        bytes[1] = options.language as u8;
+        bytes[2..4].copy_from_slice(&lifetime.played.to_le_bytes());
+        bytes[4..6].copy_from_slice(&lifetime.won.to_le_bytes());
+        bytes[6..8].copy_from_slice(&lifetime.goals.to_le_bytes());
+        bytes[8..10].copy_from_slice(&lifetime.longest_rally.to_le_bytes());
+        bytes[10] = self.achievements.bits();
+        bytes[11] = options.palette as u8
+            | ((options.ball_skin as u8) << 2)
+            | ((options.court_theme as u8) << 4)
+            | ((options.double_points as u8) << 6);
+        bytes[12] = options.music_volume.step();
+        bytes[13..].copy_from_slice(&self.name.0);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: [u8; PROFILE_BYTES]) -> Self {
+        let u16_at = |index: usize| u16::from_le_bytes([bytes[index], bytes[index + 1]]);
+        // Each look is two bits of the last byte, anything out of range goes back to the default
+        let looks = |shift: u8| ((bytes[11] >> shift) & 3) as usize;
+        // Anything that can't be typed in is left empty
+        let mut name = Name::default();
+        for (letter, &byte) in name.0.iter_mut().zip(&bytes[13..]) {
+            if NAME_LETTERS.contains(&byte) {
+                *letter = byte;
+            }
+        }
+        Self {
+            name,
+            options: Options {
+                coaching_hints: bytes[0] & 1 != 0,
+                mirrored: bytes[0] & 2 != 0,
+                reduced_motion: bytes[0] & 4 != 0,
+                crt_overlay: bytes[0] & 8 != 0,
+                language: Language::ALL
+                    .get(bytes[1] as usize)
+                    .copied()
+                    .unwrap_or_default(),
+                palette: Palette::ALL.get(looks(0)).copied().unwrap_or_default(),
+                ball_skin: BallSkin::ALL.get(looks(2)).copied().unwrap_or_default(),
+                court_theme: CourtTheme::ALL.get(looks(4)).copied().unwrap_or_default(),
+                double_points: bytes[11] & (1 << 6) != 0,
+                music_volume: MusicVolume::from_step(bytes[12]),
+            },
+            lifetime: Lifetime {
+                played: u16_at(2),
+                won: u16_at(4),
+                goals: u16_at(6),
+                longest_rally: u16_at(8),
+            },
+            achievements: Achievements::from_bits(bytes[10]),
+        }
+    }
+}
+
+/// Both profiles and which one is playing
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Profiles {
+    pub profiles: [Profile; 2],
+    pub current: usize,
+}
+
+impl Profiles {
+    pub fn current(&self) -> &Profile {
+        &self.profiles[self.current]
+    }
+
+    pub fn current_mut(&mut self) -> &mut Profile {
+        &mut self.profiles[self.current]
+    }
+
+    /// Swaps over to the other profile
+    pub fn switch(&mut self) {
+        self.current = (self.current + 1) % self.profiles.len();
+    }
+}
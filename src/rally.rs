@@ -0,0 +1,79 @@
+//! Counts how many times the ball has been returned since the serve. The count sits quietly by
+//! the top of the net and each milestone turns the celebration up a bit more.
+
+use agb::display::tiled::{BackgroundID, Tiled0, VRamManager};
+
+use crate::text::{self, Label};
+use crate::tuning::{RALLY_FLASH_FRAMES, RALLY_MILESTONES, RALLY_SHOWN_FROM};
+
+pub struct Rally<'a> {
+    returns: u32,
+    label: Label<'a>,
+    /// Frames left of the count blinking after a milestone
+    flash_frames_left: u32,
+}
+
+impl<'a> Rally<'a> {
+    pub fn new(tiled: &'a Tiled0, vram: &mut VRamManager) -> Self {
+        Self {
+            returns: 0,
+            label: Label::new(tiled, vram, (14, 1)),
+            flash_frames_left: 0,
+        }
+    }
+
+    /// Counts a return, giving back which milestone it reached, 1 being the smallest
+    pub fn add_return(&mut self, vram: &mut VRamManager) -> Option<u32> {
+        self.returns += 1;
+
+        let milestone = RALLY_MILESTONES
+            .iter()
+            .position(|&milestone| milestone == self.returns)
+            .map(|index| index as u32 + 1);
+        if milestone.is_some() {
+            self.flash_frames_left = RALLY_FLASH_FRAMES.0;
+        }
+
+        self.show(vram);
+        milestone
+    }
+
+    /// Starts counting again for a new point
+    pub fn reset(&mut self, vram: &mut VRamManager) {
+        self.returns = 0;
+        self.flash_frames_left = 0;
+        self.label.set_visible(true);
+        self.show(vram);
+    }
+
+    fn show(&mut self, vram: &mut VRamManager) {
+        if self.returns < RALLY_SHOWN_FROM {
+            self.label.write(vram, text::TEXT, format_args!(""));
+            return;
+        }
+
+        // Past the first milestone the count changes colour to show it's a big one
+        let colour = if self.returns >= RALLY_MILESTONES[0] {
+            text::HIGHLIGHT
+        } else {
+            text::TEXT
+        };
+        self.label
+            .write(vram, colour, format_args!("{}", self.returns));
+    }
+
+    /// Blinks the count while it's flashing for a milestone
+    pub fn update(&mut self) {
+        self.flash_frames_left = self.flash_frames_left.saturating_sub(1);
+        self.label
+            .set_visible(self.flash_frames_left == 0 || (self.flash_frames_left / 6) % 2 == 0);
+    }
+
+    pub fn background(&self) -> BackgroundID {
+        self.label.background()
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.label.clear(vram);
+    }
+}
@@ -0,0 +1,21 @@
+//! Random numbers for the match. Everything during a match draws from here rather than agb's
+//! shared generator, so a match can be started again from the same seed.
+
+use core::cell::RefCell;
+
+use agb::external::critical_section::{self, Mutex};
+use agb::rng::RandomNumberGenerator;
+
+static RNG: Mutex<RefCell<RandomNumberGenerator>> =
+    Mutex::new(RefCell::new(RandomNumberGenerator::new()));
+
+/// Starts the numbers again from `seed`, none of its parts can be 0
+pub fn seed(seed: [u32; 4]) {
+    critical_section::with(|cs| {
+        *RNG.borrow_ref_mut(cs) = RandomNumberGenerator::new_with_seed(seed);
+    });
+}
+
+pub fn gen() -> i32 {
+    critical_section::with(|cs| RNG.borrow_ref_mut(cs).gen())
+}
@@ -0,0 +1,212 @@
+//! The best few results for each sort of record, kept on the cartridge alongside the profiles.
+//! Each board is saved in its own part of the save, so a new one can be added without the
+//! others moving. They belong to the cartridge rather than a profile, so both profiles go on
+//! the same boards.
+
+use crate::paddle::Side;
+use crate::rtc::{self, Date};
+use crate::score::Score;
+use crate::stats::Stats;
+use crate::strings::Text;
+
+/// How many records each board keeps
+pub const RECORDS_KEPT: usize = 3;
+/// The value, the score and then the date, a month of 0 meaning there's no date
+const ENTRY_BYTES: usize = 7;
+pub const BOARD_BYTES: usize = RECORDS_KEPT * ENTRY_BYTES;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Board {
+    /// The most returns in a rally, from any match
+    LongestRally,
+    /// The fewest seconds it took the player to win a match
+    QuickestWin,
+    /// The daily challenges won by the most
+    DailyChallenge,
+}
+
+impl Board {
+    pub const ALL: [Board; 3] = [
+        Board::LongestRally,
+        Board::QuickestWin,
+        Board::DailyChallenge,
+    ];
+
+    /// The boards the records screen flicks through. The daily challenge's is left out when the
+    /// challenge isn't built in, it's still kept in the save either way.
+    pub const SHOWN: &'static [Board] = if cfg!(feature = "bonus-modes") {
+        &Board::ALL
+    } else {
+        &[Board::LongestRally, Board::QuickestWin]
+    };
+
+    pub fn name(self) -> Text {
+        match self {
+            Board::LongestRally => Text::LongestRally,
+            Board::QuickestWin => Text::QuickestWin,
+            Board::DailyChallenge => Text::DailyChallenge,
+        }
+    }
+
+    /// How good an entry is on this board, bigger is better
+    fn rank(self, entry: &Entry) -> i32 {
+        match self {
+            Board::LongestRally => entry.value as i32,
+            Board::QuickestWin => -(entry.value as i32),
+            Board::DailyChallenge => entry.score.left as i32 - entry.score.right as i32,
+        }
+    }
+}
+
+/// A record on one of the boards and the match it came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// What the board is for, like the returns or the seconds. The daily board only goes by the
+    /// score.
+    pub value: u16,
+    pub score: Score,
+    /// When it was set, if there's a clock
+    pub date: Option<Date>,
+}
+
+impl Entry {
+    fn to_bytes(self) -> [u8; ENTRY_BYTES] {
+        let [low, high] = self.value.to_le_bytes();
+        let date = self
+            .date
+            .map_or([0; 3], |date| [date.year, date.month, date.day]);
+        [
+            low,
+            high,
+            self.score.left as u8,
+            self.score.right as u8,
+            date[0],
+            date[1],
+            date[2],
+        ]
+    }
+
+    /// Reads an entry back, all zeroes being an empty slot since no finished match is 0 - 0
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.iter().all(|&byte| byte == 0) {
+            return None;
+        }
+        Some(Self {
+            value: u16::from_le_bytes([bytes[0], bytes[1]]),
+            score: Score {
+                left: bytes[2] as u32,
+                right: bytes[3] as u32,
+            },
+            date: (bytes[5] != 0).then_some(Date {
+                year: bytes[4],
+                month: bytes[5],
+                day: bytes[6],
+            }),
+        })
+    }
+}
+
+/// The best entries on a board, best first
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Leaderboard {
+    entries: [Option<Entry>; RECORDS_KEPT],
+}
+
+impl Leaderboard {
+    /// Puts `entry` in its place if it's good enough, pushing the worst one off the end. The
+    /// daily board only keeps a day's best, so playing a day again can't fill it.
+    fn insert(&mut self, board: Board, entry: Entry) {
+        if board == Board::DailyChallenge {
+            let same_day = self
+                .entries()
+                .enumerate()
+                .find(|(_, existing)| existing.date == entry.date)
+                .map(|(place, existing)| (place, *existing));
+            if let Some((same_day, existing)) = same_day {
+                if board.rank(&entry) <= board.rank(&existing) {
+                    return;
+                }
+                // Takes the old one out so the new one can go in wherever it ranks
+                self.entries.copy_within(same_day + 1.., same_day);
+                self.entries[RECORDS_KEPT - 1] = None;
+            }
+        }
+
+        let Some(place) = self.entries.iter().position(|existing| {
+            existing.is_none_or(|existing| board.rank(&entry) > board.rank(&existing))
+        }) else {
+            return;
+        };
+        self.entries.copy_within(place..RECORDS_KEPT - 1, place + 1);
+        self.entries[place] = Some(entry);
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().flatten()
+    }
+
+    pub fn to_bytes(self) -> [u8; BOARD_BYTES] {
+        let mut bytes = [0; BOARD_BYTES];
+        for (chunk, entry) in bytes.chunks_mut(ENTRY_BYTES).zip(self.entries) {
+            if let Some(entry) = entry {
+                chunk.copy_from_slice(&entry.to_bytes());
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut board = Self::default();
+        for (entry, chunk) in board.entries.iter_mut().zip(bytes.chunks(ENTRY_BYTES)) {
+            *entry = Entry::from_bytes(chunk);
+        }
+        board
+    }
+}
+
+/// Every board, in the same order as `Board::ALL`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Records {
+    pub boards: [Leaderboard; Board::ALL.len()],
+}
+
+impl Records {
+    pub fn board(&self, board: Board) -> &Leaderboard {
+        &self.boards[board as usize]
+    }
+
+    /// The best score in the daily challenge for `date`, if it made it onto the board
+    #[cfg(feature = "bonus-modes")]
+    pub fn daily_best(&self, date: Date) -> Option<Score> {
+        self.board(Board::DailyChallenge)
+            .entries()
+            .find(|entry| entry.date == Some(date))
+            .map(|entry| entry.score)
+    }
+
+    /// Puts a finished match on every board it's good enough for, `daily` being the day it was
+    /// the daily challenge for if it was one
+    pub fn record(&mut self, score: Score, stats: &Stats, daily: Option<Date>) {
+        let entry = |value| Entry {
+            value,
+            score,
+            date: daily.or_else(rtc::today),
+        };
+
+        if stats.longest_rally > 0 {
+            let returns = stats.longest_rally.min(u16::MAX as u32) as u16;
+            self.insert(Board::LongestRally, entry(returns));
+        }
+        if score.winner() == Some(Side::Left) {
+            let seconds = (stats.frames.0 / 60).min(u16::MAX as u32) as u16;
+            self.insert(Board::QuickestWin, entry(seconds));
+        }
+        if daily.is_some() {
+            self.insert(Board::DailyChallenge, entry(0));
+        }
+    }
+
+    fn insert(&mut self, board: Board, entry: Entry) {
+        self.boards[board as usize].insert(board, entry);
+    }
+}
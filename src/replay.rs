@@ -0,0 +1,108 @@
+//! Keeps track of where the ball and paddles were through the point, so the winning point can
+//! be played back in slow motion before the celebration. Start skips to the end of it.
+
+use agb::display::tiled::{Tiled0, VRamManager};
+use agb::fixnum::Vector2D;
+use alloc::collections::VecDeque;
+
+use crate::arena::MatchArena;
+use crate::ball::Ball;
+use crate::paddle::{Paddle, Side};
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+use crate::tuning::{Fixed, REPLAY_MAX_FRAMES, REPLAY_SLOW_MOTION};
+
+/// Where everything was on one frame of the point
+#[derive(Clone, Copy)]
+struct Snapshot {
+    ball_position: Vector2D<Fixed>,
+    ball_velocity: Vector2D<Fixed>,
+    left_y: Fixed,
+    right_y: Fixed,
+}
+
+/// The frames of the point so far, only the last few seconds of a long rally are kept
+pub struct Recording {
+    snapshots: VecDeque<Snapshot, MatchArena>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self {
+            snapshots: VecDeque::with_capacity_in(REPLAY_MAX_FRAMES.0 as usize, MatchArena),
+        }
+    }
+
+    /// Throws away the last point ready to record the next one
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+
+    pub fn record(&mut self, ball: &Ball, left_paddle: &Paddle, right_paddle: &Paddle) {
+        if self.snapshots.len() >= REPLAY_MAX_FRAMES.0 as usize {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(Snapshot {
+            ball_position: ball.entity.position,
+            ball_velocity: ball.entity.velocity,
+            left_y: left_paddle.y(),
+            right_y: right_paddle.y(),
+        });
+    }
+}
+
+pub struct Replay<'a> {
+    pub winner: Side,
+    snapshots: VecDeque<Snapshot, MatchArena>,
+    /// Real frames since the replay started, each recorded one is shown for a few of these
+    frame: usize,
+    banner: Label<'a>,
+}
+
+impl<'a> Replay<'a> {
+    /// Takes the recording of the winning point to play it back
+    pub fn new(
+        winner: Side,
+        recording: &mut Recording,
+        tiled: &'a Tiled0,
+        vram: &mut VRamManager,
+        language: Language,
+    ) -> Self {
+        let mut banner = Label::new(tiled, vram, (13, 4));
+        let replay = Text::Replay.get(language);
+        banner.write(vram, text::HIGHLIGHT, format_args!("{replay}"));
+
+        Self {
+            winner,
+            snapshots: core::mem::replace(&mut recording.snapshots, VecDeque::new_in(MatchArena)),
+            frame: 0,
+            banner,
+        }
+    }
+
+    /// Puts everything where it was for this frame of the replay, returns true once it's done
+    pub fn update(
+        &mut self,
+        ball: &mut Ball,
+        left_paddle: &mut Paddle,
+        right_paddle: &mut Paddle,
+    ) -> bool {
+        let Some(snapshot) = self.snapshots.get(self.frame / REPLAY_SLOW_MOTION) else {
+            return true;
+        };
+
+        ball.entity.position = snapshot.ball_position;
+        ball.entity.velocity = snapshot.ball_velocity;
+        left_paddle.set_y(snapshot.left_y);
+        right_paddle.set_y(snapshot.right_y);
+
+        // The banner blinks slowly so it's obvious this isn't being played any more
+        self.banner.set_visible((self.frame / 30) % 2 == 0);
+        self.frame += 1;
+        false
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.banner.clear(vram);
+    }
+}
@@ -0,0 +1,215 @@
+//! Shown after a match with who won, the final score, the longest rally, each side's aces and a
+//! password for the profile's progress. Under them is a timeline of the goals, the ones the
+//! player scored along the top and the computer's along the bottom.
+//!
+//! A plays a rematch with the same paddles and rules. R does too but as a rubber match, which
+//! keeps a tally of who has won each match for as long as the player keeps pressing R. Anything
+//! else ends the tally, and start goes back to the title screen.
+
+use core::fmt::{self, Display};
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::assets::SPRITES;
+use crate::background::{self, Backdrop};
+use crate::game::MatchSettings;
+use crate::layering::RenderLayer;
+use crate::marquee::{Marquee, TextEffects};
+use crate::paddle::Side;
+use crate::password::{self, Written};
+use crate::pool::SpritePool;
+use crate::scene::{Frame, Next, Resources};
+use crate::score::Score;
+use crate::stats::{Stats, MAX_GOALS};
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+use crate::title::BLINK_FRAMES;
+
+/// How far apart the goals are along the timeline
+const GOAL_SPACING: i32 = 12;
+/// The heights of the player's goals and the computer's on the timeline
+const LEFT_GOAL_Y: i32 = 112;
+const RIGHT_GOAL_Y: i32 = 122;
+
+/// How the rubber match stands, written after the aces. Nothing is written if there isn't one.
+struct RubberTally {
+    tally: Option<Score>,
+    language: Language,
+}
+
+impl Display for RubberTally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.tally {
+            Some(tally) => write!(
+                f,
+                "  {}: {} - {}",
+                Text::RubberMatch.get(self.language),
+                tally.left,
+                tally.right
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct Results<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    headline: Marquee<'a>,
+    score: Label<'a>,
+    rally: Label<'a>,
+    goals: SpritePool<'a>,
+    prompt: Label<'a>,
+    frame: u32,
+    /// Kept for a rematch
+    settings: MatchSettings,
+    winner: Side,
+}
+
+impl<'a> Results<'a> {
+    pub fn new(
+        score: Score,
+        stats: Stats,
+        settings: MatchSettings,
+        resources: Resources<'a>,
+        frame: &mut Frame,
+    ) -> Self {
+        let backdrop = background::backdrop(resources.tiled, frame.vram, Backdrop::Panel);
+
+        // The player is always on the left, the computer on the right
+        let language = frame.options.language;
+        let winner = score.winner().unwrap_or(Side::Left);
+        let headline_text = match winner {
+            Side::Left => Text::YouWin,
+            Side::Right => Text::CpuWins,
+        };
+        if let Some(tally) = &mut frame.session.rubber {
+            tally.add_points(winner, 1);
+        }
+        let headline = Marquee::new(
+            resources.object,
+            headline_text.get(language),
+            40,
+            TextEffects {
+                colour_cycle: true,
+                typewriter: true,
+                ..Default::default()
+            },
+        );
+
+        let mut score_label = Label::new(resources.tiled, frame.vram, (13, 8));
+        score_label.write(
+            frame.vram,
+            text::TEXT,
+            format_args!("{} - {}", score.left, score.right),
+        );
+
+        // The record is kept before the password is made so the password has this match in it
+        let profile = frame.save.profiles.current_mut();
+        profile.record(score, &stats);
+        let password = password::encode(profile);
+        #[cfg(feature = "bonus-modes")]
+        let daily = settings.challenge.and_then(|challenge| challenge.date);
+        #[cfg(not(feature = "bonus-modes"))]
+        let daily = None;
+        frame.save.records.record(score, &stats, daily);
+        frame.save.write();
+
+        let mut rally = Label::new(resources.tiled, frame.vram, (9, 10));
+        let longest_rally = Text::LongestRally.get(language);
+        let aces = Text::Aces.get(language);
+        let password_text = Text::Password.get(language);
+        let rubber = RubberTally {
+            tally: frame.session.rubber,
+            language,
+        };
+        rally.write(
+            frame.vram,
+            text::TEXT,
+            format_args!(
+                "{longest_rally}: {}\n{aces}: {} - {}{}\n{password_text}: {}",
+                stats.longest_rally,
+                stats.aces.left,
+                stats.aces.right,
+                rubber,
+                Written(&password)
+            ),
+        );
+
+        let mut goals = SpritePool::new(
+            resources.object,
+            SPRITES.ball.sprite(0),
+            MAX_GOALS,
+            RenderLayer::Hud,
+        );
+        let count = stats.scorers().count() as i32;
+        let left = (agb::display::WIDTH - count * GOAL_SPACING) / 2;
+        goals.place(stats.scorers().enumerate().map(|(index, scorer)| {
+            let y = match scorer {
+                Side::Left => LEFT_GOAL_Y,
+                Side::Right => RIGHT_GOAL_Y,
+            };
+            (left + index as i32 * GOAL_SPACING, y).into()
+        }));
+
+        let mut prompt = Label::new(resources.tiled, frame.vram, (1, 17));
+        let rematch = Text::ResultsHelp.get(language);
+        prompt.write(frame.vram, text::TEXT, format_args!("{rematch}"));
+
+        Self {
+            backdrop,
+            headline,
+            score: score_label,
+            rally,
+            goals,
+            prompt,
+            frame: 0,
+            settings,
+            winner,
+        }
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        self.frame = self.frame.wrapping_add(1);
+
+        if frame.input.is_just_pressed(Button::A) {
+            frame.session.rubber = None;
+            return Some(Next::Versus(self.settings));
+        }
+
+        // This match counts as the first of the rubber match if it's only starting now
+        if frame.input.is_just_pressed(Button::R) {
+            let winner = self.winner;
+            frame.session.rubber.get_or_insert_with(|| {
+                let mut tally = Score::default();
+                tally.add_points(winner, 1);
+                tally
+            });
+            return Some(Next::Versus(self.settings));
+        }
+
+        if frame.input.is_just_pressed(Button::START) {
+            frame.session.rubber = None;
+            return Some(Next::Title);
+        }
+
+        None
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.headline.update_sprites();
+    }
+
+    pub fn after_vblank(&mut self) {
+        self.prompt
+            .set_visible((self.frame / BLINK_FRAMES) % 2 == 0);
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.score.clear(vram);
+        self.rally.clear(vram);
+        self.goals.hide();
+        self.prompt.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,62 @@
+//! How much of the cartridge the graphics take up. The sizes are worked out by `build.rs` when
+//! the game is built, which also fails the build if a sprite sheet has the same frame in it
+//! twice. The build fails here if they add up to more than `GRAPHICS_ROM_BUDGET`, and how big
+//! each file is goes to the debug log when the game starts, next to the sounds.
+
+use crate::tuning::GRAPHICS_ROM_BUDGET;
+
+/// One of the files in `gfx/`
+pub struct Asset {
+    #[cfg(feature = "debug-tools")]
+    path: &'static str,
+    /// Roughly how many bytes it takes on the cartridge
+    bytes: usize,
+    /// How many frames of a sprite sheet are repeated inside a tag, or how many tiles of a
+    /// background were the same as another and didn't need storing
+    #[cfg(feature = "debug-tools")]
+    repeats: usize,
+}
+
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+
+const GRAPHICS_BYTES: usize = {
+    let mut total = 0;
+    let mut index = 0;
+    while index < ASSETS.len() {
+        total += ASSETS[index].bytes;
+        index += 1;
+    }
+    total
+};
+
+// The dead code lint doesn't count what an unnamed constant reads, so without this the total
+// would look unused whenever the debug log isn't built in
+#[allow(dead_code)]
+const _: () = assert!(
+    GRAPHICS_BYTES <= GRAPHICS_ROM_BUDGET,
+    "the graphics take up more of the cartridge than GRAPHICS_ROM_BUDGET allows"
+);
+
+/// Writes how big each graphics file is to the debug log, biggest first
+#[cfg(feature = "debug-tools")]
+pub fn log_usage() {
+    let mut assets: alloc::vec::Vec<&Asset> = ASSETS.iter().collect();
+    assets.sort_unstable_by_key(|asset| core::cmp::Reverse(asset.bytes));
+    for asset in assets {
+        if asset.repeats > 0 {
+            agb::println!(
+                "{:>6} bytes  {}, {} repeated",
+                asset.bytes,
+                asset.path,
+                asset.repeats
+            );
+        } else {
+            agb::println!("{:>6} bytes  {}", asset.bytes, asset.path);
+        }
+    }
+    agb::println!(
+        "{:>6} bytes  of {} for graphics",
+        GRAPHICS_BYTES,
+        GRAPHICS_ROM_BUDGET
+    );
+}
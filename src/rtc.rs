@@ -0,0 +1,76 @@
+//! Reads the date from the real time clock some cartridges have, a Seiko S-3511 wired to the
+//! cartridge's GPIO port. The clock is talked to one bit at a time by toggling the port's pins.
+//! Without a clock the port is just more of the ROM, so whatever comes back is checked to be a
+//! real date before it's used.
+
+/// The GPIO port's data, which pins are outputs and whether it can be read back
+const DATA: *mut u16 = 0x0800_00C4 as *mut u16;
+const DIRECTION: *mut u16 = 0x0800_00C6 as *mut u16;
+const CONTROL: *mut u16 = 0x0800_00C8 as *mut u16;
+
+/// The pins the clock is on, the serial clock, the data going either way and chip select
+const SCK: u16 = 1 << 0;
+const SIO: u16 = 1 << 1;
+const CS: u16 = 1 << 2;
+
+/// Asks for the date and time, which the clock sends back starting with the year
+const READ_DATE_TIME: u8 = 0x65;
+
+/// A day of the calendar, the year being the last two digits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Date {
+    pub year: u8,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// The date today, if there's a clock to get it from
+pub fn today() -> Option<Date> {
+    let bytes = unsafe {
+        CONTROL.write_volatile(1);
+        DATA.write_volatile(SCK);
+        DATA.write_volatile(SCK | CS);
+        DIRECTION.write_volatile(SCK | SIO | CS);
+        write_byte(READ_DATE_TIME);
+        DIRECTION.write_volatile(SCK | CS);
+        // Only the date is wanted, dropping chip select ends the transfer before the time
+        let bytes: [u8; 3] = core::array::from_fn(|_| read_byte());
+        DATA.write_volatile(SCK);
+        CONTROL.write_volatile(0);
+        bytes
+    };
+
+    let date = Date {
+        year: from_bcd(bytes[0])?,
+        month: from_bcd(bytes[1] & 0x1f)?,
+        day: from_bcd(bytes[2] & 0x3f)?,
+    };
+    ((1..=12).contains(&date.month) && (1..=31).contains(&date.day)).then_some(date)
+}
+
+/// Sends a command, highest bit first
+unsafe fn write_byte(byte: u8) {
+    for bit in (0..8).rev() {
+        let sio = (((byte >> bit) & 1) as u16) << 1;
+        DATA.write_volatile(CS | sio);
+        DATA.write_volatile(CS | sio | SCK);
+    }
+}
+
+/// Reads a byte of data, lowest bit first
+unsafe fn read_byte() -> u8 {
+    let mut byte = 0;
+    for bit in 0..8 {
+        DATA.write_volatile(CS);
+        DATA.write_volatile(CS | SCK);
+        let sio = (DATA.read_volatile() & SIO) >> 1;
+        byte |= (sio as u8) << bit;
+    }
+    byte
+}
+
+/// The clock counts in binary coded decimal, a digit in each half of the byte
+fn from_bcd(byte: u8) -> Option<u8> {
+    let (tens, ones) = (byte >> 4, byte & 0xf);
+    (tens < 10 && ones < 10).then_some(tens * 10 + ones)
+}
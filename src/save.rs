@@ -0,0 +1,162 @@
+//! Keeps the profiles and the records on the cartridge. Which save chip it has is picked once at
+//! startup from the cargo features, battery backed SRAM unless told otherwise. If there's no save
+//! there yet, or it's from something else, everything starts out fresh and overwrites it the
+//! first time anything changes.
+//!
+//! Without a save chip, or if it stops working, the game carries on with nothing kept between
+//! power cycles. `is_saving` lets the menus say so.
+
+use agb::save::{SaveData, SaveManager};
+use agb::timer::Timer;
+
+use crate::profile::{Profile, Profiles, PROFILE_BYTES};
+use crate::records::{Board, Leaderboard, Records, BOARD_BYTES};
+
+/// Written at the start of the save so one from another game isn't read as profiles
+const MAGIC: [u8; 4] = *b"PONG";
+/// Bumped whenever the layout changes so an old save starts fresh instead of being misread
+const VERSION: u8 = 6;
+/// The magic, the version, which profile was playing, each profile and then each board of
+/// records
+const HEADER_BYTES: usize = MAGIC.len() + 2;
+const RECORDS_START: usize = HEADER_BYTES + 2 * PROFILE_BYTES;
+const SAVE_BYTES: usize = RECORDS_START + Board::ALL.len() * BOARD_BYTES;
+
+pub struct Save {
+    /// Missing if there's no save or it couldn't be got at, then nothing is kept between power
+    /// cycles
+    data: Option<SaveData>,
+    pub profiles: Profiles,
+    pub records: Records,
+}
+
+impl Save {
+    /// Sets up the save chip and reads what's on it. The timer stops a flash or EEPROM chip that
+    /// doesn't answer from hanging the game.
+    pub fn new(manager: &mut SaveManager, timer: Timer) -> Self {
+        let mut data = init(manager)
+            .then(|| manager.access_with_timer(timer).ok())
+            .flatten();
+        let (profiles, records) = data.as_mut().and_then(load).unwrap_or_default();
+        Self {
+            data,
+            profiles,
+            records,
+        }
+    }
+
+    /// Whether anything is being kept between power cycles
+    pub fn is_saving(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Writes everything out. If that fails the save is given up on and the game carries on
+    /// without it.
+    pub fn write(&mut self) {
+        let Some(data) = &mut self.data else {
+            return;
+        };
+
+        let mut bytes = [0; SAVE_BYTES];
+        bytes[..MAGIC.len()].copy_from_slice(&MAGIC);
+        bytes[MAGIC.len()] = VERSION;
+        bytes[MAGIC.len() + 1] = self.profiles.current as u8;
+        for (index, profile) in self.profiles.profiles.iter().enumerate() {
+            let start = HEADER_BYTES + index * PROFILE_BYTES;
+            bytes[start..start + PROFILE_BYTES].copy_from_slice(&profile.to_bytes());
+        }
+        for (index, board) in self.records.boards.iter().enumerate() {
+            let start = RECORDS_START + index * BOARD_BYTES;
+            bytes[start..start + BOARD_BYTES].copy_from_slice(&board.to_bytes());
+        }
+
+        let written = data
+            .prepare_write(0..SAVE_BYTES)
+            .and_then(|mut block| block.write(0, &bytes));
+        if written.is_err() {
+            self.data = None;
+        }
+    }
+}
+
+/// Tells agb which save chip the cartridge has, false if it has none
+#[cfg(feature = "no-save")]
+fn init(_manager: &mut SaveManager) -> bool {
+    false
+}
+
+#[cfg(not(feature = "no-save"))]
+fn init(manager: &mut SaveManager) -> bool {
+    #[cfg(feature = "save-flash-64k")]
+    manager.init_flash_64k();
+    #[cfg(feature = "save-flash-128k")]
+    manager.init_flash_128k();
+    #[cfg(feature = "save-eeprom-512b")]
+    manager.init_eeprom_512b();
+    #[cfg(feature = "save-eeprom-8k")]
+    manager.init_eeprom_8k();
+    #[cfg(not(any(
+        feature = "save-flash-64k",
+        feature = "save-flash-128k",
+        feature = "save-eeprom-512b",
+        feature = "save-eeprom-8k"
+    )))]
+    manager.init_sram();
+    true
+}
+
+// Only one chip can be set up, so picking two would quietly use whichever came last
+#[cfg(any(
+    all(
+        feature = "save-flash-64k",
+        any(
+            feature = "save-flash-128k",
+            feature = "save-eeprom-512b",
+            feature = "save-eeprom-8k",
+            feature = "no-save"
+        )
+    ),
+    all(
+        feature = "save-flash-128k",
+        any(
+            feature = "save-eeprom-512b",
+            feature = "save-eeprom-8k",
+            feature = "no-save"
+        )
+    ),
+    all(
+        feature = "save-eeprom-512b",
+        any(feature = "save-eeprom-8k", feature = "no-save")
+    ),
+    all(feature = "save-eeprom-8k", feature = "no-save"),
+))]
+compile_error!(
+    "pick at most one of save-flash-64k, save-flash-128k, save-eeprom-512b, save-eeprom-8k and \
+     no-save, SRAM is used when none are"
+);
+
+fn load(data: &mut SaveData) -> Option<(Profiles, Records)> {
+    let mut bytes = [0; SAVE_BYTES];
+    data.read(0, &mut bytes).ok()?;
+    if bytes[..MAGIC.len()] != MAGIC || bytes[MAGIC.len()] != VERSION {
+        return None;
+    }
+
+    let mut profiles = Profiles {
+        current: (bytes[MAGIC.len() + 1] as usize).min(1),
+        ..Default::default()
+    };
+    for (index, profile) in profiles.profiles.iter_mut().enumerate() {
+        let start = HEADER_BYTES + index * PROFILE_BYTES;
+        let mut profile_bytes = [0; PROFILE_BYTES];
+        profile_bytes.copy_from_slice(&bytes[start..start + PROFILE_BYTES]);
+        *profile = Profile::from_bytes(profile_bytes);
+    }
+
+    let mut records = Records::default();
+    for (index, board) in records.boards.iter_mut().enumerate() {
+        let start = RECORDS_START + index * BOARD_BYTES;
+        *board = Leaderboard::from_bytes(&bytes[start..start + BOARD_BYTES]);
+    }
+    Some((profiles, records))
+}
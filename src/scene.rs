@@ -0,0 +1,284 @@
+//! The game is split into scenes, like the splash screen or a match, and only one of them runs
+//! at a time. Main owns the hardware and hands each scene what it needs for the frame.
+
+use agb::display::object::OamManaged;
+use agb::display::tiled::{Tiled0, VRamManager};
+use agb::display::window::Windows;
+use agb::dma::{Dma, DmaTransferHandle};
+use agb::input::ButtonController;
+use alloc::boxed::Box;
+
+use crate::arena::MatchArena;
+use crate::audio::Audio;
+use crate::blending::Blending;
+use crate::credits::Credits;
+#[cfg(feature = "bonus-modes")]
+use crate::extras::Extras;
+#[cfg(feature = "bonus-modes")]
+use crate::gallery::Gallery;
+use crate::game::{Game, MatchSettings};
+use crate::leaderboards::Leaderboards;
+use crate::music::Music;
+use crate::naming::Naming;
+use crate::options::Options;
+use crate::password::Password;
+use crate::results::Results;
+use crate::save::Save;
+use crate::score::Score;
+use crate::select::{Picks, Select};
+use crate::session::Session;
+use crate::shimmer::ScanlineOffsets;
+use crate::splash::Splash;
+use crate::stats::Stats;
+use crate::text;
+use crate::title::Title;
+use crate::tutorial::Tutorial;
+use crate::versus::Versus;
+
+/// What a scene uses to create its sprites and backgrounds
+#[derive(Clone, Copy)]
+pub struct Resources<'a> {
+    pub object: &'a OamManaged<'a>,
+    pub tiled: &'a Tiled0<'a>,
+}
+
+/// Everything a scene can use while it updates
+pub struct Frame<'f, 'a> {
+    pub input: &'f ButtonController,
+    pub vram: &'f mut VRamManager,
+    pub audio: &'f mut Audio<'a>,
+    pub blending: &'f mut Blending<'a>,
+    pub windows: &'f mut Windows<'a>,
+    pub options: &'f mut Options,
+    /// The profiles kept on the cartridge, the one playing has its settings in `options`
+    pub save: &'f mut Save,
+    pub session: &'f mut Session,
+}
+
+impl Frame<'_, '_> {
+    /// Puts the options into effect wherever they show outside a match, the text colours and
+    /// the music volume, for when they've just been changed. The rest, like the mirrored court,
+    /// are picked up when the match starts.
+    pub fn apply_options(&mut self) {
+        text::set_palette(self.vram, self.options.palette);
+        self.audio
+            .set_music_volume(self.options.music_volume.level());
+    }
+}
+
+/// The scene to switch over to
+#[derive(Clone, Copy)]
+pub enum Next {
+    Splash,
+    Title,
+    Credits,
+    Leaderboards,
+    Tutorial,
+    Password,
+    /// Typing in a name, with what was picked on the select screen to go back to it with
+    Naming(Picks),
+    /// The select screen, starting from what was picked before if it's being gone back to
+    Select(Option<Picks>),
+    #[cfg(feature = "bonus-modes")]
+    Extras,
+    #[cfg(feature = "bonus-modes")]
+    Gallery,
+    /// Who's playing who, before the match itself
+    Versus(MatchSettings),
+    Game(MatchSettings),
+    /// The results of a match that has just finished, with how it was played for a rematch
+    Results(Score, Stats, MatchSettings),
+}
+
+pub enum Scene<'a> {
+    Splash(Splash<'a>),
+    Title(Title<'a>),
+    Credits(Credits<'a>),
+    Leaderboards(Leaderboards<'a>),
+    Tutorial(Tutorial<'a>),
+    Password(Password<'a>),
+    Naming(Naming<'a>),
+    #[cfg(feature = "bonus-modes")]
+    Extras(Extras<'a>),
+    #[cfg(feature = "bonus-modes")]
+    Gallery(Gallery<'a>),
+    Select(Select<'a>),
+    Versus(Versus<'a>),
+    // A match is much bigger than the other scenes
+    Game(Box<Game<'a>, MatchArena>),
+    Results(Results<'a>),
+}
+
+/// The music behind a scene. The splash screen is quiet, the menus all share the title's music
+/// and a match goes over to the victory music once it's won, which carries on into the results.
+fn music(next: &Next) -> Option<Music> {
+    match next {
+        Next::Splash => None,
+        Next::Title
+        | Next::Credits
+        | Next::Leaderboards
+        | Next::Tutorial
+        | Next::Password
+        | Next::Naming(_)
+        | Next::Select(_) => Some(Music::Title),
+        #[cfg(feature = "bonus-modes")]
+        Next::Extras | Next::Gallery => Some(Music::Title),
+        Next::Versus(_) | Next::Game(_) => Some(Music::Match),
+        Next::Results(..) => Some(Music::Victory),
+    }
+}
+
+impl<'a> Scene<'a> {
+    /// Creates the scene and puts it on screen. The scene before must already have left and been
+    /// dropped so that its backgrounds are free again.
+    pub fn enter(next: Next, resources: Resources<'a>, frame: &mut Frame) -> Self {
+        // The profile might have changed, so the options are applied again for each scene
+        frame.apply_options();
+        frame.audio.play_music(music(&next));
+        match next {
+            Next::Splash => Scene::Splash(Splash::new(resources, frame)),
+            Next::Title => Scene::Title(Title::new(resources, frame)),
+            Next::Credits => Scene::Credits(Credits::new(resources, frame)),
+            Next::Leaderboards => Scene::Leaderboards(Leaderboards::new(resources, frame)),
+            Next::Tutorial => Scene::Tutorial(Tutorial::new(resources, frame)),
+            Next::Password => Scene::Password(Password::new(resources, frame)),
+            Next::Naming(picks) => Scene::Naming(Naming::new(picks, resources, frame)),
+            #[cfg(feature = "bonus-modes")]
+            Next::Extras => Scene::Extras(Extras::new(resources, frame)),
+            #[cfg(feature = "bonus-modes")]
+            Next::Gallery => Scene::Gallery(Gallery::new(resources, frame)),
+            Next::Select(picks) => Scene::Select(Select::new(resources, frame, picks)),
+            Next::Versus(settings) => Scene::Versus(Versus::new(settings, resources, frame)),
+            Next::Game(settings) => Scene::Game(Box::new_in(
+                Game::new(resources, frame, settings),
+                MatchArena,
+            )),
+            Next::Results(score, stats, settings) => {
+                Scene::Results(Results::new(score, stats, settings, resources, frame))
+            }
+        }
+    }
+
+    /// Runs the logic for this frame, returning the scene to switch to if this one is done
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        match self {
+            Scene::Splash(splash) => splash.update(frame),
+            Scene::Title(title) => title.update(frame),
+            Scene::Credits(credits) => credits.update(frame),
+            Scene::Leaderboards(leaderboards) => leaderboards.update(frame),
+            Scene::Tutorial(tutorial) => tutorial.update(frame),
+            Scene::Password(password) => password.update(frame),
+            Scene::Naming(naming) => naming.update(frame),
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(extras) => extras.update(frame),
+            #[cfg(feature = "bonus-modes")]
+            Scene::Gallery(gallery) => gallery.update(frame),
+            Scene::Select(select) => select.update(frame),
+            Scene::Versus(versus) => versus.update(frame),
+            Scene::Game(game) => game.update(frame),
+            Scene::Results(results) => results.update(frame),
+        }
+    }
+
+    /// Whether the scene is only waiting for the player, so the console can sleep on it
+    pub fn can_sleep(&self) -> bool {
+        matches!(self, Scene::Title(_))
+    }
+
+    /// Whether the scene is a menu, which can get away with updating less often. The credits
+    /// aren't, they're meant to be watched without pressing anything.
+    pub fn is_menu(&self) -> bool {
+        match self {
+            Scene::Title(_)
+            | Scene::Select(_)
+            | Scene::Password(_)
+            | Scene::Naming(_)
+            | Scene::Leaderboards(_)
+            | Scene::Results(_) => true,
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Moves every sprite to match the state after this frame's update
+    pub fn update_sprites(&mut self) {
+        match self {
+            Scene::Splash(splash) => splash.update_sprites(),
+            Scene::Title(title) => title.update_sprites(),
+            Scene::Credits(_) | Scene::Leaderboards(_) | Scene::Password(_) | Scene::Naming(_) => {}
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(_) => {}
+            #[cfg(feature = "bonus-modes")]
+            Scene::Gallery(gallery) => gallery.update_sprites(),
+            Scene::Tutorial(tutorial) => tutorial.update_sprites(),
+            Scene::Select(select) => select.update_sprites(),
+            Scene::Versus(versus) => versus.update_sprites(),
+            Scene::Game(game) => game.update_sprites(),
+            Scene::Results(results) => results.update_sprites(),
+        }
+    }
+
+    /// Anything that has to change the backgrounds, done just after vblank
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        match self {
+            Scene::Splash(splash) => splash.after_vblank(vram),
+            Scene::Title(_)
+            | Scene::Select(_)
+            | Scene::Tutorial(_)
+            | Scene::Password(_)
+            | Scene::Naming(_)
+            | Scene::Leaderboards(_)
+            | Scene::Versus(_) => {}
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(_) | Scene::Gallery(_) => {}
+            Scene::Credits(credits) => credits.after_vblank(vram),
+            Scene::Game(game) => game.after_vblank(vram),
+            Scene::Results(results) => results.after_vblank(),
+        }
+    }
+
+    /// Starts the scanline effects for the new frame, if the scene has any
+    pub fn start_transfer<'t>(
+        &self,
+        dma: &'t Dma,
+        offsets: &'t mut ScanlineOffsets,
+    ) -> Option<DmaTransferHandle<'t, i16>> {
+        match self {
+            Scene::Game(game) => game.start_transfer(dma, offsets),
+            Scene::Splash(_)
+            | Scene::Title(_)
+            | Scene::Credits(_)
+            | Scene::Leaderboards(_)
+            | Scene::Tutorial(_)
+            | Scene::Password(_)
+            | Scene::Naming(_)
+            | Scene::Select(_)
+            | Scene::Versus(_)
+            | Scene::Results(_) => None,
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(_) | Scene::Gallery(_) => None,
+        }
+    }
+
+    /// Frees up the tiles and blending the scene was using before it gets dropped
+    pub fn leave(&mut self, frame: &mut Frame) {
+        match self {
+            Scene::Splash(splash) => splash.leave(frame.vram),
+            Scene::Title(title) => title.leave(frame.vram),
+            Scene::Credits(credits) => credits.leave(frame.vram),
+            Scene::Leaderboards(leaderboards) => leaderboards.leave(frame.vram),
+            Scene::Tutorial(tutorial) => tutorial.leave(frame.vram),
+            Scene::Password(password) => password.leave(frame.vram),
+            Scene::Naming(naming) => naming.leave(frame.vram),
+            #[cfg(feature = "bonus-modes")]
+            Scene::Extras(extras) => extras.leave(frame.vram),
+            #[cfg(feature = "bonus-modes")]
+            Scene::Gallery(gallery) => gallery.leave(frame.vram),
+            Scene::Select(select) => select.leave(frame.vram),
+            Scene::Versus(versus) => versus.leave(frame.vram),
+            Scene::Game(game) => game.leave(frame),
+            Scene::Results(results) => results.leave(frame.vram),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Keeps track of the points each side has scored
+
+use core::cmp::Ordering;
+
+use crate::paddle::Side;
+use crate::tuning::POINTS_TO_WIN;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Score {
+    pub left: u32,
+    pub right: u32,
+}
+
+impl Score {
+    pub fn add_points(&mut self, scorer: Side, points: u32) {
+        match scorer {
+            Side::Left => self.left += points,
+            Side::Right => self.right += points,
+        }
+    }
+
+    /// The side that has won the match, once either of them has enough points
+    pub fn winner(&self) -> Option<Side> {
+        if self.left >= POINTS_TO_WIN {
+            Some(Side::Left)
+        } else if self.right >= POINTS_TO_WIN {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    /// The side with more points, `None` while they're level
+    pub fn leader(&self) -> Option<Side> {
+        match self.left.cmp(&self.right) {
+            Ordering::Greater => Some(Side::Left),
+            Ordering::Less => Some(Side::Right),
+            Ordering::Equal => None,
+        }
+    }
+}
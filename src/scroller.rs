@@ -0,0 +1,107 @@
+//! Text that scrolls up the screen and can be as long as it likes. The background only has room
+//! for a screen and a half of lines, so each line is written as it comes up from the bottom and
+//! has its tiles freed again once it has gone off the top.
+
+use core::fmt::Write;
+
+use agb::display::font::TextRenderer;
+use agb::display::tiled::{MapLoan, RegularMap, Tiled0, TiledMap, VRamManager};
+use alloc::collections::VecDeque;
+
+use crate::background;
+use crate::text::{CLEAR, FONT};
+use crate::tuning::{Fixed, PixelsPerFrame};
+
+/// How many pixels apart the lines are, two rows of tiles each
+const LINE_HEIGHT: i32 = 16;
+/// How many pixels tall the background is before it wraps around
+const BACKGROUND_HEIGHT: i32 = 32 * 8;
+/// Which column of tiles the lines start at
+const LEFT_COLUMN: u16 = 2;
+
+/// A line of text and the colour it's written in
+pub struct Line {
+    pub text: &'static str,
+    pub colour: u8,
+}
+
+pub struct Scroller<'a> {
+    map: MapLoan<'a, RegularMap>,
+    lines: &'static [Line],
+    /// The lines on the background right now, oldest first, with the renderer holding their tiles
+    written: VecDeque<(usize, TextRenderer<'static>)>,
+    /// How far the top of the screen is past the first line, it starts a screen before it so the
+    /// text comes up from the bottom
+    scroll: Fixed,
+    speed: PixelsPerFrame,
+}
+
+impl<'a> Scroller<'a> {
+    pub fn new(
+        tiled: &'a Tiled0,
+        vram: &mut VRamManager,
+        lines: &'static [Line],
+        speed: PixelsPerFrame,
+    ) -> Self {
+        Self {
+            map: background::text(tiled, vram),
+            lines,
+            written: VecDeque::new(),
+            scroll: Fixed::new(-agb::display::HEIGHT),
+            speed,
+        }
+    }
+
+    /// Moves the text on, returns true once the last line has gone off the top
+    pub fn update(&mut self, vram: &mut VRamManager) -> bool {
+        self.scroll += self.speed.0;
+        let top = self.scroll.floor();
+
+        while let Some(&(line, _)) = self.written.front() {
+            if (line as i32 + 1) * LINE_HEIGHT > top {
+                break;
+            }
+            if let Some((line, mut renderer)) = self.written.pop_front() {
+                let row = Self::row(line);
+                background::clear_row(&mut self.map, vram, row);
+                background::clear_row(&mut self.map, vram, row + 1);
+                renderer.clear(vram);
+            }
+        }
+
+        // Written a line ahead so nothing is drawn while it's on screen
+        let next = self.written.back().map_or(0, |&(line, _)| line + 1);
+        let bottom = top + agb::display::HEIGHT + LINE_HEIGHT;
+        if next < self.lines.len() && (next as i32) * LINE_HEIGHT < bottom {
+            let line = &self.lines[next];
+            let mut renderer = FONT.render_text((LEFT_COLUMN, Self::row(next)));
+            let _ = renderer
+                .writer(line.colour, CLEAR, &mut self.map, vram)
+                .write_str(line.text);
+            renderer.commit(&mut self.map, vram);
+            self.written.push_back((next, renderer));
+        }
+
+        top >= self.lines.len() as i32 * LINE_HEIGHT
+    }
+
+    /// The first row of tiles line `line` goes in, wrapping round the background
+    fn row(line: usize) -> u16 {
+        ((line as i32 * LINE_HEIGHT).rem_euclid(BACKGROUND_HEIGHT) / 8) as u16
+    }
+
+    /// Scrolls the background to match, done just after vblank so it moves smoothly
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        let y = self.scroll.floor().rem_euclid(BACKGROUND_HEIGHT);
+        self.map.set_scroll_pos((0_i16, y as i16));
+        self.map.commit(vram);
+    }
+
+    /// Frees the tiles of every line still written, must be done before it's dropped
+    pub fn clear(&mut self, vram: &mut VRamManager) {
+        self.map.clear(vram);
+        for (_, mut renderer) in self.written.drain(..) {
+            renderer.clear(vram);
+        }
+    }
+}
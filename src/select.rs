@@ -0,0 +1,527 @@
+//! Lets the player pick which paddle to play as before the match. Left and right flick through
+//! the characters, A or start picks one and B goes back to the title screen. Up and down pick
+//! one of the options under it, like the difficulty, and select changes it. The last two options
+//! swap between the two profiles, which each remember their own settings and record, and go off
+//! to type in a name for the one playing. With the bonus modes built in, R plays the challenge
+//! with the chosen paddle instead, its rules are picked for the player, and L goes to the extras
+//! to pick what's been unlocked.
+//!
+//! Changing an option puts it into effect straight away, so the music gets louder or quieter
+//! while it's being turned up or down. Going on from here keeps the changes for the profile,
+//! going back to the title puts them back how they were.
+
+use core::fmt::{self, Display};
+
+use agb::display::object::OamManaged;
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::ai::Difficulty;
+use crate::background::{self, Backdrop};
+#[cfg(feature = "bonus-modes")]
+use crate::challenge::Challenge;
+use crate::character::{Character, CHARACTERS};
+use crate::court::Court;
+use crate::game::MatchSettings;
+use crate::modifiers::{Modifier, Modifiers};
+use crate::options::{Options, Snapshot};
+use crate::paddle::{Paddle, Side};
+use crate::profile::Profile;
+use crate::scene::{Frame, Next, Resources};
+#[cfg(feature = "bonus-modes")]
+use crate::score::Score;
+use crate::strings::{Language, Text};
+use crate::text::{self, Label};
+use crate::tuning::Fixed;
+
+/// How many rows of options there are, the difficulty, every modifier, the hints, the
+/// mirrored court, reduced motion, the crt overlay, the music volume, the language and then the
+/// profile and its name
+const OPTION_ROWS: usize = Modifier::ALL.len() + 9;
+const HINTS_ROW: usize = OPTION_ROWS - 8;
+const MIRROR_ROW: usize = OPTION_ROWS - 7;
+const MOTION_ROW: usize = OPTION_ROWS - 6;
+const CRT_ROW: usize = OPTION_ROWS - 5;
+const MUSIC_ROW: usize = OPTION_ROWS - 4;
+const LANGUAGE_ROW: usize = OPTION_ROWS - 3;
+const PROFILE_ROW: usize = OPTION_ROWS - 2;
+const NAME_ROW: usize = OPTION_ROWS - 1;
+/// How many of the rows fit on screen at once, the list scrolls to keep the cursor on it
+const VISIBLE_OPTION_ROWS: usize = 11;
+
+/// Describes a setting that's either on or off
+fn on_off(on: bool) -> Text {
+    if on {
+        Text::On
+    } else {
+        Text::Off
+    }
+}
+
+/// The difficulty, every modifier, the hints, mirroring, reduced motion and the crt overlay
+/// with whether they're on, the music volume, the language and the profile with its record and
+/// name, with an arrow next to the one select will change. Only the rows scrolled to are shown,
+/// with a mark at either end if there are more past it.
+struct OptionList {
+    difficulty: Difficulty,
+    modifiers: Modifiers,
+    options: Options,
+    profile: Profile,
+    /// Which profile it is, counting from 1
+    profile_number: usize,
+    /// Whether the profile is kept between power cycles
+    saving: bool,
+    cursor: usize,
+    /// The first row on screen
+    scroll: usize,
+}
+
+impl OptionList {
+    fn write_row(&self, f: &mut fmt::Formatter<'_>, row: usize) -> fmt::Result {
+        let last = (self.scroll + VISIBLE_OPTION_ROWS).min(OPTION_ROWS) - 1;
+        let arrow = match row {
+            _ if row == self.cursor => ">",
+            _ if row == self.scroll && row > 0 => "^",
+            _ if row == last && row < OPTION_ROWS - 1 => "v",
+            _ => " ",
+        };
+        let language = self.options.language;
+        let on_off_row = |f: &mut fmt::Formatter<'_>, name: Text, on: bool| {
+            write!(
+                f,
+                "{} {} {}",
+                arrow,
+                name.get(language),
+                on_off(on).get(language)
+            )
+        };
+
+        match row {
+            0 => write!(
+                f,
+                "{} {} {}",
+                arrow,
+                Text::Difficulty.get(language),
+                self.difficulty.name().get(language)
+            ),
+            HINTS_ROW => on_off_row(f, Text::Hints, self.options.coaching_hints),
+            MIRROR_ROW => on_off_row(f, Text::MirrorCourt, self.options.mirrored),
+            MOTION_ROW => on_off_row(f, Text::ReducedMotion, self.options.reduced_motion),
+            CRT_ROW => on_off_row(f, Text::CrtOverlay, self.options.crt_overlay),
+            // A star for each step of the volume
+            MUSIC_ROW => match self.options.music_volume.step() {
+                0 => on_off_row(f, Text::Music, false),
+                step => write!(
+                    f,
+                    "{} {} {}",
+                    arrow,
+                    Text::Music.get(language),
+                    Pips(step as usize)
+                ),
+            },
+            LANGUAGE_ROW => write!(
+                f,
+                "{} {} {}",
+                arrow,
+                Text::Language.get(language),
+                language.name()
+            ),
+            PROFILE_ROW => {
+                // The record is games won out of those played, then a star for each achievement
+                let lifetime = self.profile.lifetime;
+                write!(
+                    f,
+                    "{} {} {} {}/{} {}",
+                    arrow,
+                    Text::Profile.get(language),
+                    self.profile_number,
+                    lifetime.won,
+                    lifetime.played,
+                    Pips(self.profile.achievements.count())
+                )?;
+                if !self.saving {
+                    write!(f, " {}", Text::NotSaved.get(language))?;
+                }
+                Ok(())
+            }
+            NAME_ROW => write!(
+                f,
+                "{} {} {}",
+                arrow,
+                Text::Name.get(language),
+                self.profile.name
+            ),
+            row => {
+                let modifier = Modifier::ALL[row - 1];
+                on_off_row(f, modifier.name(), self.modifiers.is_on(modifier))
+            }
+        }
+    }
+}
+
+impl Display for OptionList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.scroll..(self.scroll + VISIBLE_OPTION_ROWS).min(OPTION_ROWS);
+        for row in rows {
+            if row != self.scroll {
+                writeln!(f)?;
+            }
+            self.write_row(f, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The chosen character's stats with the options and the challenge under them. They share a
+/// label so the menu panel can have a background of its own.
+struct Details<'c> {
+    character: &'c Character,
+    options: OptionList,
+    #[cfg(feature = "bonus-modes")]
+    challenge: ChallengeLine,
+}
+
+impl Display for Details<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let character = self.character;
+        let language = self.options.options.language;
+        let speed = Pips((character.speed.0 * 2).floor() as usize);
+        // Speed and size share a line to leave room for all the options
+        writeln!(
+            f,
+            "{} {}  {} {}",
+            Text::Speed.get(language),
+            speed,
+            Text::Size.get(language),
+            Pips(character.segments)
+        )?;
+        let special = character.ability.description().get(language);
+        writeln!(f, "{} {}", Text::Special.get(language), special)?;
+        writeln!(f, "{}", self.options)?;
+        #[cfg(feature = "bonus-modes")]
+        write!(f, "{}", self.challenge)?;
+        Ok(())
+    }
+}
+
+/// Which challenge R plays, with today's best score if it's the daily one and it's on the board
+#[cfg(feature = "bonus-modes")]
+struct ChallengeLine {
+    daily: bool,
+    best: Option<Score>,
+    language: Language,
+}
+
+#[cfg(feature = "bonus-modes")]
+impl Display for ChallengeLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let language = self.language;
+        if !self.daily {
+            return write!(f, "r: {}", Text::RandomChallenge.get(language));
+        }
+        write!(f, "r: {}", Text::DailyChallenge.get(language))?;
+        if let Some(best) = self.best {
+            write!(
+                f,
+                " {} {} - {}",
+                Text::Best.get(language),
+                best.left,
+                best.right
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A stat drawn as a row of stars
+struct Pips(usize);
+
+impl Display for Pips {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.0 {
+            f.write_str("*")?;
+        }
+        Ok(())
+    }
+}
+
+/// What's been picked here, handed to the name screen and back so none of it is lost on the way
+#[derive(Clone, Copy)]
+pub struct Picks {
+    selected: usize,
+    difficulty: Difficulty,
+    modifiers: Modifiers,
+    option_cursor: usize,
+    option_scroll: usize,
+    #[cfg(feature = "bonus-modes")]
+    challenge: Challenge,
+}
+
+impl Picks {
+    /// How the screen starts when it's come to from anywhere else
+    fn fresh() -> Self {
+        Self {
+            selected: 0,
+            difficulty: Difficulty::default(),
+            modifiers: Modifiers::default(),
+            option_cursor: 0,
+            option_scroll: 0,
+            #[cfg(feature = "bonus-modes")]
+            challenge: Challenge::today(),
+        }
+    }
+}
+
+pub struct Select<'a> {
+    object: &'a OamManaged<'a>,
+    backdrop: MapLoan<'a, RegularMap>,
+    heading: Label<'a>,
+    name: Label<'a>,
+    /// The stats and then the difficulty and modifiers picked for the match, up and down choose
+    /// one of those and select changes it
+    details: Label<'a>,
+    difficulty: Difficulty,
+    modifiers: Modifiers,
+    option_cursor: usize,
+    /// The first of the options on screen
+    option_scroll: usize,
+    /// The chosen paddle drawn in its spot on the court, so its size and colours can be seen
+    preview: Paddle<'a>,
+    selected: usize,
+    /// What R plays, worked out once so the random one doesn't change under the player
+    #[cfg(feature = "bonus-modes")]
+    challenge: Challenge,
+    /// The options as they were before anything was changed here, for going back to
+    snapshot: Snapshot,
+}
+
+impl<'a> Select<'a> {
+    /// Starts from `picks` if the player is coming back to the screen, or from scratch if not
+    pub fn new(resources: Resources<'a>, frame: &mut Frame, picks: Option<Picks>) -> Self {
+        let picks = picks.unwrap_or_else(Picks::fresh);
+        let backdrop = background::backdrop(resources.tiled, frame.vram, Backdrop::Panel);
+        // The name shares the top line with the heading to leave room for all the options
+        let heading = Label::new(resources.tiled, frame.vram, (2, 0));
+
+        let mut select = Self {
+            object: resources.object,
+            backdrop,
+            heading,
+            name: Label::new(resources.tiled, frame.vram, (20, 0)),
+            details: Label::new(resources.tiled, frame.vram, (8, 2)),
+            difficulty: picks.difficulty,
+            modifiers: picks.modifiers,
+            option_cursor: picks.option_cursor,
+            option_scroll: picks.option_scroll,
+            preview: Paddle::new(
+                resources.object,
+                &CHARACTERS[0],
+                Side::Left,
+                &Court::default(),
+            ),
+            selected: picks.selected,
+            #[cfg(feature = "bonus-modes")]
+            challenge: picks.challenge,
+            snapshot: Snapshot::take(frame.options),
+        };
+        select.show_heading(frame);
+        select.show_selected(frame);
+        select
+    }
+
+    fn show_heading(&mut self, frame: &mut Frame) {
+        let heading = Text::ChooseYourPaddle.get(frame.options.language);
+        self.heading
+            .write(frame.vram, text::TEXT, format_args!("{heading}"));
+    }
+
+    fn show_selected(&mut self, frame: &mut Frame) {
+        let character = &CHARACTERS[self.selected];
+
+        self.name.write(
+            frame.vram,
+            text::HIGHLIGHT,
+            format_args!("< {} >", character.name),
+        );
+        self.show_details(frame);
+
+        self.preview = Paddle::new(self.object, character, Side::Left, &Court::default());
+        // Sit the preview in the middle of the screen whatever size it is
+        let height = self.preview.height();
+        self.preview
+            .set_y(Fixed::new((agb::display::HEIGHT - height) / 2));
+    }
+
+    fn show_details(&mut self, frame: &mut Frame) {
+        let details = Details {
+            character: &CHARACTERS[self.selected],
+            options: OptionList {
+                difficulty: self.difficulty,
+                modifiers: self.modifiers,
+                options: *frame.options,
+                profile: *frame.save.profiles.current(),
+                profile_number: frame.save.profiles.current + 1,
+                saving: frame.save.is_saving(),
+                cursor: self.option_cursor,
+                scroll: self.option_scroll,
+            },
+            #[cfg(feature = "bonus-modes")]
+            challenge: ChallengeLine {
+                daily: self.challenge.date.is_some(),
+                best: self
+                    .challenge
+                    .date
+                    .and_then(|date| frame.save.records.daily_best(date)),
+                language: frame.options.language,
+            },
+        };
+        self.details
+            .write(frame.vram, text::TEXT, format_args!("{}", details));
+    }
+
+    /// Moves the difficulty, music volume, language or profile on to the next one, or turns the
+    /// chosen modifier, hints, mirroring, reduced motion or the crt overlay on or off. Anything
+    /// changed is applied straight away but only kept once the player goes on.
+    fn change_option(&mut self, frame: &mut Frame) {
+        let options = &mut *frame.options;
+        match self.option_cursor {
+            0 => {
+                let current = Difficulty::ALL
+                    .iter()
+                    .position(|&difficulty| difficulty == self.difficulty)
+                    .unwrap_or(0);
+                self.difficulty = Difficulty::ALL[(current + 1) % Difficulty::ALL.len()];
+            }
+            HINTS_ROW => options.coaching_hints = !options.coaching_hints,
+            MIRROR_ROW => options.mirrored = !options.mirrored,
+            MOTION_ROW => options.reduced_motion = !options.reduced_motion,
+            CRT_ROW => options.crt_overlay = !options.crt_overlay,
+            MUSIC_ROW => options.music_volume = options.music_volume.next(),
+            LANGUAGE_ROW => {
+                let current = Language::ALL
+                    .iter()
+                    .position(|&language| language == options.language)
+                    .unwrap_or(0);
+                options.language = Language::ALL[(current + 1) % Language::ALL.len()];
+            }
+            PROFILE_ROW => {
+                // Whatever was changed before switching belongs to the profile switched from
+                self.snapshot.commit(options, frame.save);
+                frame.save.profiles.switch();
+                frame.save.write();
+                *options = frame.save.profiles.current().options;
+                self.snapshot = Snapshot::take(options);
+            }
+            // Typed in on a screen of its own, `update` goes off to it
+            NAME_ROW => {}
+            row => self.modifiers.toggle(Modifier::ALL[row - 1]),
+        }
+
+        frame.apply_options();
+    }
+
+    /// Keeps whatever options were changed, for going on to the match or the extras
+    fn keep_options(&mut self, frame: &mut Frame) {
+        self.snapshot.commit(frame.options, frame.save);
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            // The title applies them again as it's entered
+            self.snapshot.revert(frame.options);
+            return Some(Next::Title);
+        }
+
+        if frame.input.is_just_pressed(Button::A) || frame.input.is_just_pressed(Button::START) {
+            self.keep_options(frame);
+            // The computer takes any of the paddles, it could even be the same one
+            let cpu = agb::rng::gen().rem_euclid(CHARACTERS.len() as i32) as usize;
+            return Some(Next::Versus(MatchSettings {
+                player: &CHARACTERS[self.selected],
+                cpu: &CHARACTERS[cpu],
+                difficulty: self.difficulty,
+                modifiers: self.modifiers,
+                #[cfg(feature = "bonus-modes")]
+                challenge: None,
+            }));
+        }
+
+        #[cfg(feature = "bonus-modes")]
+        if frame.input.is_just_pressed(Button::L) {
+            self.keep_options(frame);
+            return Some(Next::Extras);
+        }
+
+        #[cfg(feature = "bonus-modes")]
+        if frame.input.is_just_pressed(Button::R) {
+            self.keep_options(frame);
+            let challenge = self.challenge;
+            return Some(Next::Versus(MatchSettings {
+                player: &CHARACTERS[self.selected],
+                cpu: challenge.cpu,
+                difficulty: challenge.difficulty,
+                modifiers: challenge.modifiers,
+                challenge: Some(challenge),
+            }));
+        }
+
+        if frame.input.is_just_pressed(Button::SELECT) && self.option_cursor == NAME_ROW {
+            self.keep_options(frame);
+            return Some(Next::Naming(Picks {
+                selected: self.selected,
+                difficulty: self.difficulty,
+                modifiers: self.modifiers,
+                option_cursor: self.option_cursor,
+                option_scroll: self.option_scroll,
+                #[cfg(feature = "bonus-modes")]
+                challenge: self.challenge,
+            }));
+        }
+
+        let cursor_change = if frame.input.is_just_pressed(Button::UP) {
+            OPTION_ROWS - 1
+        } else if frame.input.is_just_pressed(Button::DOWN) {
+            1
+        } else {
+            0
+        };
+        if cursor_change != 0 || frame.input.is_just_pressed(Button::SELECT) {
+            self.option_cursor = (self.option_cursor + cursor_change) % OPTION_ROWS;
+            self.option_scroll = self.option_scroll.clamp(
+                (self.option_cursor + 1).saturating_sub(VISIBLE_OPTION_ROWS),
+                self.option_cursor,
+            );
+            if frame.input.is_just_pressed(Button::SELECT) {
+                self.change_option(frame);
+            }
+            // Changing the language changes the heading as well
+            self.show_heading(frame);
+            self.show_details(frame);
+        }
+
+        let change = if frame.input.is_just_pressed(Button::LEFT) {
+            CHARACTERS.len() - 1
+        } else if frame.input.is_just_pressed(Button::RIGHT) {
+            1
+        } else {
+            0
+        };
+        if change != 0 {
+            self.selected = (self.selected + change) % CHARACTERS.len();
+            self.show_selected(frame);
+        }
+
+        None
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.preview.update_sprite_positions();
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.heading.clear(vram);
+        self.name.clear(vram);
+        self.details.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,10 @@
+//! Things kept for as long as the console is on but never saved, unlike the profiles
+
+use crate::score::Score;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Session {
+    /// The matches each side has won since a rubber match was started from the results, if one
+    /// is going
+    pub rubber: Option<Score>,
+}
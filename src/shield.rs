@@ -0,0 +1,96 @@
+//! A shield collected from a power up. It sits on the goal line and stops one shot before it
+//! shatters, or fades away on its own if the ball never comes near it.
+
+use agb::display::object::{OamManaged, Object};
+use agb::fixnum::{Rect, Vector2D};
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::physics;
+use crate::tuning::{Fixed, SHIELD_DEPTH, SHIELD_FRAMES, SHIELD_SEGMENTS};
+use crate::view;
+
+pub struct Shield<'a> {
+    pub side: Side,
+    /// The part of the goal line the shield covers
+    rect: Rect<Fixed>,
+    sprites: Vec<Object<'a>, MatchArena>,
+    frames_left: u32,
+}
+
+impl<'a> Shield<'a> {
+    /// Puts a shield across the middle of `side`'s goal
+    pub fn new(object: &'a OamManaged<'a>, side: Side, court: &Court) -> Self {
+        let goal = court.goal(side);
+        let height = 16 * SHIELD_SEGMENTS;
+        let top = goal.centre_y() - height / 2;
+        let (sprite_x, rect_x) = match side {
+            Side::Left => (goal.x, goal.x),
+            Side::Right => (goal.x - 16, goal.x - SHIELD_DEPTH),
+        };
+
+        let sprites = arena::collect((0..SHIELD_SEGMENTS).map(|segment| {
+            let position = (sprite_x, top + 16 * segment).into();
+            let mut sprite = object.object_sprite(SPRITES.shield.sprite(0));
+            RenderLayer::PowerUps
+                .apply(&mut sprite)
+                .set_hflip(view::hflip(side == Side::Right))
+                .set_position(view::sprite_position(position, SPRITES.shield.sprite(0)))
+                .show();
+            sprite
+        }));
+
+        let position: Vector2D<i32> = (rect_x, top).into();
+        let size: Vector2D<i32> = (SHIELD_DEPTH, height).into();
+
+        Self {
+            side,
+            rect: Rect::new(position.into(), size.into()),
+            sprites,
+            frames_left: SHIELD_FRAMES.0,
+        }
+    }
+
+    /// Whether the ball is touching the shield while heading into the goal behind it
+    pub fn is_hit_by(&self, ball: &Ball) -> bool {
+        let heading_in = match self.side {
+            Side::Left => ball.entity.velocity.x < 0.into(),
+            Side::Right => ball.entity.velocity.x > 0.into(),
+        };
+
+        heading_in && physics::overlaps(ball.entity.rect(), self.rect)
+    }
+
+    /// The part of the goal line the shield covers
+    pub fn rect(&self) -> Rect<Fixed> {
+        self.rect
+    }
+
+    /// Where the shield should break apart from
+    pub fn centre(&self) -> Vector2D<i32> {
+        (self.rect.position + self.rect.size / 2).floor()
+    }
+
+    /// Counts down the shield's life, returns false once it has run out
+    pub fn update(&mut self) -> bool {
+        self.frames_left = self.frames_left.saturating_sub(1);
+        self.frames_left > 0
+    }
+
+    /// Blinks the shield for its last second so it's clear it is about to go
+    pub fn update_sprites(&mut self) {
+        let fading = self.frames_left < 60 && (self.frames_left / 4) % 2 == 0;
+        for sprite in &mut self.sprites {
+            if fading {
+                sprite.hide();
+            } else {
+                sprite.show();
+            }
+        }
+    }
+}
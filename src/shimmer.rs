@@ -0,0 +1,74 @@
+//! Heat shimmer effect that offsets the x scroll of a background by a different amount on every
+//! scanline, using a DMA transfer that fires each HBlank.
+
+use agb::display::tiled::RegularMap;
+use agb::dma::{Dma, DmaTransferHandle};
+
+use crate::tuning::{
+    Fixed, Frames, SHIMMER_AMPLITUDE, SHIMMER_FADE_FRAMES, SHIMMER_LINE_STEP, SHIMMER_SPEED,
+};
+
+/// The number of visible scanlines, the hblank transfer needs a value for each of them
+pub const SCANLINES: usize = agb::display::HEIGHT as usize;
+
+/// The x scroll for each scanline. This lives outside of the shimmer so the shimmer can keep
+/// being updated while a transfer is reading from the previous frame's offsets.
+pub type ScanlineOffsets = [i16; SCANLINES];
+
+#[derive(Default)]
+pub struct Shimmer {
+    frames_left: Frames,
+    phase: Fixed,
+}
+
+impl Shimmer {
+    /// Starts the shimmer, or keeps it going if it is already running, for at least `frames`
+    pub fn arm(&mut self, frames: Frames) {
+        self.frames_left = self.frames_left.max(frames);
+    }
+
+    /// Stops the shimmer straight away
+    pub fn disarm(&mut self) {
+        self.frames_left = Frames::ZERO;
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.frames_left > Frames::ZERO
+    }
+
+    /// Moves the wave on by a frame
+    pub fn update(&mut self) {
+        if !self.is_armed() {
+            return;
+        }
+
+        self.frames_left.tick_down();
+        self.phase += SHIMMER_SPEED;
+    }
+
+    /// Fills in `offsets` for this frame and starts the hblank transfer if the shimmer is armed.
+    /// It should be called as soon after vblank as possible and the handle kept until the next
+    /// vblank, dropping it stops the transfer.
+    pub fn start_transfer<'a>(
+        &self,
+        dma: &'a Dma,
+        background: &RegularMap,
+        offsets: &'a mut ScanlineOffsets,
+    ) -> Option<DmaTransferHandle<'a, i16>> {
+        if !self.is_armed() {
+            return None;
+        }
+
+        // The wave gets smaller over the last few frames so it doesn't snap off
+        let fade = self.frames_left.min(SHIMMER_FADE_FRAMES).0 as i32;
+        let amplitude = SHIMMER_AMPLITUDE * fade / SHIMMER_FADE_FRAMES.0 as i32;
+
+        for (line, offset) in offsets.iter_mut().enumerate() {
+            let angle = self.phase + SHIMMER_LINE_STEP * line as i32;
+            *offset = (amplitude * angle.sin()).floor() as i16;
+        }
+
+        // Safety: the offsets are borrowed for as long as the handle is alive so can't move
+        Some(unsafe { dma.hblank_transfer(&background.x_scroll_dma(), offsets) })
+    }
+}
@@ -0,0 +1,78 @@
+//! Saves the batteries when nobody is playing. A menu left alone only updates every few frames,
+//! and the title screen left alone for long enough puts the console to sleep like carts do. The
+//! screen and sound are switched off and the CPU stops until any button is pressed.
+
+use agb::input::{Button, ButtonController};
+use agb::interrupt::{self, Interrupt};
+use agb::syscall;
+
+use crate::tuning::{Frames, MENU_THROTTLE_FRAMES, MENU_THROTTLE_IDLE_FRAMES, SLEEP_IDLE_FRAMES};
+
+const DISPLAY_CONTROL: *mut u16 = 0x0400_0000 as *mut u16;
+const SOUND_CONTROL: *mut u16 = 0x0400_0084 as *mut u16;
+const KEY_CONTROL: *mut u16 = 0x0400_0132 as *mut u16;
+
+/// Draws nothing at all, the screen goes white and the LCD can stop
+const FORCED_BLANK: u16 = 1 << 7;
+/// Switches every sound channel off
+const SOUND_ENABLE: u16 = 1 << 7;
+/// Raises the key interrupt when any of the buttons is pressed
+const WAKE_ON_ANY_BUTTON: u16 = (1 << 14) | 0x3ff;
+
+/// Counts how long it has been since a button was pressed. It's made again for every scene so
+/// the count starts over each time the scene changes.
+#[derive(Default)]
+pub struct Idle {
+    frames: Frames,
+}
+
+impl Idle {
+    /// Adds on the frames since the last update, unless something is being pressed
+    pub fn update(&mut self, input: &ButtonController, frames: Frames) {
+        if input.is_pressed(Button::all()) {
+            self.frames = Frames::ZERO;
+        } else {
+            self.frames += frames;
+        }
+    }
+
+    /// How many frames to wait before the next update. Menus don't need updating every frame
+    /// once they've been left alone, the first press puts them back to every frame.
+    pub fn frames_per_update(&self, is_menu: bool) -> Frames {
+        if is_menu && self.frames >= MENU_THROTTLE_IDLE_FRAMES {
+            MENU_THROTTLE_FRAMES
+        } else {
+            Frames(1)
+        }
+    }
+
+    /// Whether nothing has been pressed for long enough on a scene that can sleep
+    pub fn wants_sleep(&self, can_sleep: bool) -> bool {
+        can_sleep && self.frames >= SLEEP_IDLE_FRAMES
+    }
+
+    /// Sleeps until a button is pressed and puts everything back the way it was
+    pub fn sleep(&mut self) {
+        self.frames = Frames::ZERO;
+
+        // Safety: the only thing the handler does is wake the CPU and it doesn't allocate
+        let _wake = unsafe { interrupt::add_interrupt_handler(Interrupt::Keypad, |_| {}) };
+
+        // Safety: these are the display, sound and keypad control registers, everything else
+        // in them is kept as it was
+        unsafe {
+            let display = DISPLAY_CONTROL.read_volatile();
+            let sound = SOUND_CONTROL.read_volatile();
+
+            DISPLAY_CONTROL.write_volatile(display | FORCED_BLANK);
+            SOUND_CONTROL.write_volatile(sound & !SOUND_ENABLE);
+            KEY_CONTROL.write_volatile(WAKE_ON_ANY_BUTTON);
+
+            syscall::stop();
+
+            KEY_CONTROL.write_volatile(0);
+            SOUND_CONTROL.write_volatile(sound);
+            DISPLAY_CONTROL.write_volatile(display);
+        }
+    }
+}
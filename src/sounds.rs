@@ -0,0 +1,146 @@
+//! Every sound the game plays, loaded from the wav files in `sfx/` in one place so it's easy to
+//! see how much of the cartridge they take up. The build fails if they add up to more than
+//! `AUDIO_ROM_BUDGET`, and how big each one is goes to the debug log when the game starts.
+//!
+//! Most are recorded at the mixer's 18157Hz. Long ones like the voices and music can be recorded
+//! at half that instead to take half the room, they're played back at half speed to make up for
+//! it.
+
+use agb::include_wav;
+
+use crate::tuning::{fixed, Fixed, AUDIO_ROM_BUDGET};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rate {
+    Full,
+    Half,
+}
+
+/// A sound as it's kept on the cartridge
+#[derive(Clone, Copy)]
+pub struct Sample {
+    pub data: &'static [u8],
+    rate: Rate,
+}
+
+impl Sample {
+    /// How fast the mixer has to play it for it to come out at the pitch it was recorded at
+    pub fn speed(self) -> Fixed {
+        match self.rate {
+            Rate::Full => fixed(1, 1),
+            Rate::Half => fixed(1, 2),
+        }
+    }
+}
+
+/// Loads a sound recorded at the mixer's rate, or at half of it
+macro_rules! full {
+    ($path:literal) => {
+        Sample {
+            data: include_wav!($path),
+            rate: Rate::Full,
+        }
+    };
+}
+macro_rules! half {
+    ($path:literal) => {
+        Sample {
+            data: include_wav!($path),
+            rate: Rate::Half,
+        }
+    };
+}
+
+/// Soft, normal and hard versions of the hits, picked by how hard the ball hit
+pub static PADDLE_HITS: [Sample; 3] = [
+    full!("sfx/paddle_hit_soft.wav"),
+    full!("sfx/paddle_hit.wav"),
+    full!("sfx/paddle_hit_hard.wav"),
+];
+pub static WALL_BOUNCES: [Sample; 3] = [
+    full!("sfx/wall_bounce_soft.wav"),
+    full!("sfx/wall_bounce.wav"),
+    full!("sfx/wall_bounce_hard.wav"),
+];
+pub static EDGE_SHOT: Sample = full!("sfx/edge_shot.wav");
+pub static GOAL: Sample = full!("sfx/goal.wav");
+pub static SPECIAL: Sample = full!("sfx/special.wav");
+pub static POWER_UP: Sample = full!("sfx/power_up.wav");
+pub static SHIELD_BREAK: Sample = full!("sfx/shield_break.wav");
+pub static VICTORY: Sample = full!("sfx/victory.wav");
+pub static RALLY: Sample = full!("sfx/rally.wav");
+pub static ACE: Sample = full!("sfx/ace.wav");
+pub static BUMPER: Sample = full!("sfx/bumper.wav");
+
+/// What the announcer says
+pub static VOICE_ACE: Sample = half!("sfx/voice_ace.wav");
+pub static VOICE_MATCH_POINT: Sample = half!("sfx/voice_match_point.wav");
+pub static VOICE_GAME: Sample = half!("sfx/voice_game.wav");
+
+/// Loops of music for behind the scenes
+pub static MUSIC_TITLE: Sample = half!("sfx/music_title.wav");
+pub static MUSIC_MATCH: Sample = half!("sfx/music_match.wav");
+pub static MUSIC_VICTORY: Sample = half!("sfx/music_victory.wav");
+
+/// Every sound by name, for adding up how much room they take
+static LEDGER: [(&str, &Sample); 21] = [
+    ("paddle hit soft", &PADDLE_HITS[0]),
+    ("paddle hit", &PADDLE_HITS[1]),
+    ("paddle hit hard", &PADDLE_HITS[2]),
+    ("wall bounce soft", &WALL_BOUNCES[0]),
+    ("wall bounce", &WALL_BOUNCES[1]),
+    ("wall bounce hard", &WALL_BOUNCES[2]),
+    ("edge shot", &EDGE_SHOT),
+    ("goal", &GOAL),
+    ("special", &SPECIAL),
+    ("power up", &POWER_UP),
+    ("shield break", &SHIELD_BREAK),
+    ("victory", &VICTORY),
+    ("rally", &RALLY),
+    ("ace", &ACE),
+    ("bumper", &BUMPER),
+    ("voice ace", &VOICE_ACE),
+    ("voice match point", &VOICE_MATCH_POINT),
+    ("voice game", &VOICE_GAME),
+    ("music title", &MUSIC_TITLE),
+    ("music match", &MUSIC_MATCH),
+    ("music victory", &MUSIC_VICTORY),
+];
+
+/// How many bytes all the sounds take on the cartridge
+const AUDIO_BYTES: usize = {
+    let mut total = 0;
+    let mut index = 0;
+    while index < LEDGER.len() {
+        total += LEDGER[index].1.data.len();
+        index += 1;
+    }
+    total
+};
+
+// The dead code lint doesn't count what an unnamed constant reads, so without this the total
+// would look unused whenever the debug log isn't built in
+#[allow(dead_code)]
+const _: () = assert!(
+    AUDIO_BYTES <= AUDIO_ROM_BUDGET,
+    "the sounds take up more of the cartridge than AUDIO_ROM_BUDGET allows"
+);
+
+/// Writes how big each sound is to the debug log, biggest first
+#[cfg(feature = "debug-tools")]
+pub fn log_usage() {
+    let mut ledger = LEDGER;
+    ledger.sort_unstable_by_key(|(_, sample)| core::cmp::Reverse(sample.data.len()));
+    for (name, sample) in ledger {
+        let rate = match sample.rate {
+            Rate::Full => "",
+            Rate::Half => " at half rate",
+        };
+        agb::println!("{:>6} bytes  {}{}", sample.data.len(), name, rate);
+    }
+    agb::println!(
+        "{:>6} bytes  of {} for sound",
+        AUDIO_BYTES,
+        AUDIO_ROM_BUDGET
+    );
+}
@@ -0,0 +1,130 @@
+//! Each player builds up energy by returning the ball. Once the meter is full they can spend it
+//! on their character's special, which lasts for a while before wearing off.
+
+use agb::display::object::{OamManaged, Object};
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::assets::SPRITES;
+use crate::character::Ability;
+use crate::court::Court;
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::tuning::{CURVEBALL_ARMED_FRAMES, ENERGY_FOR_SPECIAL, SPEED_BURST_FRAMES, WALL_FRAMES};
+use crate::view;
+
+pub struct Special<'a> {
+    object: &'a OamManaged<'a>,
+    ability: Ability,
+    side: Side,
+    energy: u32,
+    /// Frames left before the special wears off, 0 when it isn't being used
+    frames_left: u32,
+    /// The energy meter at the top of the screen
+    meter: Object<'a>,
+    /// Sprites down the edge of the screen while the wall is up
+    wall: Vec<Object<'a>, MatchArena>,
+}
+
+impl<'a> Special<'a> {
+    pub fn new(object: &'a OamManaged<'a>, ability: Ability, side: Side) -> Self {
+        let mut meter = object.object_sprite(SPRITES.meter.sprite(0));
+        let x = match side {
+            Side::Left => 4,
+            Side::Right => agb::display::WIDTH - 36,
+        };
+        RenderLayer::Hud
+            .apply(&mut meter)
+            .set_position(view::sprite_position(
+                (x, 2).into(),
+                SPRITES.meter.sprite(0),
+            ))
+            .show();
+
+        Self {
+            object,
+            ability,
+            side,
+            energy: 0,
+            frames_left: 0,
+            meter,
+            wall: Vec::new_in(MatchArena),
+        }
+    }
+
+    /// Adds energy for returning the ball. Nothing builds up while the special is running.
+    pub fn fill(&mut self) {
+        if !self.is_active() {
+            self.energy = (self.energy + 1).min(ENERGY_FOR_SPECIAL);
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.energy >= ENERGY_FOR_SPECIAL
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.frames_left > 0
+    }
+
+    /// Whether the special is running right now and is `ability`
+    pub fn is(&self, ability: Ability) -> bool {
+        self.is_active() && self.ability == ability
+    }
+
+    /// Spends a full meter on the special, returns false if there wasn't enough energy
+    pub fn trigger(&mut self, court: &Court) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+
+        self.energy = 0;
+        self.frames_left = match self.ability {
+            Ability::Curveball => CURVEBALL_ARMED_FRAMES.0,
+            Ability::Wall => WALL_FRAMES.0,
+            Ability::SpeedBurst => SPEED_BURST_FRAMES.0,
+        };
+
+        if self.ability == Ability::Wall {
+            // The wall covers the goal with a column of sprites
+            let goal = court.goal(self.side);
+            let x = match self.side {
+                Side::Left => goal.x,
+                Side::Right => goal.x - 16,
+            };
+            self.wall = arena::collect((goal.top..goal.bottom).step_by(16).map(|y| {
+                let mut sprite = self.object.object_sprite(SPRITES.wall.sprite(0));
+                RenderLayer::PowerUps
+                    .apply(&mut sprite)
+                    .set_hflip(view::hflip(self.side == Side::Right))
+                    .set_position(view::sprite_position((x, y).into(), SPRITES.wall.sprite(0)))
+                    .show();
+                sprite
+            }));
+        }
+
+        true
+    }
+
+    /// Ends the special early, a curveball is used up as soon as it is hit
+    pub fn finish(&mut self) {
+        self.frames_left = 0;
+        self.wall.clear();
+    }
+
+    pub fn update(&mut self) {
+        if self.frames_left > 0 {
+            self.frames_left -= 1;
+            if self.frames_left == 0 {
+                self.finish();
+            }
+        }
+    }
+
+    pub fn update_sprites(&mut self) {
+        let frame = self.energy as usize * (SPRITES.meter.sprites().len() - 1)
+            / ENERGY_FOR_SPECIAL as usize;
+        self.meter
+            .set_sprite(self.object.sprite(SPRITES.meter.sprite(frame)));
+    }
+}
@@ -0,0 +1,113 @@
+//! The studio splash shown when the game boots. The logo drops in and a ball bounces along
+//! under it, then it moves on to the title screen. Start skips straight there.
+
+use agb::display::object::Object;
+use agb::display::tiled::VRamManager;
+use agb::input::Button;
+
+use crate::animation::{Cue, Cued, Keyframe, Timeline, Track};
+use crate::assets::SPRITES;
+use crate::events::{GameEvent, Impact};
+use crate::scene::{Frame, Next, Resources};
+use crate::text::{self, Label};
+
+/// Scroll of the logo background. It starts scrolled so the logo is hidden below the bottom of
+/// the screen in the part of the background that's never shown, then drops in with a bounce.
+const LOGO_SCROLL: Track = Track::new(&[
+    Keyframe::new(0, 0, 96),
+    Keyframe::new(24, 0, -6),
+    Keyframe::new(30, 0, 3),
+    Keyframe::new(36, 0, 0),
+]);
+
+/// The ball comes in from the left and bounces along the bottom of the screen
+const BALL_PATH: Track = Track::new(&[
+    Keyframe::new(30, -16, 80),
+    Keyframe::new(44, 24, 128),
+    Keyframe::new(62, 76, 96),
+    Keyframe::new(80, 128, 128),
+    Keyframe::new(94, 172, 108),
+    Keyframe::new(108, 216, 128),
+    Keyframe::new(118, 240, 120),
+]);
+
+const LANDING: GameEvent = GameEvent::WallBounce {
+    impact: Impact::HARDEST,
+};
+
+/// The ball comes on screen when it starts moving, makes a sound each time it lands and goes
+/// once it's off the other side
+const BALL_CUES: &[Cued] = &[
+    Cued::new(30, Cue::ShowSprite),
+    Cued::new(44, Cue::Sound(LANDING)),
+    Cued::new(80, Cue::Sound(LANDING)),
+    Cued::new(108, Cue::Sound(LANDING)),
+    Cued::new(118, Cue::HideSprite),
+];
+
+/// How long to keep the finished logo up before moving on
+const HOLD_FRAMES: u32 = 40;
+
+fn scroll_at(frame: u32) -> (i16, i16) {
+    let scroll = LOGO_SCROLL.position_at(frame);
+    (scroll.x as i16, scroll.y as i16)
+}
+
+pub struct Splash<'a> {
+    logo: Label<'a>,
+    ball: Object<'a>,
+    timeline: Timeline,
+}
+
+impl<'a> Splash<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let mut logo = Label::new(resources.tiled, frame.vram, (10, 9));
+        logo.write(frame.vram, text::HIGHLIGHT, format_args!("fatfingers23"));
+        logo.scroll_to(frame.vram, scroll_at(0));
+
+        let mut ball = resources.object.object_sprite(SPRITES.ball.sprite(0));
+        ball.set_position(BALL_PATH.position_at(0)).hide();
+
+        Self {
+            logo,
+            ball,
+            timeline: Timeline::default(),
+        }
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        self.timeline.advance();
+        for cue in self.timeline.cues(BALL_CUES) {
+            match cue {
+                Cue::ShowSprite => {
+                    self.ball.show();
+                }
+                Cue::HideSprite => {
+                    self.ball.hide();
+                }
+                Cue::Sound(event) => frame.audio.handle_event(event),
+                Cue::Prompt(_) => {}
+            }
+        }
+
+        let finished = self.timeline.frame() >= BALL_PATH.end() + HOLD_FRAMES;
+        if finished || frame.input.is_just_pressed(Button::START) {
+            return Some(Next::Title);
+        }
+
+        None
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.ball
+            .set_position(BALL_PATH.position_at(self.timeline.frame()));
+    }
+
+    pub fn after_vblank(&mut self, vram: &mut VRamManager) {
+        self.logo.scroll_to(vram, scroll_at(self.timeline.frame()));
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.logo.clear(vram);
+    }
+}
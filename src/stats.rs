@@ -0,0 +1,51 @@
+//! Keeps a log of how a match went from its events, for the results screen to show afterwards
+
+use crate::events::GameEvent;
+use crate::paddle::Side;
+use crate::score::Score;
+use crate::tuning::{Frames, POINTS_TO_WIN};
+
+/// The most goals a match can have, every goal is worth at least a point and the loser can't
+/// have got to the points needed to win
+pub const MAX_GOALS: usize = 2 * POINTS_TO_WIN as usize - 1;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    /// Who scored each goal, in order
+    scorers: [Option<Side>; MAX_GOALS],
+    goals: usize,
+    /// Returns in the rally going on now and the most in any rally so far
+    rally: u32,
+    pub longest_rally: u32,
+    /// How many serves each side scored straight from, counted like points
+    pub aces: Score,
+    /// How long the match took, not counting the pauses after goals
+    pub frames: Frames,
+}
+
+impl Stats {
+    pub fn handle_event(&mut self, event: GameEvent) {
+        match event {
+            GameEvent::PaddleHit { .. } | GameEvent::EdgeShot { .. } => {
+                self.rally += 1;
+                self.longest_rally = self.longest_rally.max(self.rally);
+            }
+            GameEvent::Goal { scorer, ace, .. } => {
+                if let Some(slot) = self.scorers.get_mut(self.goals) {
+                    *slot = Some(scorer);
+                    self.goals += 1;
+                }
+                if ace {
+                    self.aces.add_points(scorer, 1);
+                }
+                self.rally = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Who scored each goal, in order
+    pub fn scorers(&self) -> impl Iterator<Item = Side> + '_ {
+        self.scorers[..self.goals].iter().flatten().copied()
+    }
+}
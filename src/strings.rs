@@ -0,0 +1,381 @@
+//! Every bit of text the menus and the match show, in each language. Anything new that's shown
+//! on screen goes in the table here rather than being written where it's drawn.
+
+/// The languages the text can be shown in, picked from the options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::Spanish,
+        Language::French,
+        Language::German,
+    ];
+
+    /// Each language is named in itself so it can be found whatever is picked now
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "english",
+            Language::Spanish => "español",
+            Language::French => "français",
+            Language::German => "deutsch",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Text {
+    PressStart,
+    ChooseYourPaddle,
+    Speed,
+    Size,
+    Special,
+    Difficulty,
+    Easy,
+    Normal,
+    Hard,
+    Fog,
+    ShrinkingCourt,
+    LopsidedCourt,
+    Portals,
+    Bumpers,
+    Elevator,
+    ShrinkingPaddles,
+    Hints,
+    MirrorCourt,
+    ReducedMotion,
+    CrtOverlay,
+    Music,
+    Language,
+    On,
+    Off,
+    CurveballReturn,
+    TemporaryWall,
+    SpeedBurst,
+    Replay,
+    YouWin,
+    CpuWins,
+    LongestRally,
+    QuickestWin,
+    Aces,
+    RubberMatch,
+    ResultsHelp,
+    You,
+    Cpu,
+    Versus,
+    Record,
+    Records,
+    NoRecords,
+    Profile,
+    Name,
+    EnterName,
+    DailyChallenge,
+    Password,
+    EnterPassword,
+    WrongPassword,
+    PasswordAccepted,
+    NotSaved,
+    KeptItUp,
+    TitleHelp,
+    TutorialMove,
+    TutorialServe,
+    TutorialAngles,
+    TutorialSmash,
+    TutorialDone,
+    // Only shown by the bonus modes, kept last so the rows before them stay where they are
+    #[cfg(feature = "bonus-modes")]
+    RandomChallenge,
+    #[cfg(feature = "bonus-modes")]
+    Best,
+    #[cfg(feature = "bonus-modes")]
+    Extras,
+    #[cfg(feature = "bonus-modes")]
+    Colours,
+    #[cfg(feature = "bonus-modes")]
+    Ball,
+    #[cfg(feature = "bonus-modes")]
+    Court,
+    #[cfg(feature = "bonus-modes")]
+    Cheat,
+    #[cfg(feature = "bonus-modes")]
+    Classic,
+    #[cfg(feature = "bonus-modes")]
+    Mint,
+    #[cfg(feature = "bonus-modes")]
+    Rose,
+    #[cfg(feature = "bonus-modes")]
+    Swirl,
+    #[cfg(feature = "bonus-modes")]
+    Shard,
+    #[cfg(feature = "bonus-modes")]
+    Plain,
+    #[cfg(feature = "bonus-modes")]
+    Rainy,
+    #[cfg(feature = "bonus-modes")]
+    Snowy,
+    #[cfg(feature = "bonus-modes")]
+    DoublePoints,
+    #[cfg(feature = "bonus-modes")]
+    WinAMatch,
+    #[cfg(feature = "bonus-modes")]
+    KeepALongRally,
+    #[cfg(feature = "bonus-modes")]
+    WinFiveNil,
+    #[cfg(feature = "bonus-modes")]
+    BallSkins,
+    #[cfg(feature = "bonus-modes")]
+    Picked,
+    #[cfg(feature = "bonus-modes")]
+    PressAToPick,
+}
+
+/// A row for each `Text` in the same order, with a column for each `Language`
+const STRINGS: [[&str; 4]; 79] = [
+    [
+        "press start",
+        "pulsa start",
+        "appuie sur start",
+        "start drücken",
+    ],
+    [
+        "choose your paddle",
+        "elige tu pala",
+        "choisis ta raquette",
+        "wähle deinen schläger",
+    ],
+    ["speed", "velocidad", "vitesse", "tempo"],
+    ["size", "tamaño", "taille", "größe"],
+    ["special", "especial", "spécial", "spezial"],
+    ["difficulty", "dificultad", "difficulté", "schwierigkeit"],
+    ["easy", "fácil", "facile", "leicht"],
+    ["normal", "normal", "normal", "normal"],
+    ["hard", "difícil", "difficile", "schwer"],
+    ["fog", "niebla", "brouillard", "nebel"],
+    [
+        "shrinking court",
+        "pista menguante",
+        "terrain rétréci",
+        "schrumpffeld",
+    ],
+    [
+        "lopsided court",
+        "pista desigual",
+        "terrain déséquilibré",
+        "schiefes feld",
+    ],
+    ["portals", "portales", "portails", "portale"],
+    ["bumpers", "rebotadores", "bumpers", "bumper"],
+    ["elevator", "ascensor", "ascenseur", "aufzug"],
+    [
+        "shrinking paddles",
+        "palas menguantes",
+        "raquettes qui rétrécissent",
+        "schrumpfschläger",
+    ],
+    ["hints", "pistas", "conseils", "tipps"],
+    [
+        "mirror court",
+        "pista espejo",
+        "terrain miroir",
+        "spiegelfeld",
+    ],
+    [
+        "reduced motion",
+        "menos movimiento",
+        "mouvement réduit",
+        "weniger bewegung",
+    ],
+    ["crt filter", "filtro crt", "filtre crt", "crt-filter"],
+    ["music", "música", "musique", "musik"],
+    ["language", "idioma", "langue", "sprache"],
+    ["on", "sí", "oui", "an"],
+    ["off", "no", "non", "aus"],
+    [
+        "curveball return",
+        "golpe con efecto",
+        "retour à effet",
+        "effetball",
+    ],
+    [
+        "temporary wall",
+        "muro temporal",
+        "mur temporaire",
+        "zeitweise mauer",
+    ],
+    ["speed burst", "acelerón", "accélération", "sprint"],
+    ["REPLAY", "REPETICIÓN", "RALENTI", "WIEDERHOLUNG"],
+    ["you win!", "ganaste!", "victoire!", "gewonnen!"],
+    [
+        "the cpu wins",
+        "gana la cpu",
+        "le cpu gagne",
+        "die cpu gewinnt",
+    ],
+    [
+        "longest rally",
+        "peloteo más largo",
+        "plus long échange",
+        "längster ballwechsel",
+    ],
+    [
+        "quickest win",
+        "victoria más rápida",
+        "victoire la plus rapide",
+        "schnellster sieg",
+    ],
+    ["aces", "aces", "aces", "asse"],
+    ["rubber", "desempate", "belle", "entscheidung"],
+    [
+        "a: rematch  r: rubber match  start: quit",
+        "a: revancha  r: desempate  start: salir",
+        "a: revanche  r: belle  start: quitter",
+        "a: revanche  r: entscheidung  start: ende",
+    ],
+    ["you", "tú", "toi", "du"],
+    ["cpu", "cpu", "cpu", "cpu"],
+    ["VS", "VS", "VS", "VS"],
+    ["record", "historial", "bilan", "bilanz"],
+    ["records", "récords", "records", "rekorde"],
+    [
+        "nothing yet",
+        "nada aún",
+        "rien pour l'instant",
+        "noch nichts",
+    ],
+    ["profile", "perfil", "profil", "profil"],
+    ["name", "nombre", "nom", "name"],
+    [
+        "enter a name",
+        "escribe un nombre",
+        "entre un nom",
+        "namen eingeben",
+    ],
+    [
+        "daily challenge",
+        "reto diario",
+        "défi du jour",
+        "tagesaufgabe",
+    ],
+    ["password", "contraseña", "mot de passe", "passwort"],
+    [
+        "enter a password",
+        "escribe una contraseña",
+        "entre un mot de passe",
+        "passwort eingeben",
+    ],
+    [
+        "that password is wrong",
+        "contraseña incorrecta",
+        "mot de passe faux",
+        "falsches passwort",
+    ],
+    [
+        "progress restored!",
+        "¡progreso recuperado!",
+        "progrès récupéré!",
+        "fortschritt geladen!",
+    ],
+    [
+        "(not saved)",
+        "(sin guardar)",
+        "(non sauvé)",
+        "(nicht gespeichert)",
+    ],
+    ["kept it up", "sin caer", "toujours en l'air", "in der luft"],
+    [
+        "l: tutorial  r: password\nsel: credits  b: records",
+        "l: tutorial  r: contraseña\nsel: créditos  b: récords",
+        "l: tutoriel  r: mot de passe\nsel: crédits  b: records",
+        "l: anleitung  r: passwort\nsel: abspann  b: rekorde",
+    ],
+    [
+        "press up and down to move",
+        "pulsa arriba y abajo para moverte",
+        "haut et bas pour te déplacer",
+        "hoch und runter zum bewegen",
+    ],
+    [
+        "press a to serve",
+        "pulsa a para sacar",
+        "appuie sur a pour servir",
+        "a drücken zum aufschlagen",
+    ],
+    [
+        "hit it with an end to angle it",
+        "golpea con un extremo para dar ángulo",
+        "frappe avec un bout pour l'angle",
+        "mit einem ende schräg zurückschlagen",
+    ],
+    [
+        "clip it with a corner to smash",
+        "roza con una esquina para rematar",
+        "touche avec un coin pour smasher",
+        "mit der ecke schmettern",
+    ],
+    [
+        "you're ready!",
+        "¡ya estás listo!",
+        "tu es prêt!",
+        "du bist bereit!",
+    ],
+    [
+        "random challenge",
+        "reto al azar",
+        "défi au hasard",
+        "zufallsaufgabe",
+    ],
+    ["best", "récord", "record", "rekord"],
+    ["extras", "extras", "bonus", "extras"],
+    ["colours", "colores", "couleurs", "farben"],
+    ["ball", "pelota", "balle", "ball"],
+    ["court", "pista", "terrain", "platz"],
+    ["cheat", "truco", "triche", "cheat"],
+    ["classic", "clásico", "classique", "klassisch"],
+    ["mint", "menta", "menthe", "minze"],
+    ["rose", "rosa", "rose", "rosa"],
+    ["swirl", "remolino", "tourbillon", "wirbel"],
+    ["shard", "esquirla", "éclat", "splitter"],
+    ["plain", "liso", "uni", "schlicht"],
+    ["rainy", "lluvioso", "pluvieux", "regnerisch"],
+    ["snowy", "nevado", "enneigé", "verschneit"],
+    [
+        "double points",
+        "puntos dobles",
+        "points doublés",
+        "doppelte punkte",
+    ],
+    [
+        "win a match",
+        "gana un partido",
+        "gagne un match",
+        "gewinne ein spiel",
+    ],
+    [
+        "rally 20 returns",
+        "peloteo de 20",
+        "échange de 20",
+        "20er ballwechsel",
+    ],
+    ["win 5 - 0", "gana 5 - 0", "gagne 5 - 0", "gewinne 5 - 0"],
+    ["ball skins", "pelotas", "balles", "bälle"],
+    ["in use", "en uso", "utilisée", "in benutzung"],
+    [
+        "a: use this ball",
+        "a: usar esta pelota",
+        "a: prendre cette balle",
+        "a: diesen ball nehmen",
+    ],
+];
+
+impl Text {
+    pub fn get(self, language: Language) -> &'static str {
+        STRINGS[self as usize][language as usize]
+    }
+}
@@ -0,0 +1,53 @@
+//! Little things the computer's paddle does so it feels like someone is playing it. It bobs
+//! up and down while it waits between points and flashes to show off when it scores.
+
+use crate::events::GameEvent;
+use crate::paddle::Side;
+use crate::tuning::{IDLE_BOB_FRAMES, TAUNT_FRAMES};
+
+/// How far the sprites are moved up and down through one bob
+const BOB: [i32; 8] = [0, -1, -2, -2, -1, 0, 1, 1];
+
+pub struct Taunt {
+    /// The side being played by the computer
+    side: Side,
+    /// Frames left of flashing after scoring
+    flash_frames_left: u32,
+    /// Counts up while idle to drive the bob
+    idle_frame: u32,
+}
+
+impl Taunt {
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            flash_frames_left: 0,
+            idle_frame: 0,
+        }
+    }
+
+    pub fn handle_event(&mut self, event: GameEvent) {
+        if let GameEvent::Goal { scorer, .. } = event {
+            if scorer == self.side {
+                self.flash_frames_left = TAUNT_FRAMES.0;
+            }
+        }
+    }
+
+    /// Moves the animations on, `idle` is whether the paddle is waiting between points
+    pub fn update(&mut self, idle: bool) {
+        self.flash_frames_left = self.flash_frames_left.saturating_sub(1);
+        self.idle_frame = if idle { self.idle_frame + 1 } else { 0 };
+    }
+
+    /// Whether the paddle should be drawn with its bright frame this frame
+    pub fn is_flashing(&self) -> bool {
+        self.flash_frames_left > 0 && (self.flash_frames_left / 6) % 2 == 0
+    }
+
+    /// How far up or down to draw the paddle, it only bobs while idle
+    pub fn bob(&self) -> i32 {
+        let step = IDLE_BOB_FRAMES.0 / BOB.len() as u32;
+        BOB[(self.idle_frame / step) as usize % BOB.len()]
+    }
+}
@@ -0,0 +1,102 @@
+//! Text drawn onto backgrounds with the pixel font that ships with agb
+
+use core::fmt::{Arguments, Write};
+
+use agb::display::font::TextRenderer;
+use agb::display::tiled::{BackgroundID, MapLoan, RegularMap, Tiled0, TiledMap, VRamManager};
+use agb::display::Font;
+use agb::include_font;
+
+use crate::background;
+use crate::colour::rgb15;
+
+pub static FONT: Font = include_font!("fnt/ark-pixel-10px-proportional-latin.ttf", 10);
+
+/// Palette index text is drawn in
+pub const TEXT: u8 = 15;
+/// Palette index for text that should stand out
+pub const HIGHLIGHT: u8 = 14;
+/// Palette index for text that's greyed out, like things that haven't been unlocked
+pub const LOCKED: u8 = 13;
+/// Palette index behind the text, 0 leaves it see through
+pub const CLEAR: u8 = 0;
+
+/// The colours the text can be drawn in, picked from the extras once they're unlocked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Palette {
+    #[default]
+    Classic,
+    Mint,
+    Rose,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [Palette::Classic, Palette::Mint, Palette::Rose];
+
+    /// The colour of normal text and then highlighted text
+    fn colours(self) -> (u32, u32) {
+        match self {
+            Palette::Classic => (0xc0cbdc, 0xfee761),
+            Palette::Mint => (0xc7f0d8, 0x63c74d),
+            Palette::Rose => (0xf6d6e0, 0xe43b44),
+        }
+    }
+}
+
+/// Text tiles always use the first background palette, so the text colours are put at the end
+/// of it where the court graphics don't reach. Must be called after the court palettes are set.
+pub fn set_palette(vram: &mut VRamManager, palette: Palette) {
+    let (text, highlight) = palette.colours();
+    vram.set_background_palette_colour(0, TEXT as usize, rgb15(text));
+    vram.set_background_palette_colour(0, HIGHLIGHT as usize, rgb15(highlight));
+    vram.set_background_palette_colour(0, LOCKED as usize, rgb15(0x5a6988));
+}
+
+/// Text on a background of its own, so it can be rewritten, moved or hidden without touching
+/// anything else on screen
+pub struct Label<'a> {
+    map: MapLoan<'a, RegularMap>,
+    renderer: TextRenderer<'static>,
+}
+
+impl<'a> Label<'a> {
+    /// Creates an empty label whose text starts at `tile_pos`
+    pub fn new(tiled: &'a Tiled0, vram: &mut VRamManager, tile_pos: (u16, u16)) -> Self {
+        Self {
+            map: background::text(tiled, vram),
+            renderer: FONT.render_text(tile_pos),
+        }
+    }
+
+    /// Replaces whatever the label says with `text`
+    pub fn write(&mut self, vram: &mut VRamManager, colour: u8, text: Arguments) {
+        self.clear(vram);
+
+        let _ = self
+            .renderer
+            .writer(colour, CLEAR, &mut self.map, vram)
+            .write_fmt(text);
+        self.renderer.commit(&mut self.map, vram);
+        self.map.commit(vram);
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.map.set_visible(visible);
+    }
+
+    pub fn background(&self) -> BackgroundID {
+        self.map.background()
+    }
+
+    /// Scrolls the label's background, moving the text the opposite way
+    pub fn scroll_to(&mut self, vram: &mut VRamManager, scroll: (i16, i16)) {
+        self.map.set_scroll_pos(scroll);
+        self.map.commit(vram);
+    }
+
+    /// Removes the text and frees its tiles, must be done before the label is dropped
+    pub fn clear(&mut self, vram: &mut VRamManager) {
+        self.map.clear(vram);
+        self.renderer.clear(vram);
+    }
+}
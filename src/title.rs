@@ -0,0 +1,91 @@
+//! The title screen, waits for the player to press start before the match begins. Select shows
+//! the credits instead, L teaches how to play, R types in a password and B shows the records.
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::background::{self, Backdrop};
+use crate::juggle::Juggle;
+use crate::marquee::{Marquee, TextEffects};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+
+/// How many frames the prompt stays on and then off for while it blinks
+pub const BLINK_FRAMES: u32 = 32;
+
+pub struct Title<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    name: Label<'a>,
+    /// Says what the other buttons do
+    help: Label<'a>,
+    prompt: Marquee<'a>,
+    juggle: Juggle<'a>,
+}
+
+impl<'a> Title<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let backdrop = background::backdrop(resources.tiled, frame.vram, Backdrop::Title);
+
+        let mut name = Label::new(resources.tiled, frame.vram, (12, 6));
+        name.write(frame.vram, text::HIGHLIGHT, format_args!("agb pong"));
+
+        let mut help = Label::new(resources.tiled, frame.vram, (2, 1));
+        let help_text = Text::TitleHelp.get(frame.options.language);
+        help.write(frame.vram, text::TEXT, format_args!("{help_text}"));
+
+        let prompt = Marquee::new(
+            resources.object,
+            Text::PressStart.get(frame.options.language),
+            96,
+            TextEffects {
+                bounce: true,
+                colour_cycle: true,
+                ..Default::default()
+            },
+        );
+
+        Self {
+            backdrop,
+            name,
+            help,
+            prompt,
+            juggle: Juggle::new(resources.object, resources.tiled, frame.vram),
+        }
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::START) {
+            return Some(Next::Select(None));
+        }
+        if frame.input.is_just_pressed(Button::SELECT) {
+            return Some(Next::Credits);
+        }
+        if frame.input.is_just_pressed(Button::L) {
+            return Some(Next::Tutorial);
+        }
+        if frame.input.is_just_pressed(Button::R) {
+            return Some(Next::Password);
+        }
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Leaderboards);
+        }
+
+        self.juggle
+            .update(frame.input, frame.vram, frame.options.language);
+
+        None
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.prompt.update_sprites();
+        self.juggle.update_sprites();
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.name.clear(vram);
+        self.help.clear(vram);
+        self.juggle.leave(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,62 @@
+//! Every button the player held through the match along with the seed it started from. Holding
+//! L and R and pressing select prints it to mGBA's log as hex, so a physics glitch can be
+//! attached to a bug report and played back.
+
+use core::fmt::Write;
+
+use agb::input::{Button, ButtonController};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::arena::MatchArena;
+
+/// How many runs of buttons go on each line of the dump
+const RUNS_PER_LINE: usize = 8;
+
+pub struct InputTrace {
+    seed: [u32; 4],
+    /// The buttons held and for how many frames in a row
+    runs: Vec<(u16, u16), MatchArena>,
+}
+
+impl InputTrace {
+    pub fn new(seed: [u32; 4]) -> Self {
+        Self {
+            seed,
+            runs: Vec::new_in(MatchArena),
+        }
+    }
+
+    pub fn record(&mut self, input: &ButtonController) {
+        let buttons = Button::all()
+            .iter()
+            .filter(|&button| input.is_pressed(button))
+            .fold(0, |held, button| held | button.bits()) as u16;
+
+        match self.runs.last_mut() {
+            Some((held, frames)) if *held == buttons && *frames < u16::MAX => *frames += 1,
+            _ => self.runs.push((buttons, 1)),
+        }
+    }
+
+    pub fn wants_dump(input: &ButtonController) -> bool {
+        input.is_pressed(Button::L)
+            && input.is_pressed(Button::R)
+            && input.is_just_pressed(Button::SELECT)
+    }
+
+    /// Prints the seed and then each run as `buttons x frames`
+    pub fn dump(&self) {
+        let [a, b, c, d] = self.seed;
+        agb::println!("input trace seed {:08x}{:08x}{:08x}{:08x}", a, b, c, d);
+
+        for runs in self.runs.chunks(RUNS_PER_LINE) {
+            let mut line = String::new();
+            for (buttons, frames) in runs {
+                let _ = write!(line, "{:04x}x{:04x} ", buttons, frames);
+            }
+            agb::println!("{}", line);
+        }
+        agb::println!("input trace end, {} runs", self.runs.len());
+    }
+}
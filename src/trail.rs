@@ -0,0 +1,89 @@
+//! Dots left behind the ball showing where it's been, in the colour of whoever last hit it so
+//! it's easy to see whose shot it is. Each dot remembers who had the ball when it was dropped,
+//! so the colour changes along the trail from the paddle onwards rather than all at once.
+
+use agb::display::object::{OamManaged, Tag};
+use agb::fixnum::Vector2D;
+use alloc::collections::VecDeque;
+
+use crate::arena::MatchArena;
+use crate::assets::SPRITES;
+use crate::ball::Ball;
+use crate::layering::RenderLayer;
+use crate::paddle::Side;
+use crate::pool::SpritePool;
+use crate::tuning::{Fixed, Frames, TRAIL_LENGTH, TRAIL_SKEW, TRAIL_SPACING};
+
+/// A dot of the trail and who had the ball when it was left there
+struct Dot {
+    position: Vector2D<i32>,
+    possession: Option<Side>,
+    /// Which way the dot leans as it gets older, away from where a spinning ball is curving
+    skew: Vector2D<Fixed>,
+}
+
+pub struct Trail<'a> {
+    pool: SpritePool<'a>,
+    /// Newest first
+    dots: VecDeque<Dot, MatchArena>,
+    frame: Frames,
+}
+
+impl<'a> Trail<'a> {
+    pub fn new(object: &'a OamManaged<'a>) -> Self {
+        Self {
+            pool: SpritePool::new(
+                object,
+                SPRITES.trail.sprite(0),
+                TRAIL_LENGTH,
+                RenderLayer::Particles,
+            ),
+            dots: VecDeque::new_in(MatchArena),
+            frame: Frames::ZERO,
+        }
+    }
+
+    /// Drops another dot where the ball is every few frames, losing the oldest
+    pub fn update(&mut self, ball: &Ball) {
+        self.frame.tick();
+        if self.frame % TRAIL_SPACING != Frames::ZERO {
+            return;
+        }
+        if self.dots.len() == TRAIL_LENGTH {
+            self.dots.pop_back();
+        }
+        self.dots.push_front(Dot {
+            position: ball.entity.position.floor(),
+            possession: ball.last_hit_by,
+            skew: -ball.spin_acceleration() * TRAIL_SKEW,
+        });
+    }
+
+    /// Takes the whole trail away, for when the ball is picked up to serve
+    pub fn clear(&mut self) {
+        self.dots.clear();
+    }
+
+    pub fn update_sprites(&mut self) {
+        let frames = SPRITES.trail.sprites().len();
+        self.pool
+            .place_sprites(self.dots.iter().enumerate().map(|(age, dot)| {
+                // Older dots are drawn smaller
+                let frame = age * frames / TRAIL_LENGTH;
+                let lean = (dot.skew * age as i32).floor();
+                (dot.position + lean, tag(dot.possession).sprite(frame))
+            }));
+    }
+
+    pub fn hide(&mut self) {
+        self.pool.hide();
+    }
+}
+
+fn tag(possession: Option<Side>) -> &'static Tag {
+    match possession {
+        None => SPRITES.trail,
+        Some(Side::Left) => SPRITES.trail_left,
+        Some(Side::Right) => SPRITES.trail_right,
+    }
+}
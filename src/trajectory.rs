@@ -0,0 +1,77 @@
+//! Works out where the ball is going by stepping it along the same way it moves each frame,
+//! bouncing off the walls and speeding up or slowing down in each half on the way. Curves and
+//! paddles aren't taken into account, so it's only right until something else touches the ball.
+
+use agb::fixnum::Vector2D;
+
+use crate::court::Court;
+use crate::entity::Entity;
+use crate::physics;
+use crate::tuning::{Fixed, Frames};
+
+/// Where the ball will be on each of the frames to come
+pub struct Trajectory<'c> {
+    court: &'c Court,
+    position: Vector2D<Fixed>,
+    velocity: Vector2D<Fixed>,
+    size: Vector2D<i32>,
+}
+
+impl<'c> Trajectory<'c> {
+    /// The path of something `size` big starting at `position` and moving with `velocity`
+    pub fn new(
+        position: Vector2D<Fixed>,
+        velocity: Vector2D<Fixed>,
+        size: Vector2D<i32>,
+        court: &'c Court,
+    ) -> Self {
+        Self {
+            court,
+            position,
+            velocity,
+            size,
+        }
+    }
+
+    /// The path `ball` is on right now
+    pub fn of(ball: &Entity, court: &'c Court) -> Self {
+        Self::new(ball.position, ball.velocity, ball.size(), court)
+    }
+}
+
+impl Iterator for Trajectory<'_> {
+    type Item = Vector2D<Fixed>;
+
+    #[link_section = ".iwram.trajectory"]
+    #[instruction_set(arm::a32)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let court = self.court;
+        self.velocity = court.half_court_velocity(self.position, self.velocity);
+        let position = physics::integrate(self.position, self.velocity, Frames(1));
+        self.position = physics::clamp_to_court(position, court, self.size);
+
+        if self.position.y == court.top() || self.position.y == court.bottom() - self.size.y {
+            self.velocity = physics::reflect(self.velocity, (0, 1).into());
+        }
+
+        Some(self.position)
+    }
+}
+
+/// The y the ball will be at when its left edge first gets to `plane_x` heading left, looking
+/// at most `frames` ahead. `None` if it's going the other way or won't get there in time.
+pub fn crossing_left(
+    ball: &Entity,
+    court: &Court,
+    plane_x: Fixed,
+    frames: Frames,
+) -> Option<Fixed> {
+    if ball.velocity.x >= 0.into() {
+        return None;
+    }
+
+    Trajectory::of(ball, court)
+        .take(frames.0 as usize)
+        .find(|position| position.x <= plane_x)
+        .map(|position| position.y)
+}
@@ -0,0 +1,647 @@
+//! Numbers that control how the game feels. Keeping them here means tweaking the
+//! gameplay doesn't require hunting through the physics code.
+
+use core::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use agb::fixnum::{Num, Vector2D};
+
+/// Fixed point number used for positions and velocities, 8 bits of fraction is plenty
+/// for sub pixel movement on a 240x160 screen
+pub type Fixed = Num<i32, 8>;
+
+/// A speed, how far something moves each frame. It's a type of its own so a speed can't be
+/// used as a distance or a plain fraction by mistake, `.0` is the number for the maths once
+/// it's the speed that's wanted. Velocities stay as `Vector2D<Fixed>`, agb's vectors only hold
+/// its own number types.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PixelsPerFrame(pub Fixed);
+
+impl PixelsPerFrame {
+    #[cfg(feature = "debug-tools")]
+    pub const ZERO: PixelsPerFrame = PixelsPerFrame(fixed(0, 1));
+}
+
+impl Add for PixelsPerFrame {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+/// Scaling a speed, by a fraction or a whole number
+impl<T> Mul<T> for PixelsPerFrame
+where
+    Fixed: Mul<T, Output = Fixed>,
+{
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+/// How long something lasts, counted in frames at 60 a second. Like `PixelsPerFrame` it's its
+/// own type so it isn't mixed up with other counts, `.0` is the number of frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frames(pub u32);
+
+impl Frames {
+    pub const ZERO: Frames = Frames(0);
+
+    /// Counts down a frame, stopping at zero
+    pub fn tick_down(&mut self) {
+        self.0 = self.0.saturating_sub(1);
+    }
+
+    /// Counts on a frame
+    pub fn tick(&mut self) {
+        self.0 += 1;
+    }
+}
+
+impl Add for Frames {
+    type Output = Frames;
+    fn add(self, rhs: Frames) -> Frames {
+        Frames(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Frames {
+    fn add_assign(&mut self, rhs: Frames) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Frames {
+    type Output = Frames;
+    fn sub(self, rhs: Frames) -> Frames {
+        Frames(self.0 - rhs.0)
+    }
+}
+
+impl Mul<u32> for Frames {
+    type Output = Frames;
+    fn mul(self, rhs: u32) -> Frames {
+        Frames(self.0 * rhs)
+    }
+}
+
+/// A share of a length, rounded down
+impl Div<u32> for Frames {
+    type Output = Frames;
+    fn div(self, rhs: u32) -> Frames {
+        Frames(self.0 / rhs)
+    }
+}
+
+/// How many times one length fits into another
+impl Div for Frames {
+    type Output = u32;
+    fn div(self, rhs: Frames) -> u32 {
+        self.0 / rhs.0
+    }
+}
+
+impl Rem for Frames {
+    type Output = Frames;
+    fn rem(self, rhs: Frames) -> Frames {
+        Frames(self.0 % rhs.0)
+    }
+}
+
+/// Builds a fixed point number from a fraction, so constants can be written as `fixed(3, 2)`
+/// for 1.5 since `num!` can't be used in a const context
+pub const fn fixed(numerator: i32, denominator: i32) -> Fixed {
+    Fixed::from_raw((numerator << 8) / denominator)
+}
+
+/// How the ball leaves a paddle segment
+#[derive(Clone, Copy)]
+pub struct SegmentBounce {
+    /// The ball velocity after the bounce. x is the speed away from the paddle and y is
+    /// the vertical speed, negative being up the screen
+    pub velocity: Vector2D<Fixed>,
+    /// Keeps the vertical direction the ball came in with instead of forcing the sign of `velocity.y`
+    pub keep_vertical_direction: bool,
+}
+
+/// Bounce for each paddle segment from top to bottom. The ends act like the curved edge
+/// of the paddle sending the ball away at a steep angle, the middle returns it flatter.
+pub const PADDLE_SEGMENT_BOUNCES: [SegmentBounce; 3] = [
+    SegmentBounce {
+        velocity: Vector2D::new(fixed(5, 4), fixed(-5, 4)),
+        keep_vertical_direction: false,
+    },
+    SegmentBounce {
+        velocity: Vector2D::new(fixed(3, 2), fixed(1, 2)),
+        keep_vertical_direction: true,
+    },
+    SegmentBounce {
+        velocity: Vector2D::new(fixed(5, 4), fixed(5, 4)),
+        keep_vertical_direction: false,
+    },
+];
+
+/// How much of the ball and of each paddle segment collides, in pixels
+pub const BALL_COLLISION_MASK: (u16, u16) = (16, 16);
+pub const PADDLE_COLLISION_MASK: (u16, u16) = (14, 14);
+
+/// How far a paddle's sprite sits from the edge of the court it is guarding
+pub const PADDLE_GOAL_GAP: i32 = 1;
+
+/// Where the top of each paddle starts on the screen
+pub const PADDLE_START_Y: i32 = 34;
+
+/// Velocity of an edge shot, when the ball clips the very corner of a paddle. It is sent
+/// back faster than any normal return and away from the corner it hit.
+pub const EDGE_SHOT_VELOCITY: Vector2D<Fixed> = Vector2D::new(fixed(9, 4), fixed(7, 4));
+
+/// A ball coming in slower than this picks up some of the paddle's movement when it's returned
+pub const MOMENTUM_SLOW_BALL_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(2, 1));
+
+/// How much of the paddle's speed is passed on to a slow ball, and the most that can add to it
+pub const PADDLE_MOMENTUM_TRANSFER: Fixed = fixed(1, 2);
+pub const PADDLE_MOMENTUM_MAX: PixelsPerFrame = PixelsPerFrame(fixed(3, 4));
+
+/// The slowest the ball is allowed to move towards a paddle. Without this the ball can end up
+/// bouncing almost vertically between the top and bottom of the screen forever.
+pub const MINIMUM_HORIZONTAL_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(3, 4));
+
+/// How much faster each return in a rally sends the ball, as a fraction of the bounce speed, and
+/// the most returns that count towards it. None by default, it's there to try from the console.
+pub const BALL_SPEED_RAMP: Fixed = fixed(0, 1);
+pub const BALL_SPEED_RAMP_RETURNS: u32 = 10;
+
+/// How much a paddle's speed can change in a frame. High enough by default that it gets to any
+/// speed straight away, lower makes the paddles feel heavier.
+pub const PADDLE_ACCELERATION: PixelsPerFrame = PixelsPerFrame(fixed(8, 1));
+
+/// Whether the ball loses vertical speed when it keeps bouncing off the top and bottom of the
+/// screen without a paddle touching it
+pub const DAMPEN_REPEATED_WALL_BOUNCES: bool = true;
+
+/// Wall bounces allowed after a paddle hit before dampening kicks in
+pub const WALL_BOUNCES_BEFORE_DAMPENING: u32 = 2;
+
+/// How much of its vertical speed the ball keeps on each dampened wall bounce
+pub const WALL_BOUNCE_DAMPENING: Fixed = fixed(7, 8);
+
+/// How long the ball can overlap a paddle before the watchdog decides it is stuck and re-serves
+pub const MAX_FRAMES_INSIDE_PADDLE: Frames = Frames(30);
+
+/// How long the background shimmers for after a smash hit like an edge shot
+pub const SMASH_SHIMMER_FRAMES: Frames = Frames(45);
+
+/// Furthest in pixels a scanline gets pushed sideways by the shimmer
+pub const SHIMMER_AMPLITUDE: Fixed = fixed(3, 1);
+
+/// How far the wave travels each frame, in full turns of the sine wave
+pub const SHIMMER_SPEED: Fixed = fixed(1, 32);
+
+/// How much of a turn of the sine wave each scanline moves on from the one above
+pub const SHIMMER_LINE_STEP: Fixed = fixed(1, 40);
+
+/// Frames at the end of the shimmer over which the wave shrinks away to nothing
+pub const SHIMMER_FADE_FRAMES: Frames = Frames(15);
+
+/// How strongly the net is drawn when it is blended over the court and the ball, out of 1
+pub const NET_WEIGHT: Num<u8, 4> = Num::from_raw(8);
+
+/// How much of the court and ball show through the net, out of 1
+pub const NET_BENEATH_WEIGHT: Num<u8, 4> = Num::from_raw(10);
+
+/// How long the ball spins for after a smash return
+pub const SMASH_SPIN_FRAMES: Frames = Frames(40);
+
+/// The ball always spins when it's going at least this fast
+pub const HIGH_SPEED_THRESHOLD: PixelsPerFrame = PixelsPerFrame(fixed(5, 2));
+
+/// How far the spinning ball turns each frame, in full turns
+pub const BALL_SPIN_SPEED: Fixed = fixed(1, 16);
+
+/// How much bigger the ball is drawn while it is spinning
+pub const SPINNING_BALL_SCALE: Fixed = fixed(5, 4);
+
+/// How long the "+1" popup floats up for after a goal
+pub const SCORE_POPUP_FRAMES: Frames = Frames(30);
+
+/// How the "+1" popup drifts each frame
+pub const SCORE_POPUP_VELOCITY: Vector2D<Fixed> = Vector2D::new(fixed(0, 1), fixed(-1, 2));
+
+/// How far in from the edge of the screen the "+1" popup starts
+pub const SCORE_POPUP_INSET: i32 = 4;
+
+/// Points needed to win a match
+pub const POINTS_TO_WIN: u32 = 5;
+
+/// How long the celebration runs after the winning point before the results come up
+pub const CELEBRATION_FRAMES: Frames = Frames(180);
+
+/// Frames between each step of the final score counting up
+pub const SCORE_COUNT_FRAMES: Frames = Frames(12);
+
+/// How high the winning paddle jumps during the celebration, in pixels
+pub const WINNER_HOP_HEIGHT: i32 = 12;
+
+/// How long each jump of the winning paddle takes
+pub const WINNER_HOP_FRAMES: Frames = Frames(24);
+
+/// Frames between each piece of confetti being dropped
+pub const CONFETTI_INTERVAL: Frames = Frames(3);
+
+/// How long confetti is dropped for after a match is won
+pub const CONFETTI_FRAMES: Frames = Frames(120);
+
+/// How fast confetti falls, it also drifts a random amount sideways
+pub const CONFETTI_FALL_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(1, 1));
+
+/// How long a piece of confetti lasts, long enough to fall off the bottom of the screen
+pub const CONFETTI_LIFETIME: Frames = Frames(180);
+
+/// Returns needed to fill the energy meter for a special
+pub const ENERGY_FOR_SPECIAL: u32 = 4;
+
+/// How long a curveball stays ready for after it is triggered. It's used up on the next return.
+pub const CURVEBALL_ARMED_FRAMES: Frames = Frames(240);
+
+/// How much spin a curveball leaves the paddle with. The ball is pushed sideways to the way
+/// it's going by its velocity times its spin each frame.
+pub const CURVE_SPIN: Fixed = fixed(1, 16);
+
+/// How much of its spin the ball keeps each frame, and the least it can have before it stops
+pub const SPIN_DECAY: Fixed = fixed(31, 32);
+pub const MINIMUM_SPIN: Fixed = fixed(1, 128);
+
+/// How long the temporary wall stays up in front of the goal
+pub const WALL_FRAMES: Frames = Frames(300);
+
+/// How long a speed burst lasts
+pub const SPEED_BURST_FRAMES: Frames = Frames(180);
+
+/// How many times faster a paddle moves during a speed burst
+pub const SPEED_BURST_MULTIPLIER: i32 = 2;
+
+/// Frames between a power up going away and the next one turning up
+pub const POWER_UP_INTERVAL: Frames = Frames(600);
+
+/// How long a power up waits to be collected before it disappears
+pub const POWER_UP_LIFETIME: Frames = Frames(480);
+
+/// A power up starts blinking this many frames before it disappears
+pub const POWER_UP_BLINK_FRAMES: Frames = Frames(90);
+
+/// How long a shield stays up in front of the goal if the ball never hits it
+pub const SHIELD_FRAMES: Frames = Frames(900);
+
+/// How many 16 pixel sprites tall the shield is
+pub const SHIELD_SEGMENTS: i32 = 3;
+
+/// How far the shield sticks out from the edge of the screen
+pub const SHIELD_DEPTH: i32 = 6;
+
+/// How long the pieces of a broken shield fly about for
+pub const SHARD_FRAMES: Frames = Frames(20);
+
+/// How long the sparks from the ball hitting a paddle fly about for, and how fast
+pub const SPARK_FRAMES: Frames = Frames(8);
+pub const SPARK_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(1, 1));
+
+/// How fast a hit has to be to make the loudest sound, anything slower is quieter down to
+/// the quietest volume
+pub const IMPACT_LOUDEST_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(2, 1));
+pub const IMPACT_QUIETEST_VOLUME: Fixed = fixed(1, 3);
+
+/// How far over to the left or right a sound at the very edge of the screen is played, 1 being
+/// only out of that side
+pub const SOUND_PANNING_WIDTH: Fixed = fixed(3, 4);
+
+/// How big the block that everything belonging to a match is allocated from is
+pub const MATCH_ARENA_BYTES: usize = 32 * 1024;
+
+/// How many of the 228 lines of a frame the game can take before the debug log says it's slow
+#[cfg(feature = "debug-tools")]
+pub const FRAME_TIME_WARNING_LINES: u16 = 200;
+
+/// How many of the 128 OAM slots can be used before the debug log says it's getting full
+#[cfg(feature = "debug-tools")]
+pub const OAM_WARNING_OBJECTS: usize = 112;
+
+/// The most of the cartridge the sounds are allowed to take up, in bytes
+pub const AUDIO_ROM_BUDGET: usize = 256 * 1024;
+/// The same for everything in `gfx/`, the sprite sheets and the backgrounds together
+pub const GRAPHICS_ROM_BUDGET: usize = 32 * 1024;
+
+/// How long one piece of music takes to fade into the next, and how loud it is once it has with
+/// the music volume option turned all the way up
+pub const MUSIC_CROSSFADE_FRAMES: Frames = Frames(30);
+pub const MUSIC_VOLUME: Fixed = fixed(1, 2);
+/// How many steps the music volume option has between silent and `MUSIC_VOLUME`
+pub const MUSIC_VOLUME_STEPS: u8 = 4;
+
+/// How much faster bounces are played for each return in the rally, up to the most they go up
+pub const BOUNCE_PITCH_STEP: Fixed = fixed(1, 128);
+pub const BOUNCE_PITCH_MAX: Fixed = fixed(1, 4);
+
+/// Added to the speed of each bounce in turn so no two in a row sound quite the same
+pub const BOUNCE_PITCH_WOBBLE: [Fixed; 5] = [
+    fixed(0, 1),
+    fixed(1, 32),
+    fixed(-1, 64),
+    fixed(1, 64),
+    fixed(-1, 32),
+];
+
+/// How fast a hit has to be to use the normal and then the hard version of its sound
+pub const IMPACT_HARDNESS_SPEEDS: [PixelsPerFrame; 2] =
+    [PixelsPerFrame(fixed(1, 1)), PixelsPerFrame(fixed(7, 4))];
+
+/// How long the extra ball from a split lasts, 10 seconds
+pub const SPLIT_FRAMES: Frames = Frames(600);
+
+/// How long a curse keeps someone's controls upside down, 5 seconds
+pub const CURSE_FRAMES: Frames = Frames(300);
+
+/// Where the middle of each portal is, across from the net and down from the top of the
+/// screen. The left one is high up and the right one low down.
+pub const PORTAL_OFFSETS: [(i32, i32); 2] = [(-64, 40), (64, 104)];
+
+/// How long after going through a portal the ball can't go through another one
+pub const PORTAL_COOLDOWN_FRAMES: Frames = Frames(30);
+
+/// How fast the elevator rides up and down the middle of the court
+pub const ELEVATOR_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(1, 2));
+
+/// How long the elevator waits at the top and bottom before heading back
+pub const ELEVATOR_PAUSE_FRAMES: Frames = Frames(45);
+
+/// Where the middle of each bumper is, across from the net and down from the top of the screen
+pub const BUMPER_OFFSETS: [(i32, i32); 4] = [(0, 36), (-36, 80), (36, 80), (0, 124)];
+
+/// How much faster the ball leaves a bumper, pushed straight away from its middle
+pub const BUMPER_KICK: PixelsPerFrame = PixelsPerFrame(fixed(1, 2));
+
+/// A bumper never sends the ball off faster than this
+pub const BUMPER_MAX_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(4, 1));
+
+/// How long a bumper stays lit up after it's hit
+pub const BUMPER_FLASH_FRAMES: Frames = Frames(12);
+
+/// How far past the net the ball can be and still be seen through the fog
+pub const FOG_REVEAL_DISTANCE: i32 = 40;
+
+/// How big the hole in the fog around the ball is
+pub const FOG_REVEAL_SIZE: i32 = 32;
+
+/// How many points have to be played between each time a shrinking court closes in
+pub const POINTS_PER_COURT_SHRINK: u32 = 3;
+
+/// How far the top and bottom walls move in each time the court shrinks
+pub const COURT_SHRINK_STEP: i32 = 8;
+
+/// The furthest the walls can move in, so the tallest paddle still has room to move
+pub const COURT_SHRINK_LIMIT: i32 = 32;
+
+/// The scores where whoever is ahead loses a segment of their paddle, with the shrinking paddles
+/// modifier on. A paddle never goes below its two ends, they're pushed together instead.
+pub const PADDLE_SHRINK_SCORES: [u32; 2] = [2, 4];
+
+/// How many pixels the two ends of a paddle overlap once it shrinks with nothing left between
+/// them, which it only does the once
+pub const PADDLE_SQUEEZE: i32 = 8;
+
+/// How far the ball has to be from the middle of the computer's paddle before it starts moving
+pub const AI_DEAD_ZONE: i32 = 12;
+
+/// Once it's moving, the computer keeps going until the ball is this close to the middle, so it
+/// doesn't twitch back and forth around one spot
+pub const AI_SETTLE_DISTANCE: i32 = 3;
+
+/// How long the computer takes to notice the ball has been sent back towards it, it carries on
+/// doing what it was until then
+pub const AI_REACTION_FRAMES: Frames = Frames(0);
+
+/// How much the computer can change its push on the paddle each frame, as a fraction of full speed
+pub const AI_ACCELERATION: Fixed = fixed(1, 8);
+
+/// A way the computer can serve
+pub struct ServeOption {
+    /// The ball velocity at the start of the serve, x is the speed towards the other side
+    pub velocity: Vector2D<Fixed>,
+    /// How often this serve is picked against the others on easy, normal and hard
+    pub weights: [u32; 3],
+}
+
+/// Every serve the computer knows. Easy mostly sticks to gentle serves, hard goes for the
+/// fast and steep ones much more often.
+pub const AI_SERVES: [ServeOption; 6] = [
+    ServeOption {
+        velocity: Vector2D::new(fixed(1, 1), fixed(1, 1)),
+        weights: [4, 3, 2],
+    },
+    ServeOption {
+        velocity: Vector2D::new(fixed(1, 1), fixed(-1, 1)),
+        weights: [4, 3, 2],
+    },
+    ServeOption {
+        velocity: Vector2D::new(fixed(5, 4), fixed(1, 2)),
+        weights: [2, 3, 3],
+    },
+    ServeOption {
+        velocity: Vector2D::new(fixed(5, 4), fixed(-1, 2)),
+        weights: [2, 3, 3],
+    },
+    // A slow lob
+    ServeOption {
+        velocity: Vector2D::new(fixed(3, 4), fixed(3, 4)),
+        weights: [3, 1, 0],
+    },
+    // A fast flat serve
+    ServeOption {
+        velocity: Vector2D::new(fixed(2, 1), fixed(1, 4)),
+        weights: [0, 1, 3],
+    },
+];
+
+/// The angles the player can aim a serve at with L and R, from up the screen to down it
+pub const PLAYER_SERVES: [Vector2D<Fixed>; 5] = [
+    Vector2D::new(fixed(1, 1), fixed(-1, 1)),
+    Vector2D::new(fixed(5, 4), fixed(-1, 2)),
+    Vector2D::new(fixed(3, 2), fixed(1, 4)),
+    Vector2D::new(fixed(5, 4), fixed(1, 2)),
+    Vector2D::new(fixed(1, 1), fixed(1, 1)),
+];
+
+/// The computer waits somewhere between these many frames before it serves
+pub const AI_SERVE_DELAY: (Frames, Frames) = (Frames(30), Frames(90));
+
+/// How much the ball's speed is multiplied by each frame it's in each half of the lopsided court,
+/// the left then the right. It picks up speed heading away from the player and loses it again
+/// on the other side.
+pub const LOPSIDED_SPEEDS: [Fixed; 2] = [fixed(257, 256), fixed(255, 256)];
+
+/// The lopsided court stops speeding the ball up once it's going across this fast
+pub const LOPSIDED_MAX_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(4, 1));
+
+/// How many frames of play into a match it's dusk, then night
+pub const DUSK_FRAMES: Frames = Frames(2 * 60 * 60);
+pub const NIGHT_FRAMES: Frames = Frames(4 * 60 * 60);
+
+/// How many of the court's colours are moved towards the time of day each frame
+pub const DAYLIGHT_COLOURS_PER_FRAME: usize = 2;
+
+/// How long the computer's paddle flashes for after it scores
+pub const TAUNT_FRAMES: Frames = Frames(60);
+
+/// How many frames one bob of the computer's paddle takes while it waits for a serve
+pub const IDLE_BOB_FRAMES: Frames = Frames(64);
+
+/// How many sprites the crowd along the top of the court can use. Seats are filled in both
+/// stands in turn, so turning this down thins them out evenly.
+pub const CROWD_SIZE: usize = 10;
+
+/// How many frames the crowd holds each step of their swaying for
+pub const CROWD_SWAY_FRAMES: Frames = Frames(24);
+
+/// How long the crowd cheers for after a goal or a rally milestone
+pub const CROWD_CHEER_FRAMES: Frames = Frames(90);
+
+/// How many dots the ball leaves behind it and how many frames apart they're dropped
+pub const TRAIL_LENGTH: usize = 6;
+pub const TRAIL_SPACING: Frames = Frames(3);
+
+/// How far each older dot of the trail leans away from a curving ball, times how hard it's curving
+pub const TRAIL_SKEW: Fixed = fixed(6, 1);
+
+/// How many raindrops or snowflakes fall at once on the courts with weather
+pub const WEATHER_DROPS: usize = 16;
+
+/// How far ahead the coaching hint looks for where the ball is going
+pub const HINT_LOOKAHEAD_FRAMES: Frames = Frames(300);
+
+/// How many frames of the serve's path are shown while the player aims it
+pub const SERVE_PREVIEW_FRAMES: Frames = Frames(40);
+
+/// How many dots the serve preview is drawn with
+pub const SERVE_PREVIEW_DOTS: usize = 8;
+
+/// How fast the ball comes off the paddle each time it's bounced while the player lines up a
+/// serve, it goes about 9 pixels out
+pub const WARMUP_BOUNCE_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(3, 2));
+
+/// How much the ball slows down heading away from the paddle each frame of the warm up bounce
+pub const WARMUP_GRAVITY: Fixed = fixed(1, 8);
+
+/// Returns in a row that set off the rally effects, each one bigger than the last
+pub const RALLY_MILESTONES: [u32; 3] = [10, 20, 50];
+
+/// The rally count only shows up once it gets to this many returns
+pub const RALLY_SHOWN_FROM: u32 = 2;
+
+/// How long the rally count flashes after reaching a milestone
+pub const RALLY_FLASH_FRAMES: Frames = Frames(60);
+
+/// How long the court shimmers for at the second rally milestone
+pub const RALLY_SHIMMER_FRAMES: Frames = Frames(30);
+
+/// How long confetti falls for at the biggest rally milestone
+pub const RALLY_CONFETTI_FRAMES: Frames = Frames(45);
+
+/// The most frames of a point kept for the replay, anything before this is dropped
+pub const REPLAY_MAX_FRAMES: Frames = Frames(4 * 60);
+
+/// How many frames each recorded frame is shown for in the replay
+pub const REPLAY_SLOW_MOTION: usize = 3;
+
+/// How long everything freezes for when a goal goes in
+pub const PHOTO_FINISH_FRAMES: Frames = Frames(20);
+
+/// How many pixels the court moves towards the goal during the freeze
+pub const PHOTO_FINISH_NUDGE: i32 = 3;
+
+/// How long one wave of bouncing letters takes
+pub const MARQUEE_BOUNCE_FRAMES: Frames = Frames(48);
+
+/// How far bouncing letters move up and down
+pub const MARQUEE_BOUNCE_HEIGHT: i32 = 3;
+
+/// How long letters stay each colour while their colours cycle
+pub const MARQUEE_COLOUR_FRAMES: Frames = Frames(6);
+
+/// How long typing out each letter takes
+pub const MARQUEE_TYPE_FRAMES: Frames = Frames(5);
+
+/// How long nothing has to be pressed on a menu before it only updates every few frames
+pub const MENU_THROTTLE_IDLE_FRAMES: Frames = Frames(10 * 60);
+
+/// How often a menu that's been left alone updates, the music is still mixed every frame
+pub const MENU_THROTTLE_FRAMES: Frames = Frames(4);
+
+/// How long nothing has to be pressed on the title screen before the console goes to sleep
+pub const SLEEP_IDLE_FRAMES: Frames = Frames(5 * 60 * 60);
+
+/// How many pixels across each square of the collision grid is
+pub const BROADPHASE_CELL_SIZE: i32 = 32;
+
+/// How many frames early a serve or special can be pressed and still count
+pub const INPUT_BUFFER_FRAMES: Frames = Frames(5);
+
+/// How long the nudge from tapping up or down lasts, holding for longer than this moves the
+/// paddle at full speed
+pub const TAP_NUDGE_FRAMES: Frames = Frames(6);
+
+/// How fast the paddle moves during a nudge, as a fraction of its full speed
+pub const TAP_NUDGE_SPEED: Fixed = fixed(1, 2);
+
+/// How much faster the ball on the title screen falls each frame
+pub const JUGGLE_GRAVITY: Fixed = fixed(1, 16);
+
+/// How fast kicking the ball on the title screen sends it back up
+pub const JUGGLE_KICK_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(3, 1));
+
+/// How much holding left or right speeds the ball on the title screen up sideways each frame
+pub const JUGGLE_NUDGE: Fixed = fixed(1, 16);
+
+/// The fastest the ball on the title screen can go sideways
+pub const JUGGLE_MAX_SIDEWAYS_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(2, 1));
+
+/// How many seconds the ball on the title screen has to stay off the floor for each message
+pub const JUGGLE_MILESTONES: [u32; 3] = [10, 30, 60];
+
+/// How fast the credits scroll up the screen
+pub const CREDITS_SCROLL_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(1, 2));
+
+/// How long up and down each have to be held in the tutorial's lesson on moving
+pub const TUTORIAL_MOVE_FRAMES: Frames = Frames(30);
+
+/// How steeply a return has to go to pass the tutorial's lesson on angles
+pub const TUTORIAL_ANGLE_SPEED: PixelsPerFrame = PixelsPerFrame(fixed(1, 1));
+
+/// How long the paddles take to slide in on the versus screen, and how long it's up altogether
+pub const VERSUS_SLIDE_FRAMES: Frames = Frames(30);
+pub const VERSUS_FRAMES: Frames = Frames(2 * 60);
+
+/// How long the end of the tutorial stays up before going back to the title screen
+pub const TUTORIAL_DONE_FRAMES: Frames = Frames(2 * 60);
+
+/// How many returns a rally needs for the achievement for a long one
+pub const ACHIEVEMENT_RALLY: u32 = 20;
+
+/// The slowest the ball can go towards a paddle in a challenge, one is picked for each
+#[cfg(feature = "bonus-modes")]
+pub const CHALLENGE_BALL_SPEEDS: [PixelsPerFrame; 3] = [
+    MINIMUM_HORIZONTAL_SPEED,
+    PixelsPerFrame(fixed(1, 1)),
+    PixelsPerFrame(fixed(5, 4)),
+];
+
+/// How much bigger the ball skin is shown in the gallery, it fills a double sized affine object
+/// at 2
+#[cfg(feature = "bonus-modes")]
+pub const GALLERY_PREVIEW_SCALE: Fixed = fixed(2, 1);
+
+/// How far the ball skin in the gallery turns each frame, in full turns
+#[cfg(feature = "bonus-modes")]
+pub const GALLERY_SPIN_SPEED: Fixed = fixed(1, 128);
+
+/// How many frames each frame of a ball skin's animation is shown for in the gallery
+#[cfg(feature = "bonus-modes")]
+pub const GALLERY_ANIMATION_FRAMES: Frames = Frames(8);
@@ -0,0 +1,256 @@
+//! Teaches the controls one lesson at a time. Each lesson puts up a prompt and only moves on
+//! once the player has actually done it. The lessons about returning the ball send it at the
+//! player along the same path every time, so a miss just means another go. B goes back to the
+//! title screen.
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::fixnum::Vector2D;
+use agb::input::Button;
+
+use crate::animation::{Cue, Cued, Timeline};
+use crate::background;
+use crate::ball::Ball;
+use crate::character::CHARACTERS;
+use crate::court::Court;
+use crate::events::{Events, GameEvent};
+use crate::paddle::{Paddle, Side};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+use crate::tuning::{
+    fixed, Fixed, Frames, PLAYER_SERVES, TUTORIAL_ANGLE_SPEED, TUTORIAL_DONE_FRAMES,
+    TUTORIAL_MOVE_FRAMES,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lesson {
+    Move,
+    Serve,
+    Angles,
+    Smash,
+    Done,
+}
+
+/// Where the ball is sent from and how fast for the lessons about returning it
+struct Demonstration {
+    start: Vector2D<i32>,
+    velocity: Vector2D<Fixed>,
+}
+
+/// A lesson, what it puts up as it starts and whether the ball is sent at the player for it
+struct Script {
+    lesson: Lesson,
+    cues: &'static [Cued],
+    demonstration: Option<Demonstration>,
+}
+
+static SCRIPT: [Script; 5] = [
+    Script {
+        lesson: Lesson::Move,
+        cues: &[Cued::new(0, Cue::Prompt(Text::TutorialMove))],
+        demonstration: None,
+    },
+    Script {
+        lesson: Lesson::Serve,
+        cues: &[Cued::new(0, Cue::Prompt(Text::TutorialServe))],
+        demonstration: None,
+    },
+    Script {
+        lesson: Lesson::Angles,
+        cues: &[Cued::new(0, Cue::Prompt(Text::TutorialAngles))],
+        demonstration: Some(Demonstration {
+            start: Vector2D::new(200, 24),
+            velocity: Vector2D::new(fixed(-3, 2), fixed(3, 4)),
+        }),
+    },
+    Script {
+        lesson: Lesson::Smash,
+        cues: &[Cued::new(0, Cue::Prompt(Text::TutorialSmash))],
+        demonstration: Some(Demonstration {
+            start: Vector2D::new(200, 120),
+            velocity: Vector2D::new(fixed(-3, 2), fixed(-1, 2)),
+        }),
+    },
+    Script {
+        lesson: Lesson::Done,
+        cues: &[
+            Cued::new(0, Cue::Prompt(Text::TutorialDone)),
+            Cued::new(0, Cue::Sound(GameEvent::RallyMilestone { milestone: 1 })),
+        ],
+        demonstration: None,
+    },
+];
+
+pub struct Tutorial<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    prompt: Label<'a>,
+    court: Court,
+    paddle: Paddle<'a>,
+    ball: Ball<'a>,
+    events: Events,
+    /// Which entry in the script the player is on
+    step: usize,
+    /// Frames spent moving up and down in the first lesson
+    frames_up: Frames,
+    frames_down: Frames,
+    served: bool,
+    /// Frames since the lesson started
+    timeline: Timeline,
+}
+
+impl<'a> Tutorial<'a> {
+    pub fn new(resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let court = Court::default();
+        let mut tutorial = Self {
+            backdrop: background::backdrop(
+                resources.tiled,
+                frame.vram,
+                frame.options.court_theme.backdrop(),
+            ),
+            prompt: Label::new(resources.tiled, frame.vram, (2, 1)),
+            court,
+            paddle: Paddle::new(resources.object, &CHARACTERS[0], Side::Left, &court),
+            ball: Ball::new(resources.object, frame.options.ball_skin),
+            events: Events::default(),
+            step: 0,
+            frames_up: Frames::ZERO,
+            frames_down: Frames::ZERO,
+            served: false,
+            timeline: Timeline::default(),
+        };
+        tutorial.start_lesson();
+        tutorial
+    }
+
+    fn start_lesson(&mut self) {
+        self.timeline = Timeline::default();
+        self.frames_up = Frames::ZERO;
+        self.frames_down = Frames::ZERO;
+        self.send_ball();
+    }
+
+    /// Puts the ball where this lesson wants it, sent along its path or ready to serve
+    fn send_ball(&mut self) {
+        self.ball.serve();
+        self.served = false;
+        if let Some(demonstration) = &SCRIPT[self.step].demonstration {
+            self.ball.hold(demonstration.start.into());
+            self.ball.launch(demonstration.velocity);
+        }
+    }
+
+    /// Keeps the ball in front of the paddle, where a serve goes from
+    fn hold_ball(&mut self) {
+        let rect = self.paddle.rect();
+        let size = self.ball.entity.size();
+        let y = self.paddle.centre_y() - size.y / 2;
+        self.ball.hold((rect.position.x + rect.size.x, y).into());
+    }
+
+    fn next_lesson(&mut self) {
+        self.step += 1;
+        self.start_lesson();
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        if frame.input.is_just_pressed(Button::B) {
+            return Some(Next::Title);
+        }
+
+        let direction = frame.input.y_tri() as i32;
+        self.paddle.move_paddle_with_input(Fixed::new(direction));
+        self.paddle.checks_and_keeps_in_bounds(&self.court);
+
+        let script = &SCRIPT[self.step];
+        for cue in self.timeline.cues(script.cues) {
+            match cue {
+                Cue::Prompt(prompt) => {
+                    let prompt = prompt.get(frame.options.language);
+                    self.prompt
+                        .write(frame.vram, text::HIGHLIGHT, format_args!("{prompt}"));
+                }
+                Cue::Sound(event) => frame.audio.handle_event(event),
+                Cue::ShowSprite | Cue::HideSprite => {}
+            }
+        }
+        self.timeline.advance();
+
+        match script.lesson {
+            Lesson::Move => {
+                self.hold_ball();
+                match direction {
+                    -1 => self.frames_up.tick(),
+                    1 => self.frames_down.tick(),
+                    _ => {}
+                }
+                if self.frames_up >= TUTORIAL_MOVE_FRAMES
+                    && self.frames_down >= TUTORIAL_MOVE_FRAMES
+                {
+                    self.next_lesson();
+                }
+            }
+            Lesson::Serve if !self.served => {
+                self.hold_ball();
+                if frame.input.is_just_pressed(Button::A) {
+                    self.ball.launch(PLAYER_SERVES[PLAYER_SERVES.len() / 2]);
+                    self.served = true;
+                }
+            }
+            Lesson::Serve | Lesson::Angles | Lesson::Smash => {
+                if self.ball_lands() {
+                    self.next_lesson();
+                }
+            }
+            Lesson::Done => {
+                self.hold_ball();
+                if self.timeline.frame() >= TUTORIAL_DONE_FRAMES.0 {
+                    return Some(Next::Title);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Moves the ball on a frame, returns true once it has done what the lesson is after. It's
+    /// sent again if it reaches either end without that happening.
+    fn ball_lands(&mut self) -> bool {
+        let ball = &mut self.ball;
+        ball.checks_and_keeps_in_bounds(&self.court);
+        ball.bounce_if_hits_screen_bounds(&self.court, &mut self.events);
+        self.paddle.checks_all_collisions(ball, &mut self.events);
+        ball.keep_moving_horizontally();
+
+        let lesson = SCRIPT[self.step].lesson;
+        let mut done = false;
+        for event in self.events.drain() {
+            done |= match (lesson, event) {
+                (Lesson::Angles, GameEvent::PaddleHit { .. }) => {
+                    ball.entity.velocity.y.abs() >= TUTORIAL_ANGLE_SPEED.0
+                }
+                (Lesson::Smash, GameEvent::EdgeShot { .. }) => true,
+                _ => false,
+            };
+        }
+
+        match ball.scoring_side(&self.court) {
+            // A serve only has to make it across
+            Some(Side::Left) if lesson == Lesson::Serve => true,
+            Some(_) => {
+                self.send_ball();
+                done
+            }
+            None => done,
+        }
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.paddle.update_sprite_positions();
+        self.ball.update_sprite();
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.prompt.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,43 @@
+//! The tuning numbers that can be changed while the game is running, from the tuning console in
+//! debug builds. They all start out as the constants in `tuning`.
+
+use core::cell::Cell;
+
+use agb::external::critical_section::{self, Mutex};
+
+use crate::tuning::{
+    Fixed, Frames, PixelsPerFrame, SegmentBounce, AI_ACCELERATION, AI_DEAD_ZONE,
+    AI_REACTION_FRAMES, BALL_SPEED_RAMP, MINIMUM_HORIZONTAL_SPEED, PADDLE_ACCELERATION,
+    PADDLE_SEGMENT_BOUNCES,
+};
+
+#[derive(Clone, Copy)]
+pub struct Tweaks {
+    pub minimum_horizontal_speed: PixelsPerFrame,
+    pub ball_speed_ramp: Fixed,
+    pub paddle_acceleration: PixelsPerFrame,
+    pub ai_acceleration: Fixed,
+    pub ai_dead_zone: i32,
+    pub ai_reaction_frames: Frames,
+    pub segment_bounces: [SegmentBounce; 3],
+}
+
+static TWEAKS: Mutex<Cell<Tweaks>> = Mutex::new(Cell::new(Tweaks {
+    minimum_horizontal_speed: MINIMUM_HORIZONTAL_SPEED,
+    ball_speed_ramp: BALL_SPEED_RAMP,
+    paddle_acceleration: PADDLE_ACCELERATION,
+    ai_acceleration: AI_ACCELERATION,
+    ai_dead_zone: AI_DEAD_ZONE,
+    ai_reaction_frames: AI_REACTION_FRAMES,
+    segment_bounces: PADDLE_SEGMENT_BOUNCES,
+}));
+
+pub fn get() -> Tweaks {
+    critical_section::with(|cs| TWEAKS.borrow(cs).get())
+}
+
+/// Only the challenge and the tuning console change them
+#[cfg(any(feature = "bonus-modes", feature = "debug-tools"))]
+pub fn set(tweaks: Tweaks) {
+    critical_section::with(|cs| TWEAKS.borrow(cs).set(tweaks));
+}
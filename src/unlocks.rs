@@ -0,0 +1,123 @@
+//! Everything that has to be unlocked before it can be picked and the achievement that unlocks
+//! it. Nothing is saved for the unlocks themselves, they follow from the profile's achievements
+//! so they're kept wherever those are.
+
+use crate::assets::BallSkin;
+use crate::background::CourtTheme;
+use crate::options::Options;
+use crate::profile::{Achievement, Achievements};
+use crate::strings::Text;
+use crate::text::Palette;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unlock {
+    Palette(Palette),
+    BallSkin(BallSkin),
+    CourtTheme(CourtTheme),
+    /// The cheat that doubles the player's goals
+    DoublePoints,
+}
+
+impl Unlock {
+    /// What sort of thing it is, shown before its name
+    pub fn kind(self) -> Text {
+        match self {
+            Unlock::Palette(_) => Text::Colours,
+            Unlock::BallSkin(_) => Text::Ball,
+            Unlock::CourtTheme(_) => Text::Court,
+            Unlock::DoublePoints => Text::Cheat,
+        }
+    }
+
+    /// Whether it's what the options have picked, or for the cheat whether it's on
+    pub fn is_chosen(self, options: &Options) -> bool {
+        match self {
+            Unlock::Palette(palette) => options.palette == palette,
+            Unlock::BallSkin(skin) => options.ball_skin == skin,
+            Unlock::CourtTheme(theme) => options.court_theme == theme,
+            Unlock::DoublePoints => options.double_points,
+        }
+    }
+
+    /// Picks it, the cheat is turned on or off instead
+    pub fn choose(self, options: &mut Options) {
+        match self {
+            Unlock::Palette(palette) => options.palette = palette,
+            Unlock::BallSkin(skin) => options.ball_skin = skin,
+            Unlock::CourtTheme(theme) => options.court_theme = theme,
+            Unlock::DoublePoints => options.double_points = !options.double_points,
+        }
+    }
+}
+
+pub struct Entry {
+    pub unlock: Unlock,
+    pub name: Text,
+    /// The achievement needed first, the defaults don't need one
+    pub requirement: Option<Achievement>,
+}
+
+impl Entry {
+    pub fn is_unlocked(&self, achievements: Achievements) -> bool {
+        self.requirement
+            .is_none_or(|requirement| achievements.contains(requirement))
+    }
+}
+
+pub static UNLOCKS: [Entry; 11] = [
+    Entry {
+        unlock: Unlock::Palette(Palette::Classic),
+        name: Text::Classic,
+        requirement: None,
+    },
+    Entry {
+        unlock: Unlock::Palette(Palette::Mint),
+        name: Text::Mint,
+        requirement: Some(Achievement::FirstWin),
+    },
+    Entry {
+        unlock: Unlock::Palette(Palette::Rose),
+        name: Text::Rose,
+        requirement: Some(Achievement::LongRally),
+    },
+    Entry {
+        unlock: Unlock::BallSkin(BallSkin::Classic),
+        name: Text::Classic,
+        requirement: None,
+    },
+    Entry {
+        unlock: Unlock::BallSkin(BallSkin::Swirl),
+        name: Text::Swirl,
+        requirement: Some(Achievement::LongRally),
+    },
+    Entry {
+        unlock: Unlock::BallSkin(BallSkin::Shard),
+        name: Text::Shard,
+        requirement: Some(Achievement::Shutout),
+    },
+    Entry {
+        unlock: Unlock::CourtTheme(CourtTheme::Classic),
+        name: Text::Classic,
+        requirement: None,
+    },
+    Entry {
+        unlock: Unlock::CourtTheme(CourtTheme::Plain),
+        name: Text::Plain,
+        requirement: Some(Achievement::FirstWin),
+    },
+    Entry {
+        unlock: Unlock::CourtTheme(CourtTheme::Rainy),
+        name: Text::Rainy,
+        requirement: Some(Achievement::LongRally),
+    },
+    Entry {
+        unlock: Unlock::CourtTheme(CourtTheme::Snowy),
+        name: Text::Snowy,
+        requirement: Some(Achievement::Shutout),
+    },
+    Entry {
+        unlock: Unlock::DoublePoints,
+        name: Text::DoublePoints,
+        requirement: Some(Achievement::Shutout),
+    },
+];
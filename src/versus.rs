@@ -0,0 +1,158 @@
+//! Shown before every match. The two paddles slide in from the sides and meet the "VS" in the
+//! middle, with who's playing each and the player's record against the computer, then the
+//! match starts on its own. A or start gets there straight away.
+
+use agb::display::tiled::{MapLoan, RegularMap, TiledMap, VRamManager};
+use agb::input::Button;
+
+use crate::animation::{Cue, Cued, Keyframe, Timeline, Track};
+use crate::background::{self, Backdrop};
+use crate::court::Court;
+use crate::events::{GameEvent, Impact};
+use crate::game::MatchSettings;
+use crate::marquee::{Marquee, TextEffects};
+use crate::paddle::{Paddle, Side};
+use crate::scene::{Frame, Next, Resources};
+use crate::strings::Text;
+use crate::text::{self, Label};
+use crate::tuning::{Fixed, VERSUS_FRAMES, VERSUS_SLIDE_FRAMES};
+
+/// How far off to the side each paddle starts
+const SLIDE_DISTANCE: i32 = 80;
+
+static LEFT_SLIDE: Track = Track::new(&[
+    Keyframe::new(0, -SLIDE_DISTANCE, 0),
+    Keyframe::new(VERSUS_SLIDE_FRAMES.0, 0, 0),
+]);
+static RIGHT_SLIDE: Track = Track::new(&[
+    Keyframe::new(0, SLIDE_DISTANCE, 0),
+    Keyframe::new(VERSUS_SLIDE_FRAMES.0, 0, 0),
+]);
+/// The paddles clack together as they arrive
+static CUES: [Cued; 1] = [Cued::new(
+    VERSUS_SLIDE_FRAMES.0,
+    Cue::Sound(GameEvent::PaddleHit {
+        side: Side::Left,
+        impact: Impact::HARDEST,
+    }),
+)];
+
+pub struct Versus<'a> {
+    backdrop: MapLoan<'a, RegularMap>,
+    /// Who's playing each paddle, on their own side of the screen
+    left_name: Label<'a>,
+    right_name: Label<'a>,
+    record: Label<'a>,
+    vs: Marquee<'a>,
+    left: Paddle<'a>,
+    right: Paddle<'a>,
+    settings: MatchSettings,
+    timeline: Timeline,
+}
+
+impl<'a> Versus<'a> {
+    pub fn new(settings: MatchSettings, resources: Resources<'a>, frame: &mut Frame) -> Self {
+        let language = frame.options.language;
+        let court = Court::default();
+
+        let mut left_name = Label::new(resources.tiled, frame.vram, (4, 3));
+        let you = Text::You.get(language);
+        left_name.write(
+            frame.vram,
+            text::HIGHLIGHT,
+            format_args!("{you}\n{}", settings.player.name),
+        );
+        let mut right_name = Label::new(resources.tiled, frame.vram, (18, 3));
+        let cpu = Text::Cpu.get(language);
+        let difficulty = settings.difficulty.name().get(language);
+        right_name.write(
+            frame.vram,
+            text::HIGHLIGHT,
+            format_args!("{cpu} - {difficulty}\n{}", settings.cpu.name),
+        );
+
+        // Matches lost are worked out, the profile only keeps the ones played and won
+        let lifetime = frame.save.profiles.current().lifetime;
+        let mut record = Label::new(resources.tiled, frame.vram, (7, 15));
+        let record_text = Text::Record.get(language);
+        let lost = lifetime.played.saturating_sub(lifetime.won);
+        match frame.session.rubber {
+            Some(tally) => {
+                let rubber = Text::RubberMatch.get(language);
+                record.write(
+                    frame.vram,
+                    text::TEXT,
+                    format_args!(
+                        "{record_text}: {} - {lost}  {rubber}: {} - {}",
+                        lifetime.won, tally.left, tally.right
+                    ),
+                );
+            }
+            None => record.write(
+                frame.vram,
+                text::TEXT,
+                format_args!("{record_text}: {} - {lost}", lifetime.won),
+            ),
+        }
+
+        let mut versus = Self {
+            backdrop: background::backdrop(resources.tiled, frame.vram, Backdrop::Panel),
+            left_name,
+            right_name,
+            record,
+            vs: Marquee::new(
+                resources.object,
+                Text::Versus.get(language),
+                76,
+                TextEffects {
+                    bounce: true,
+                    colour_cycle: true,
+                    ..Default::default()
+                },
+            ),
+            left: Paddle::new(resources.object, settings.player, Side::Left, &court),
+            right: Paddle::new(resources.object, settings.cpu, Side::Right, &court),
+            settings,
+            timeline: Timeline::default(),
+        };
+        // Both sit in the middle of the screen whatever size they are
+        for paddle in [&mut versus.left, &mut versus.right] {
+            let height = paddle.height();
+            paddle.set_y(Fixed::new((agb::display::HEIGHT - height) / 2));
+        }
+        versus
+    }
+
+    pub fn update(&mut self, frame: &mut Frame) -> Option<Next> {
+        for cue in self.timeline.cues(&CUES) {
+            if let Cue::Sound(event) = cue {
+                frame.audio.handle_event(event);
+            }
+        }
+        self.timeline.advance();
+
+        let skipped =
+            frame.input.is_just_pressed(Button::A) || frame.input.is_just_pressed(Button::START);
+        if skipped || self.timeline.frame() >= VERSUS_FRAMES.0 {
+            return Some(Next::Game(self.settings));
+        }
+
+        None
+    }
+
+    pub fn update_sprites(&mut self) {
+        let frame = self.timeline.frame();
+        self.left.set_sprite_offset(LEFT_SLIDE.position_at(frame));
+        self.right.set_sprite_offset(RIGHT_SLIDE.position_at(frame));
+        self.left.update_sprite_positions();
+        self.right.update_sprite_positions();
+        self.vs.update_sprites();
+    }
+
+    pub fn leave(&mut self, vram: &mut VRamManager) {
+        self.left_name.clear(vram);
+        self.right_name.clear(vram);
+        self.record.clear(vram);
+        self.backdrop.clear(vram);
+    }
+}
@@ -0,0 +1,49 @@
+//! Where things are drawn rather than where they are. Playing left handed mirrors the court so
+//! the player's paddle is on the right, but only when sprites and windows are put on screen.
+//! Everything else in the game still has the player on the left.
+
+use core::cell::Cell;
+
+use agb::display::object::Sprite;
+use agb::external::critical_section::{self, Mutex};
+use agb::fixnum::{Rect, Vector2D};
+
+static MIRRORED: Mutex<Cell<bool>> = Mutex::new(Cell::new(false));
+
+fn is_mirrored() -> bool {
+    critical_section::with(|cs| MIRRORED.borrow(cs).get())
+}
+
+/// Turns the mirroring on for a match and back off after it, the menus are never mirrored
+pub fn set_mirrored(mirrored: bool) {
+    critical_section::with(|cs| MIRRORED.borrow(cs).set(mirrored));
+}
+
+/// Where the left edge of something `width` pixels wide at `x` ends up on screen
+pub fn x(x: i32, width: i32) -> i32 {
+    if is_mirrored() {
+        agb::display::WIDTH - x - width
+    } else {
+        x
+    }
+}
+
+/// Where the top left corner of something `size` pixels big at `position` ends up on screen
+pub fn position(position: Vector2D<i32>, size: Vector2D<i32>) -> Vector2D<i32> {
+    (x(position.x, size.x), position.y).into()
+}
+
+/// Where `sprite` is drawn when it is at `position`
+pub fn sprite_position(position: Vector2D<i32>, sprite: &Sprite) -> Vector2D<i32> {
+    let (width, height) = sprite.size().to_width_height();
+    self::position(position, (width as i32, height as i32).into())
+}
+
+/// Whether something facing the other way should be flipped, mirroring turns everything round
+pub fn hflip(flip: bool) -> bool {
+    flip != is_mirrored()
+}
+
+pub fn rect(rect: Rect<i32>) -> Rect<i32> {
+    Rect::new(position(rect.position, rect.size), rect.size)
+}
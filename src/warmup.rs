@@ -0,0 +1,30 @@
+//! The player bouncing the ball off their paddle while they line up a serve. It's only for show,
+//! the ball is served from the paddle just the same wherever it is in the bounce.
+
+use crate::tuning::{Fixed, PixelsPerFrame, WARMUP_BOUNCE_SPEED, WARMUP_GRAVITY};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Warmup {
+    /// How far off the face of the paddle the ball is
+    height: Fixed,
+    /// How fast it's heading away from the paddle, back towards it once this goes negative
+    speed: PixelsPerFrame,
+}
+
+impl Warmup {
+    /// Moves the bounce on a frame, returns how far off the paddle the ball is now
+    pub fn update(&mut self) -> Fixed {
+        self.speed.0 -= WARMUP_GRAVITY;
+        self.height += self.speed.0;
+        if self.height <= 0.into() {
+            self.height = 0.into();
+            self.speed = WARMUP_BOUNCE_SPEED;
+        }
+        self.height
+    }
+
+    /// Puts the ball back on the paddle for the next serve
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
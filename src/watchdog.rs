@@ -0,0 +1,66 @@
+//! Catches the ball getting into a state the game can't recover from on its own, like being
+//! stuck inside a paddle, so the match gets a clean re-serve instead of soft locking.
+
+use crate::ball::Ball;
+use crate::court::Court;
+use crate::paddle::Paddle;
+use crate::physics;
+use crate::tuning::MAX_FRAMES_INSIDE_PADDLE;
+
+/// Something that should never happen to the ball during normal play
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// The ball has been overlapping a paddle for longer than any bounce takes to resolve
+    StuckInPaddle,
+    /// The ball ended up somewhere off the court
+    OutsideCourt,
+    /// The ball has no velocity so it will never move again
+    Stopped,
+}
+
+#[derive(Default)]
+pub struct Watchdog {
+    frames_inside_paddle: u32,
+}
+
+impl Watchdog {
+    /// Checks the ball for problems, should be called once a frame after all the movement is done
+    pub fn check(&mut self, ball: &Ball, paddles: [&Paddle; 2], court: &Court) -> Option<Problem> {
+        let entity = &ball.entity;
+
+        if entity.velocity.x == 0.into() && entity.velocity.y == 0.into() {
+            return Some(Problem::Stopped);
+        }
+
+        let max_x = court.right() - entity.collision_mask.x as i32;
+        let max_y = court.bottom() - entity.collision_mask.y as i32;
+        if entity.position.x < court.left()
+            || entity.position.y < court.top()
+            || entity.position.x > max_x
+            || entity.position.y > max_y
+        {
+            return Some(Problem::OutsideCourt);
+        }
+
+        let inside_paddle = paddles
+            .iter()
+            .any(|paddle| physics::overlaps(entity.rect(), paddle.rect()));
+
+        if inside_paddle {
+            self.frames_inside_paddle += 1;
+        } else {
+            self.frames_inside_paddle = 0;
+        }
+
+        if self.frames_inside_paddle > MAX_FRAMES_INSIDE_PADDLE.0 {
+            return Some(Problem::StuckInPaddle);
+        }
+
+        None
+    }
+
+    /// Forgets about anything seen so far, used after the ball has been re-served
+    pub fn reset(&mut self) {
+        self.frames_inside_paddle = 0;
+    }
+}
@@ -0,0 +1,89 @@
+//! Rain or snow falling over the themed courts. Each drop is a sprite from a pool that falls
+//! down the screen and goes back to the top once it's gone off the bottom, at a spot along from
+//! where it was so the same pattern doesn't come round again. It's only there to look at, so
+//! reduced motion turns it off.
+
+use agb::display::object::OamManaged;
+use agb::fixnum::Vector2D;
+use alloc::vec::Vec;
+
+use crate::arena::{self, MatchArena};
+use crate::assets::SPRITES;
+use crate::layering::RenderLayer;
+use crate::pool::SpritePool;
+use crate::tuning::{fixed, Fixed, Frames, WEATHER_DROPS};
+
+/// How far out of the screen a drop can be before it wraps around, the size of its sprite
+const MARGIN: i32 = 16;
+/// How far along the next spot for a drop is, odd so it takes a long time to come round
+const STRIDE: i32 = 97;
+/// How many frames a snowflake drifts one way before going back the other
+const DRIFT_FRAMES: Frames = Frames(40);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeatherKind {
+    /// Quick streaks slanting down to the left
+    Rain,
+    /// Slow flakes drifting from side to side
+    Snow,
+}
+
+pub struct Weather<'a> {
+    kind: WeatherKind,
+    drops: Vec<Vector2D<Fixed>, MatchArena>,
+    pool: SpritePool<'a>,
+    frame: Frames,
+}
+
+impl<'a> Weather<'a> {
+    pub fn new(object: &'a OamManaged<'a>, kind: WeatherKind) -> Self {
+        let tag = match kind {
+            WeatherKind::Rain => SPRITES.raindrop,
+            WeatherKind::Snow => SPRITES.snowflake,
+        };
+        // Spread over the screen to start with, so it's already falling when the match starts
+        let height = agb::display::HEIGHT + MARGIN;
+        let drops = arena::collect((0..WEATHER_DROPS as i32).map(|drop| {
+            let x = (drop * STRIDE).rem_euclid(agb::display::WIDTH);
+            (
+                Fixed::new(x),
+                Fixed::new(drop * height / WEATHER_DROPS as i32),
+            )
+                .into()
+        }));
+
+        Self {
+            kind,
+            drops,
+            pool: SpritePool::new(object, tag.sprite(0), WEATHER_DROPS, RenderLayer::Decor),
+            frame: Frames::ZERO,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.frame.tick();
+        let swaying_right = (self.frame / DRIFT_FRAMES) % 2 == 0;
+        for (index, drop) in self.drops.iter_mut().enumerate() {
+            let velocity: Vector2D<Fixed> = match self.kind {
+                WeatherKind::Rain => (fixed(-1, 1), fixed(3, 1)).into(),
+                // Every other flake sways the other way
+                WeatherKind::Snow if swaying_right == (index % 2 == 0) => {
+                    (fixed(1, 4), fixed(1, 2)).into()
+                }
+                WeatherKind::Snow => (fixed(-1, 4), fixed(1, 2)).into(),
+            };
+            *drop += velocity;
+
+            if drop.y > Fixed::new(agb::display::HEIGHT) {
+                drop.y -= agb::display::HEIGHT + MARGIN;
+                drop.x += STRIDE;
+            }
+            let x = (drop.x + MARGIN).rem_euclid(Fixed::new(agb::display::WIDTH + MARGIN));
+            drop.x = x - MARGIN;
+        }
+    }
+
+    pub fn update_sprites(&mut self) {
+        self.pool.place(self.drops.iter().map(|drop| drop.floor()));
+    }
+}